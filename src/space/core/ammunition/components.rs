@@ -0,0 +1,48 @@
+use bevy_core::Timer;
+use bevy_ecs::{entity::Entity, prelude::Component};
+
+// Power cells and magazines. Weapons that take ammunition have one too, it stands for whatever is loaded into them.
+#[derive(Component)]
+pub struct AmmoContainer {
+    pub ammo_type: String,
+    pub capacity: u32,
+    pub amount: u32,
+}
+
+impl AmmoContainer {
+    // Takes the cost out of the container, unless there is not enough left.
+    pub fn consume(&mut self, cost: u32) -> bool {
+        if self.amount < cost {
+            return false;
+        }
+        self.amount -= cost;
+        true
+    }
+    pub fn get_space(&self) -> u32 {
+        self.capacity.saturating_sub(self.amount)
+    }
+    pub fn is_full(&self) -> bool {
+        self.amount >= self.capacity
+    }
+}
+
+// Weapons that use up their loaded ammunition with every shot.
+#[derive(Component)]
+pub struct AmmoUser {
+    pub shot_cost: u32,
+}
+
+// Refills the containers of its ammo type that get held up to it.
+#[derive(Component)]
+pub struct Recharger {
+    pub ammo_type: String,
+    pub charge_per_second: u32,
+}
+
+// Attached to the user for as long as a container is being recharged.
+#[derive(Component)]
+pub struct Recharging {
+    pub recharger: Entity,
+    pub ammo_container: Entity,
+    pub timer: Timer,
+}
@@ -0,0 +1,13 @@
+use bevy_ecs::entity::Entity;
+
+pub struct InputReloadWeapon {
+    pub user: Entity,
+    pub weapon: Entity,
+    // Swap the loaded container with the held one instead of topping the weapon up.
+    pub swap: bool,
+}
+
+pub struct InputRecharge {
+    pub user: Entity,
+    pub recharger: Entity,
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use bevy_ecs::{entity::Entity, system::Query};
+
+use crate::space::core::{
+    chat::functions::FURTHER_ITALIC_FONT,
+    data_link::components::DataLink,
+    entity::{components::EntityData, resources::EntityDataResource},
+    gridmap::resources::CellData,
+    inventory::components::Inventory,
+    networking::resources::GridMapType,
+    pawn::functions::can_reach_entity::REACH_DISTANCE,
+    tab_actions::components::TabAction,
+};
+
+use super::components::AmmoContainer;
+
+// Tab actions the weapon offers while it is in the active hand.
+pub fn get_ammo_user_tab_actions(weapon_entity: Entity) -> Vec<TabAction> {
+    vec![
+        TabAction {
+            id: "ammoreload".to_string(),
+            text: "Reload".to_string(),
+            tab_list_priority: 58,
+            prerequisite_check: Arc::new(ammo_reload_action),
+            belonging_entity: Some(weapon_entity),
+        },
+        TabAction {
+            id: "ammoswap".to_string(),
+            text: "Swap".to_string(),
+            tab_list_priority: 57,
+            prerequisite_check: Arc::new(ammo_reload_action),
+            belonging_entity: Some(weapon_entity),
+        },
+    ]
+}
+
+// Reloading takes from whatever is held in the other hand.
+pub fn ammo_reload_action(
+    self_tab_entity_option: Option<Entity>,
+    _entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    _distance: f32,
+    inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    match self_tab_entity_option {
+        Some(self_tab_entity) => {
+            inventory_component.get_active_slot_entity() == Some(self_tab_entity)
+                && get_other_hand_entity(inventory_component).is_some()
+        }
+        None => false,
+    }
+}
+
+pub fn get_recharger_tab_actions(recharger_entity: Entity) -> Vec<TabAction> {
+    vec![TabAction {
+        id: "recharge".to_string(),
+        text: "Recharge".to_string(),
+        tab_list_priority: 62,
+        prerequisite_check: Arc::new(recharge_action),
+        belonging_entity: Some(recharger_entity),
+    }]
+}
+
+pub fn recharge_action(
+    _self_tab_entity_option: Option<Entity>,
+    entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    distance: f32,
+    inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    entity_id_bits_option.is_some()
+        && distance < REACH_DISTANCE
+        && inventory_component.get_active_slot_entity().is_some()
+}
+
+pub fn get_other_hand_entity(inventory_component: &Inventory) -> Option<Entity> {
+    let other_hand;
+
+    if inventory_component.active_slot == "left_hand" {
+        other_hand = "right_hand";
+    } else if inventory_component.active_slot == "right_hand" {
+        other_hand = "left_hand";
+    } else {
+        return None;
+    }
+
+    inventory_component.get_slot(other_hand).slot_item
+}
+
+pub fn get_ammo_examine_text(ammo_container: &AmmoContainer) -> String {
+    let state;
+
+    if ammo_container.amount == 0 {
+        state = " It is empty.";
+    } else {
+        state = "";
+    }
+
+    "[font=".to_string()
+        + FURTHER_ITALIC_FONT
+        + "]"
+        + &ammo_container.amount.to_string()
+        + "/"
+        + &ammo_container.capacity.to_string()
+        + " "
+        + &ammo_container.ammo_type
+        + " left."
+        + state
+        + "[/font]"
+}
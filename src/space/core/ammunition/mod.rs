@@ -0,0 +1,27 @@
+use bevy_app::{App, Plugin};
+
+use self::{
+    events::{InputRecharge, InputReloadWeapon},
+    systems::{
+        ammo_examine_text::ammo_examine_text, recharging::recharging, reload_weapon::reload_weapon,
+        start_recharge::start_recharge,
+    },
+};
+
+pub mod components;
+pub mod events;
+pub mod functions;
+pub mod systems;
+
+pub struct AmmunitionPlugin;
+
+impl Plugin for AmmunitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<InputReloadWeapon>()
+            .add_event::<InputRecharge>()
+            .add_system(reload_weapon)
+            .add_system(start_recharge)
+            .add_system(recharging)
+            .add_system(ammo_examine_text);
+    }
+}
@@ -0,0 +1,20 @@
+use bevy_ecs::{prelude::Changed, system::Query};
+
+use crate::space::core::{
+    ammunition::{components::AmmoContainer, functions::get_ammo_examine_text},
+    examinable::components::Examinable,
+};
+
+// Kept clear of the texts assigned by prototypes and of the reagent text.
+pub const AMMO_EXAMINE_TEXT_ID: u32 = 101;
+
+pub fn ammo_examine_text(
+    mut ammo_containers: Query<(&AmmoContainer, &mut Examinable), Changed<AmmoContainer>>,
+) {
+    for (ammo_container_component, mut examinable_component) in ammo_containers.iter_mut() {
+        examinable_component.assigned_texts.insert(
+            AMMO_EXAMINE_TEXT_ID,
+            get_ammo_examine_text(ammo_container_component),
+        );
+    }
+}
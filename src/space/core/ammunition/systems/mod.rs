@@ -0,0 +1,4 @@
+pub mod ammo_examine_text;
+pub mod recharging;
+pub mod reload_weapon;
+pub mod start_recharge;
@@ -0,0 +1,101 @@
+use bevy_app::EventWriter;
+use bevy_core::Time;
+use bevy_ecs::{
+    entity::Entity,
+    system::{Commands, Query, Res},
+};
+use bevy_math::Vec3;
+use bevy_rapier3d::prelude::RigidBodyPositionComponent;
+
+use crate::space::core::{
+    ammunition::components::{AmmoContainer, Recharger, Recharging},
+    chat::{events::NetChatMessage, functions::new_personal_message},
+    connected_player::resources::HandleToEntity,
+    inventory::components::Inventory,
+    pawn::functions::can_reach_entity::REACH_DISTANCE,
+};
+
+pub fn recharging(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut users: Query<(Entity, &mut Recharging, &Inventory)>,
+    rigid_body_positions: Query<&RigidBodyPositionComponent>,
+    rechargers: Query<&Recharger>,
+    mut ammo_containers: Query<&mut AmmoContainer>,
+    handle_to_entity: Res<HandleToEntity>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+) {
+    for (user_entity, mut recharging_component, inventory_component) in users.iter_mut() {
+        // Switching hands or walking away interrupts the recharge.
+        let mut interrupted = inventory_component.get_active_slot_entity()
+            != Some(recharging_component.ammo_container);
+
+        match (
+            rigid_body_positions.get(user_entity),
+            rigid_body_positions.get(recharging_component.recharger),
+        ) {
+            (Ok(user_position), Ok(recharger_position)) => {
+                let user_translation: Vec3 = user_position.position.translation.into();
+                let recharger_translation: Vec3 = recharger_position.position.translation.into();
+                if user_translation.distance(recharger_translation) > REACH_DISTANCE {
+                    interrupted = true;
+                }
+            }
+            _ => {
+                interrupted = true;
+            }
+        }
+
+        if interrupted {
+            new_personal_message(
+                &mut net_chat_message,
+                &handle_to_entity,
+                user_entity,
+                "You stop recharging.",
+            );
+            commands.entity(user_entity).remove::<Recharging>();
+            continue;
+        }
+
+        if !recharging_component
+            .timer
+            .tick(time.delta())
+            .just_finished()
+        {
+            continue;
+        }
+
+        let charge_per_second;
+
+        match rechargers.get(recharging_component.recharger) {
+            Ok(recharger_component) => {
+                charge_per_second = recharger_component.charge_per_second;
+            }
+            Err(_rr) => {
+                commands.entity(user_entity).remove::<Recharging>();
+                continue;
+            }
+        }
+
+        match ammo_containers.get_mut(recharging_component.ammo_container) {
+            Ok(mut ammo_container_component) => {
+                ammo_container_component.amount = ammo_container_component
+                    .capacity
+                    .min(ammo_container_component.amount + charge_per_second);
+
+                if ammo_container_component.is_full() {
+                    new_personal_message(
+                        &mut net_chat_message,
+                        &handle_to_entity,
+                        user_entity,
+                        "It is fully charged.",
+                    );
+                    commands.entity(user_entity).remove::<Recharging>();
+                }
+            }
+            Err(_rr) => {
+                commands.entity(user_entity).remove::<Recharging>();
+            }
+        }
+    }
+}
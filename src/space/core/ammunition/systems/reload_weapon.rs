@@ -0,0 +1,164 @@
+use bevy_app::{EventReader, EventWriter};
+use bevy_ecs::system::{Query, Res};
+
+use crate::space::core::{
+    ammunition::{
+        components::AmmoContainer, events::InputReloadWeapon, functions::get_other_hand_entity,
+    },
+    chat::{events::NetChatMessage, functions::new_personal_message},
+    connected_player::resources::HandleToEntity,
+    examinable::components::Examinable,
+    inventory::components::Inventory,
+};
+
+pub fn reload_weapon(
+    mut reload_events: EventReader<InputReloadWeapon>,
+    users: Query<&Inventory>,
+    mut ammo_containers: Query<&mut AmmoContainer>,
+    examinables: Query<&Examinable>,
+    handle_to_entity: Res<HandleToEntity>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+) {
+    for event in reload_events.iter() {
+        let held_entity;
+
+        match users.get(event.user) {
+            Ok(inventory_component) => {
+                if inventory_component.get_active_slot_entity() != Some(event.weapon) {
+                    continue;
+                }
+                match get_other_hand_entity(inventory_component) {
+                    Some(entity) => {
+                        held_entity = entity;
+                    }
+                    None => {
+                        continue;
+                    }
+                }
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let weapon_name;
+        let held_name;
+
+        match examinables.get(event.weapon) {
+            Ok(examinable_component) => {
+                weapon_name = examinable_component.name.get_name().to_string();
+            }
+            Err(_rr) => {
+                weapon_name = "weapon".to_string();
+            }
+        }
+        match examinables.get(held_entity) {
+            Ok(examinable_component) => {
+                held_name = examinable_component.name.get_name().to_string();
+            }
+            Err(_rr) => {
+                held_name = "it".to_string();
+            }
+        }
+
+        let held_ammo_type;
+        let held_capacity;
+        let held_amount;
+
+        match ammo_containers.get(held_entity) {
+            Ok(ammo_container_component) => {
+                held_ammo_type = ammo_container_component.ammo_type.clone();
+                held_capacity = ammo_container_component.capacity;
+                held_amount = ammo_container_component.amount;
+            }
+            Err(_rr) => {
+                new_personal_message(
+                    &mut net_chat_message,
+                    &handle_to_entity,
+                    event.user,
+                    &("The ".to_string() + &held_name + " cannot be loaded into anything."),
+                );
+                continue;
+            }
+        }
+
+        let mut weapon_ammo_component;
+
+        match ammo_containers.get_mut(event.weapon) {
+            Ok(c) => {
+                weapon_ammo_component = c;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        if weapon_ammo_component.ammo_type != held_ammo_type {
+            new_personal_message(
+                &mut net_chat_message,
+                &handle_to_entity,
+                event.user,
+                &("The ".to_string() + &held_name + " does not fit the " + &weapon_name + "."),
+            );
+            continue;
+        }
+
+        let message;
+        let new_held_capacity;
+        let new_held_amount;
+
+        if event.swap {
+            // The loaded container comes out and ends up in the other hand.
+            new_held_capacity = weapon_ammo_component.capacity;
+            new_held_amount = weapon_ammo_component.amount;
+            weapon_ammo_component.capacity = held_capacity;
+            weapon_ammo_component.amount = held_amount;
+            message = "You swap the ".to_string() + &held_name + " into the " + &weapon_name + ".";
+        } else {
+            let transferred = held_amount.min(weapon_ammo_component.get_space());
+
+            if transferred == 0 {
+                if held_amount == 0 {
+                    message = "The ".to_string() + &held_name + " is empty.";
+                } else {
+                    message = "The ".to_string() + &weapon_name + " is already full.";
+                }
+                new_personal_message(
+                    &mut net_chat_message,
+                    &handle_to_entity,
+                    event.user,
+                    &message,
+                );
+                continue;
+            }
+
+            new_held_capacity = held_capacity;
+            new_held_amount = held_amount - transferred;
+            weapon_ammo_component.amount += transferred;
+            message = "You reload the ".to_string()
+                + &weapon_name
+                + " with the "
+                + &held_name
+                + ", it has "
+                + &weapon_ammo_component.amount.to_string()
+                + "/"
+                + &weapon_ammo_component.capacity.to_string()
+                + " left.";
+        }
+
+        match ammo_containers.get_mut(held_entity) {
+            Ok(mut held_ammo_component) => {
+                held_ammo_component.capacity = new_held_capacity;
+                held_ammo_component.amount = new_held_amount;
+            }
+            Err(_rr) => {}
+        }
+
+        new_personal_message(
+            &mut net_chat_message,
+            &handle_to_entity,
+            event.user,
+            &message,
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use bevy_app::{EventReader, EventWriter};
+use bevy_core::Timer;
+use bevy_ecs::system::{Commands, Query, Res};
+use bevy_math::Vec3;
+use bevy_rapier3d::prelude::RigidBodyPositionComponent;
+
+use crate::space::core::{
+    ammunition::{
+        components::{AmmoContainer, Recharger, Recharging},
+        events::InputRecharge,
+    },
+    chat::{events::NetChatMessage, functions::new_personal_message},
+    connected_player::resources::HandleToEntity,
+    examinable::components::Examinable,
+    inventory::components::Inventory,
+    pawn::functions::can_reach_entity::REACH_DISTANCE,
+};
+
+pub fn start_recharge(
+    mut recharge_events: EventReader<InputRecharge>,
+    mut commands: Commands,
+    users: Query<&Inventory>,
+    rigid_body_positions: Query<&RigidBodyPositionComponent>,
+    rechargers: Query<&Recharger>,
+    ammo_containers: Query<&AmmoContainer>,
+    examinables: Query<&Examinable>,
+    handle_to_entity: Res<HandleToEntity>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+) {
+    for event in recharge_events.iter() {
+        let held_entity;
+
+        match users.get(event.user) {
+            Ok(inventory_component) => match inventory_component.get_active_slot_entity() {
+                Some(entity) => {
+                    held_entity = entity;
+                }
+                None => {
+                    continue;
+                }
+            },
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let recharger_component;
+
+        match rechargers.get(event.recharger) {
+            Ok(c) => {
+                recharger_component = c;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        match (
+            rigid_body_positions.get(event.user),
+            rigid_body_positions.get(event.recharger),
+        ) {
+            (Ok(user_position), Ok(recharger_position)) => {
+                let user_translation: Vec3 = user_position.position.translation.into();
+                let recharger_translation: Vec3 = recharger_position.position.translation.into();
+                if user_translation.distance(recharger_translation) > REACH_DISTANCE {
+                    continue;
+                }
+            }
+            _ => {
+                continue;
+            }
+        }
+
+        let held_name;
+
+        match examinables.get(held_entity) {
+            Ok(examinable_component) => {
+                held_name = examinable_component.name.get_name().to_string();
+            }
+            Err(_rr) => {
+                held_name = "it".to_string();
+            }
+        }
+
+        match ammo_containers.get(held_entity) {
+            Ok(ammo_container_component) => {
+                if ammo_container_component.ammo_type != recharger_component.ammo_type {
+                    new_personal_message(
+                        &mut net_chat_message,
+                        &handle_to_entity,
+                        event.user,
+                        &("The ".to_string() + &held_name + " cannot be recharged here."),
+                    );
+                    continue;
+                }
+                if ammo_container_component.is_full() {
+                    new_personal_message(
+                        &mut net_chat_message,
+                        &handle_to_entity,
+                        event.user,
+                        &("The ".to_string() + &held_name + " is already fully charged."),
+                    );
+                    continue;
+                }
+            }
+            Err(_rr) => {
+                new_personal_message(
+                    &mut net_chat_message,
+                    &handle_to_entity,
+                    event.user,
+                    &("The ".to_string() + &held_name + " cannot be recharged."),
+                );
+                continue;
+            }
+        }
+
+        new_personal_message(
+            &mut net_chat_message,
+            &handle_to_entity,
+            event.user,
+            &("You start recharging the ".to_string() + &held_name + "."),
+        );
+
+        // Replaces any recharge that was still in progress.
+        commands.entity(event.user).insert(Recharging {
+            recharger: event.recharger,
+            ammo_container: held_entity,
+            timer: Timer::from_seconds(1., true),
+        });
+    }
+}
@@ -1,4 +1,7 @@
-use crate::space::core::networking::resources::{NetMessageType, ReliableServerMessage};
+use crate::space::core::{
+    gridmap::resources::Vec2Int,
+    networking::resources::{NetMessageType, ReliableServerMessage},
+};
 
 pub struct NetMapDisplayAtmospherics {
     pub handle: u32,
@@ -14,3 +17,7 @@ pub struct NetAtmosphericsNotices {
     pub handle: u32,
     pub message: ReliableServerMessage,
 }
+
+pub struct IgniteAtmospherics {
+    pub cell_id: Vec2Int,
+}
@@ -0,0 +1,121 @@
+pub mod snapshot;
+
+use std::collections::HashSet;
+
+use crate::space::core::gridmap::resources::{Vec2Int, FOV_MAP_WIDTH};
+
+use super::resources::AtmosphericsResource;
+
+pub fn get_atmos_index(id: Vec2Int) -> usize {
+    let idx: u32 = (id.x + (FOV_MAP_WIDTH / 2) as i16) as u32;
+    let idy: u32 = (id.y + (FOV_MAP_WIDTH / 2) as i16) as u32;
+
+    (idx + (idy * FOV_MAP_WIDTH as u32)) as usize
+}
+
+pub fn get_atmos_id(i: usize) -> Vec2Int {
+    let y = (i as f32 / FOV_MAP_WIDTH as f32).floor() as usize;
+    let x = i - (y * FOV_MAP_WIDTH);
+
+    Vec2Int {
+        x: x as i16 - (FOV_MAP_WIDTH as i16 / 2),
+        y: y as i16 - (FOV_MAP_WIDTH as i16 / 2),
+    }
+}
+
+// Largest pressure difference between the open cells around a cell, in kpa.
+pub fn get_adjacent_pressure_difference(
+    cell_id: Vec2Int,
+    atmospherics_resource: &AtmosphericsResource,
+) -> f32 {
+    let mut lowest_pressure = f32::MAX;
+    let mut highest_pressure = f32::MIN;
+
+    for j in 0..4 {
+        let mut adjacent_cell_id = cell_id.clone();
+
+        if j == 0 {
+            adjacent_cell_id.x += 1;
+        } else if j == 1 {
+            adjacent_cell_id.x -= 1;
+        } else if j == 2 {
+            adjacent_cell_id.y += 1;
+        } else {
+            adjacent_cell_id.y -= 1;
+        }
+
+        if AtmosphericsResource::is_id_out_of_range(adjacent_cell_id) {
+            continue;
+        }
+
+        let adjacent_atmospherics = atmospherics_resource
+            .atmospherics
+            .get(get_atmos_index(adjacent_cell_id))
+            .unwrap();
+
+        if adjacent_atmospherics.blocked {
+            continue;
+        }
+
+        let pressure = adjacent_atmospherics.get_pressure();
+
+        if pressure < lowest_pressure {
+            lowest_pressure = pressure;
+        }
+        if pressure > highest_pressure {
+            highest_pressure = pressure;
+        }
+    }
+
+    if highest_pressure < lowest_pressure {
+        return 0.;
+    }
+
+    highest_pressure - lowest_pressure
+}
+
+// Flood fill from the center through cells that don't block atmospherics, up to cell_radius cells away.
+// The blocking cells around the filled area are reached too, like the walls a blast hits.
+pub fn get_unblocked_reach(
+    center_cell_id: Vec2Int,
+    cell_radius: i16,
+    atmospherics_resource: &AtmosphericsResource,
+) -> HashSet<Vec2Int> {
+    let mut reached_cells = HashSet::new();
+    let mut open_cells = vec![center_cell_id];
+
+    reached_cells.insert(center_cell_id);
+
+    while let Some(cell_id) = open_cells.pop() {
+        // The center may be a blocking cell itself, like a door an explosive lies against.
+        if cell_id != center_cell_id
+            && atmospherics_resource
+                .atmospherics
+                .get(get_atmos_index(cell_id))
+                .unwrap()
+                .blocked
+        {
+            continue;
+        }
+
+        for (x, y) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            let adjacent_cell_id = Vec2Int {
+                x: cell_id.x + x,
+                y: cell_id.y + y,
+            };
+
+            if (adjacent_cell_id.x - center_cell_id.x).abs() > cell_radius
+                || (adjacent_cell_id.y - center_cell_id.y).abs() > cell_radius
+                || AtmosphericsResource::is_id_out_of_range(adjacent_cell_id)
+                || reached_cells.contains(&adjacent_cell_id)
+            {
+                continue;
+            }
+
+            reached_cells.insert(adjacent_cell_id);
+            open_cells.push(adjacent_cell_id);
+        }
+    }
+
+    reached_cells
+}
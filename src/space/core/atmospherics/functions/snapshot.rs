@@ -0,0 +1,395 @@
+use std::{
+    fs::{self, File},
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+
+use crate::space::core::{
+    atmospherics::{
+        functions::{get_atmos_id, get_atmos_index},
+        resources::{AtmosphericsResource, EffectType},
+        systems::effects::VACUUM_ATMOSEFFECT,
+    },
+    configuration::functions::is_valid_file_name,
+    gridmap::resources::Vec2Int,
+};
+
+const SNAPSHOT_CSV_HEADER: &str = "x,y,blocked,temperature,amount,oxidiser,fuel,burning,forces_push_up,thermal_conductivity,heat_capacity,floorless,default_vacuum";
+const SNAPSHOT_CSV_COLUMNS: usize = 13;
+// Pixels per cell in the heatmaps.
+const SNAPSHOT_PNG_CELL_SIZE: u32 = 4;
+
+const BLOCKED_COLOR: [u8; 3] = [64, 64, 64];
+const OPEN_COLOR: [u8; 3] = [255, 255, 255];
+
+pub fn get_atmospherics_snapshot_directory() -> PathBuf {
+    Path::new("data").join("atmospherics_snapshots")
+}
+
+// The smallest rectangle containing every cell that is not default vacuum.
+pub fn get_occupied_bounds(
+    atmospherics_resource: &AtmosphericsResource,
+) -> Option<(Vec2Int, Vec2Int)> {
+    let mut bounds: Option<(Vec2Int, Vec2Int)> = None;
+
+    for (i, atmospherics) in atmospherics_resource.atmospherics.iter().enumerate() {
+        if !atmospherics.blocked && atmospherics.flags.contains(&"default_vacuum".to_string()) {
+            continue;
+        }
+
+        let cell_id = get_atmos_id(i);
+
+        match &mut bounds {
+            Some((min, max)) => {
+                min.x = min.x.min(cell_id.x);
+                min.y = min.y.min(cell_id.y);
+                max.x = max.x.max(cell_id.x);
+                max.y = max.y.max(cell_id.y);
+            }
+            None => {
+                bounds = Some((cell_id.clone(), cell_id));
+            }
+        }
+    }
+
+    bounds
+}
+
+// Writes <name>.csv and the <name>_pressure.png, <name>_temperature.png and <name>_blocked.png heatmaps.
+pub fn export_atmospherics_snapshot(
+    name: &str,
+    atmospherics_resource: &AtmosphericsResource,
+) -> Result<Vec<PathBuf>, String> {
+    if !is_valid_file_name(name) {
+        return Err("Invalid snapshot name.".to_string());
+    }
+
+    let min;
+    let max;
+
+    match get_occupied_bounds(atmospherics_resource) {
+        Some(bounds) => {
+            min = bounds.0;
+            max = bounds.1;
+        }
+        None => {
+            return Err("There are no occupied atmospherics cells.".to_string());
+        }
+    }
+
+    let directory = get_atmospherics_snapshot_directory();
+
+    match fs::create_dir_all(&directory) {
+        Ok(_) => {}
+        Err(rr) => {
+            return Err(rr.to_string());
+        }
+    }
+
+    let csv = atmospherics_to_csv(atmospherics_resource, min, max);
+
+    let csv_path = directory.join(name.to_string() + ".csv");
+
+    match fs::write(&csv_path, csv) {
+        Ok(_) => {}
+        Err(rr) => {
+            return Err(rr.to_string());
+        }
+    }
+
+    let mut written_paths = vec![csv_path];
+
+    let mut pressures = vec![];
+    let mut temperatures = vec![];
+    let mut blocked = vec![];
+
+    for y in min.y..max.y + 1 {
+        for x in min.x..max.x + 1 {
+            let atmospherics = atmospherics_resource
+                .atmospherics
+                .get(get_atmos_index(Vec2Int { x, y }))
+                .unwrap();
+            blocked.push(atmospherics.blocked);
+            pressures.push(atmospherics.get_pressure());
+            temperatures.push(atmospherics.temperature);
+        }
+    }
+
+    let width = (max.x - min.x + 1) as u32;
+    let height = (max.y - min.y + 1) as u32;
+
+    let blocked_colors: Vec<[u8; 3]> = blocked
+        .iter()
+        .map(|b| if *b { BLOCKED_COLOR } else { OPEN_COLOR })
+        .collect();
+
+    for (suffix, colors) in [
+        ("_pressure", values_to_heatmap(&pressures, &blocked)),
+        ("_temperature", values_to_heatmap(&temperatures, &blocked)),
+        ("_blocked", blocked_colors),
+    ] {
+        let png_path = directory.join(name.to_string() + suffix + ".png");
+        write_png(&png_path, width, height, &colors)?;
+        written_paths.push(png_path);
+    }
+
+    Ok(written_paths)
+}
+
+// Restores the cells stored in <name>.csv, cells outside of the snapshot remain untouched.
+pub fn load_atmospherics_snapshot(
+    name: &str,
+    atmospherics_resource: &mut AtmosphericsResource,
+) -> Result<usize, String> {
+    if !is_valid_file_name(name) {
+        return Err("Invalid snapshot name.".to_string());
+    }
+
+    let csv_path = get_atmospherics_snapshot_directory().join(name.to_string() + ".csv");
+
+    let csv;
+
+    match fs::read_to_string(&csv_path) {
+        Ok(s) => {
+            csv = s;
+        }
+        Err(rr) => {
+            return Err(rr.to_string());
+        }
+    }
+
+    atmospherics_from_csv(&csv, atmospherics_resource)
+}
+
+// One line per cell in the rectangle from min to max, both inclusive.
+pub fn atmospherics_to_csv(
+    atmospherics_resource: &AtmosphericsResource,
+    min: Vec2Int,
+    max: Vec2Int,
+) -> String {
+    let mut csv = SNAPSHOT_CSV_HEADER.to_string() + "\n";
+
+    for y in min.y..max.y + 1 {
+        for x in min.x..max.x + 1 {
+            let atmospherics = atmospherics_resource
+                .atmospherics
+                .get(get_atmos_index(Vec2Int { x, y }))
+                .unwrap();
+
+            csv = csv
+                + &x.to_string()
+                + ","
+                + &y.to_string()
+                + ","
+                + &atmospherics.blocked.to_string()
+                + ","
+                + &atmospherics.temperature.to_string()
+                + ","
+                + &atmospherics.amount.to_string()
+                + ","
+                + &atmospherics.oxidiser.to_string()
+                + ","
+                + &atmospherics.fuel.to_string()
+                + ","
+                + &atmospherics.burning.to_string()
+                + ","
+                + &atmospherics.forces_push_up.to_string()
+                + ","
+                + &atmospherics.thermal_conductivity.to_string()
+                + ","
+                + &atmospherics.heat_capacity.to_string()
+                + ","
+                + &atmospherics
+                    .effects
+                    .contains_key(&EffectType::Floorless)
+                    .to_string()
+                + ","
+                + &atmospherics
+                    .flags
+                    .contains(&"default_vacuum".to_string())
+                    .to_string()
+                + "\n";
+        }
+    }
+
+    csv
+}
+
+// A cell of a snapshot csv, parsed before anything gets applied.
+struct SnapshotCell {
+    cell_id: Vec2Int,
+    blocked: bool,
+    temperature: f32,
+    amount: f32,
+    oxidiser: f32,
+    fuel: f32,
+    burning: bool,
+    forces_push_up: bool,
+    thermal_conductivity: f32,
+    heat_capacity: f32,
+    floorless: bool,
+    default_vacuum: bool,
+}
+
+// Restores the cells stored in the csv, cells that are not in it remain untouched.
+// Nothing gets restored when any of the lines is invalid.
+pub fn atmospherics_from_csv(
+    csv: &str,
+    atmospherics_resource: &mut AtmosphericsResource,
+) -> Result<usize, String> {
+    let mut snapshot_cells = vec![];
+
+    for (line_i, line) in csv.lines().enumerate().skip(1) {
+        if line.is_empty() {
+            continue;
+        }
+
+        let columns: Vec<&str> = line.split(",").collect();
+
+        if columns.len() != SNAPSHOT_CSV_COLUMNS {
+            return Err("Wrong amount of columns on line ".to_string() + &(line_i + 1).to_string());
+        }
+
+        let parse_error = "Could not parse line ".to_string() + &(line_i + 1).to_string();
+
+        let cell_id = Vec2Int {
+            x: columns[0].parse::<i16>().map_err(|_| parse_error.clone())?,
+            y: columns[1].parse::<i16>().map_err(|_| parse_error.clone())?,
+        };
+
+        if AtmosphericsResource::is_id_out_of_range(cell_id) {
+            return Err(parse_error);
+        }
+
+        snapshot_cells.push(SnapshotCell {
+            cell_id,
+            blocked: columns[2]
+                .parse::<bool>()
+                .map_err(|_| parse_error.clone())?,
+            temperature: columns[3].parse::<f32>().map_err(|_| parse_error.clone())?,
+            amount: columns[4].parse::<f32>().map_err(|_| parse_error.clone())?,
+            oxidiser: columns[5].parse::<f32>().map_err(|_| parse_error.clone())?,
+            fuel: columns[6].parse::<f32>().map_err(|_| parse_error.clone())?,
+            burning: columns[7]
+                .parse::<bool>()
+                .map_err(|_| parse_error.clone())?,
+            forces_push_up: columns[8]
+                .parse::<bool>()
+                .map_err(|_| parse_error.clone())?,
+            thermal_conductivity: columns[9].parse::<f32>().map_err(|_| parse_error.clone())?,
+            heat_capacity: columns[10]
+                .parse::<f32>()
+                .map_err(|_| parse_error.clone())?,
+            floorless: columns[11]
+                .parse::<bool>()
+                .map_err(|_| parse_error.clone())?,
+            default_vacuum: columns[12]
+                .parse::<bool>()
+                .map_err(|_| parse_error.clone())?,
+        });
+    }
+
+    let default_vacuum = "default_vacuum".to_string();
+
+    for snapshot_cell in snapshot_cells.iter() {
+        let atmospherics = atmospherics_resource
+            .atmospherics
+            .get_mut(get_atmos_index(snapshot_cell.cell_id))
+            .unwrap();
+
+        atmospherics.blocked = snapshot_cell.blocked;
+        atmospherics.temperature = snapshot_cell.temperature;
+        atmospherics.amount = snapshot_cell.amount;
+        atmospherics.oxidiser = snapshot_cell.oxidiser;
+        atmospherics.fuel = snapshot_cell.fuel;
+        atmospherics.burning = snapshot_cell.burning;
+        atmospherics.forces_push_up = snapshot_cell.forces_push_up;
+        atmospherics.thermal_conductivity = snapshot_cell.thermal_conductivity;
+        atmospherics.heat_capacity = snapshot_cell.heat_capacity;
+
+        if snapshot_cell.floorless {
+            atmospherics
+                .effects
+                .insert(EffectType::Floorless, VACUUM_ATMOSEFFECT);
+        } else {
+            atmospherics.effects.remove(&EffectType::Floorless);
+        }
+
+        atmospherics.flags.retain(|flag| flag != &default_vacuum);
+        if snapshot_cell.default_vacuum {
+            atmospherics.flags.push(default_vacuum.to_string());
+        }
+    }
+
+    Ok(snapshot_cells.len())
+}
+
+// Blue for the lowest value in view, red for the highest.
+fn values_to_heatmap(values: &Vec<f32>, blocked: &Vec<bool>) -> Vec<[u8; 3]> {
+    let mut lowest = f32::MAX;
+    let mut highest = f32::MIN;
+
+    for (value, is_blocked) in values.iter().zip(blocked.iter()) {
+        if *is_blocked {
+            continue;
+        }
+        lowest = lowest.min(*value);
+        highest = highest.max(*value);
+    }
+
+    let range = highest - lowest;
+
+    values
+        .iter()
+        .zip(blocked.iter())
+        .map(|(value, is_blocked)| {
+            if *is_blocked {
+                return BLOCKED_COLOR;
+            }
+            let mut t = 0.5;
+            if range > 0. {
+                t = (value - lowest) / range;
+            }
+            [(t * 255.) as u8, 0, ((1. - t) * 255.) as u8]
+        })
+        .collect()
+}
+
+fn write_png(path: &Path, width: u32, height: u32, colors: &Vec<[u8; 3]>) -> Result<(), String> {
+    let file;
+
+    match File::create(path) {
+        Ok(f) => {
+            file = f;
+        }
+        Err(rr) => {
+            return Err(rr.to_string());
+        }
+    }
+
+    let image_width = width * SNAPSHOT_PNG_CELL_SIZE;
+    let image_height = height * SNAPSHOT_PNG_CELL_SIZE;
+
+    let mut encoder = png::Encoder::new(BufWriter::new(file), image_width, image_height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut data = Vec::with_capacity((image_width * image_height * 3) as usize);
+
+    // Rows are written top to bottom, the highest cell row ends up at the top of the image.
+    for image_y in 0..image_height {
+        let cell_y = height - 1 - image_y / SNAPSHOT_PNG_CELL_SIZE;
+        for image_x in 0..image_width {
+            let cell_x = image_x / SNAPSHOT_PNG_CELL_SIZE;
+            data.extend_from_slice(&colors[(cell_y * width + cell_x) as usize]);
+        }
+    }
+
+    match encoder.write_header() {
+        Ok(mut writer) => match writer.write_image_data(&data) {
+            Ok(_) => Ok(()),
+            Err(rr) => Err(rr.to_string()),
+        },
+        Err(rr) => Err(rr.to_string()),
+    }
+}
@@ -4,20 +4,28 @@ pub mod functions;
 pub mod resources;
 pub mod systems;
 
+use std::env;
+
 use bevy_app::{App, Plugin};
 use bevy_core::FixedTimestep;
 use bevy_ecs::{
     schedule::{ParallelSystemDescriptorCoercion, SystemSet},
     system::{Res, ResMut},
 };
-use bevy_log::info;
+use bevy_log::{info, warn};
 use bevy_rapier3d::physics::{PhysicsStages, PhysicsSystems};
 
 use crate::space::{
     core::{
         atmospherics::{
-            functions::get_atmos_index,
-            resources::{Atmospherics, AtmosphericsResource, DEFAULT_INTERNAL_AMOUNT},
+            functions::{
+                get_atmos_index,
+                snapshot::{export_atmospherics_snapshot, load_atmospherics_snapshot},
+            },
+            resources::{
+                Atmospherics, AtmosphericsResource, DEFAULT_HEAT_CAPACITY, DEFAULT_INTERNAL_AMOUNT,
+                DEFAULT_THERMAL_CONDUCTIVITY,
+            },
         },
         gridmap::resources::{GridmapMain, Vec2Int, Vec3Int, FOV_MAP_WIDTH},
     },
@@ -25,11 +33,17 @@ use crate::space::{
 };
 
 use self::{
-    events::{NetAtmosphericsNotices, NetMapDisplayAtmospherics, NetMapHoverAtmospherics},
-    resources::{MapHolders, RigidBodyForcesAccumulation},
+    events::{
+        IgniteAtmospherics, NetAtmosphericsNotices, NetMapDisplayAtmospherics,
+        NetMapHoverAtmospherics,
+    },
+    resources::{MapHolders, PendingIgnitions, RigidBodyForcesAccumulation},
     systems::{
+        combustion::{atmos_combustion, queue_ignitions},
         diffusion::{atmos_diffusion, DIFFUSION_STEP},
         effects::atmos_effects,
+        fire_damage::atmos_fire_damage,
+        heat_conduction::atmos_heat_conduction,
         map::atmospherics_map,
         map_hover::atmospherics_map_hover,
         notices::atmospherics_notices,
@@ -68,6 +82,8 @@ pub fn startup_atmospherics(
 
         let blocked;
         let push_up;
+        let thermal_conductivity;
+        let heat_capacity;
 
         match gridmap_main.grid_data.get(&Vec3Int {
             x: current_cell_id.x,
@@ -81,10 +97,14 @@ pub fn startup_atmospherics(
                     .unwrap();
                 blocked = properties.atmospherics_blocker;
                 push_up = properties.atmospherics_pushes_up;
+                thermal_conductivity = properties.thermal_conductivity;
+                heat_capacity = properties.heat_capacity;
             }
             None => {
                 blocked = false;
                 push_up = false;
+                thermal_conductivity = DEFAULT_THERMAL_CONDUCTIVITY;
+                heat_capacity = DEFAULT_HEAT_CAPACITY;
             }
         }
 
@@ -104,14 +124,19 @@ pub fn startup_atmospherics(
         }
 
         if internal {
-            atmospherics.atmospherics[get_atmos_index(current_cell_id)] =
-                Atmospherics::new_internal(blocked, push_up);
+            atmospherics.atmospherics[get_atmos_index(current_cell_id)] = Atmospherics {
+                thermal_conductivity,
+                heat_capacity,
+                ..Atmospherics::new_internal(blocked, push_up)
+            };
         } else {
             let flags = vec!["default_vacuum".to_string()];
             atmospherics.atmospherics[get_atmos_index(current_cell_id)] = Atmospherics {
                 blocked,
                 flags,
                 forces_push_up: push_up,
+                thermal_conductivity,
+                heat_capacity,
                 ..Default::default()
             };
             vacuum_cells += 1;
@@ -133,6 +158,48 @@ pub fn startup_atmospherics(
     );
 }
 
+// Start the server with --atmos-snapshot-load <name> to restore a snapshot for reproducible tests,
+// and with --atmos-snapshot <name> to export the atmospherics the server starts out with.
+pub fn startup_atmospherics_snapshot(mut atmospherics: ResMut<AtmosphericsResource>) {
+    let args: Vec<String> = env::args().collect();
+
+    for (i, arg) in args.iter().enumerate() {
+        if arg == "--atmos-snapshot-load" {
+            match args.get(i + 1) {
+                Some(name) => match load_atmospherics_snapshot(name, &mut atmospherics) {
+                    Ok(loaded_cells) => {
+                        info!("Loaded {} atmospherics cells from snapshot.", loaded_cells);
+                    }
+                    Err(rr) => {
+                        warn!("Failed to load atmospherics snapshot: {}", rr);
+                    }
+                },
+                None => {
+                    warn!("--atmos-snapshot-load requires a snapshot name.");
+                }
+            }
+        }
+    }
+
+    for (i, arg) in args.iter().enumerate() {
+        if arg == "--atmos-snapshot" {
+            match args.get(i + 1) {
+                Some(name) => match export_atmospherics_snapshot(name, &atmospherics) {
+                    Ok(_) => {
+                        info!("Exported atmospherics snapshot {}.", name);
+                    }
+                    Err(rr) => {
+                        warn!("Failed to export atmospherics snapshot: {}", rr);
+                    }
+                },
+                None => {
+                    warn!("--atmos-snapshot requires a snapshot name.");
+                }
+            }
+        }
+    }
+}
+
 pub struct AtmosphericsPlugin;
 
 impl Plugin for AtmosphericsPlugin {
@@ -152,6 +219,7 @@ impl Plugin for AtmosphericsPlugin {
                 SystemSet::new()
                     .with_run_criteria(FixedTimestep::step(1. / 4.).with_label(ATMOS_LABEL))
                     .with_system(atmospherics_notices)
+                    .with_system(atmos_fire_damage)
                     .with_system(atmospherics_map.after(MapLabels::ChangeMode)),
             )
             .add_event::<NetMapDisplayAtmospherics>()
@@ -162,6 +230,16 @@ impl Plugin for AtmosphericsPlugin {
                         FixedTimestep::step(1. / DIFFUSION_STEP).with_label(ATMOS_DIFFUSION_LABEL),
                     )
                     .with_system(atmos_diffusion.label(AtmosphericsLabels::Diffusion))
+                    .with_system(
+                        atmos_heat_conduction
+                            .after(AtmosphericsLabels::Diffusion)
+                            .before(AtmosphericsLabels::Effects),
+                    )
+                    .with_system(
+                        atmos_combustion
+                            .after(AtmosphericsLabels::Diffusion)
+                            .before(AtmosphericsLabels::Effects),
+                    )
                     .with_system(
                         atmos_effects
                             .after(AtmosphericsLabels::Diffusion)
@@ -176,6 +254,12 @@ impl Plugin for AtmosphericsPlugin {
                     .label(StartupLabels::InitAtmospherics)
                     .after(StartupLabels::BuildGridmap),
             )
-            .add_event::<NetAtmosphericsNotices>();
+            .add_startup_system(
+                startup_atmospherics_snapshot.after(StartupLabels::InitAtmospherics),
+            )
+            .add_event::<NetAtmosphericsNotices>()
+            .add_event::<IgniteAtmospherics>()
+            .init_resource::<PendingIgnitions>()
+            .add_system(queue_ignitions);
     }
 }
@@ -49,6 +49,14 @@ pub struct Atmospherics {
     pub temperature: f32,
     //Mol
     pub amount: f32,
+    //Mol, part of amount.
+    pub oxidiser: f32,
+    //Mol, part of amount.
+    pub fuel: f32,
+    pub burning: bool,
+    // Only used while blocked, the cell then conducts heat like the wall that blocks it.
+    pub thermal_conductivity: f32,
+    pub heat_capacity: f32,
     pub flags: Vec<String>,
     pub effects: HashMap<EffectType, AtmosEffect>,
     pub forces_push_up: bool,
@@ -79,6 +87,11 @@ impl Default for Atmospherics {
             blocked: false,
             temperature: -270.45 + CELCIUS_KELVIN_OFFSET,
             amount: 0.,
+            oxidiser: 0.,
+            fuel: 0.,
+            burning: false,
+            thermal_conductivity: DEFAULT_THERMAL_CONDUCTIVITY,
+            heat_capacity: DEFAULT_HEAT_CAPACITY,
             effects: effects,
             flags: vec![],
             forces_push_up: false,
@@ -88,6 +101,10 @@ impl Default for Atmospherics {
 
 pub const CELCIUS_KELVIN_OFFSET: f32 = 273.15;
 pub const DEFAULT_INTERNAL_AMOUNT: f32 = 84.58;
+pub const DEFAULT_OXIDISER_RATIO: f32 = 0.21;
+pub const DEFAULT_THERMAL_CONDUCTIVITY: f32 = 0.001;
+// An aluminum wall holds about as much heat as twelve cells of air.
+pub const DEFAULT_HEAT_CAPACITY: f32 = 1000.;
 
 impl Atmospherics {
     pub fn new_internal(blocked: bool, forces_push_up: bool) -> Self {
@@ -95,6 +112,11 @@ impl Atmospherics {
             blocked,
             temperature: 20. + CELCIUS_KELVIN_OFFSET,
             amount: DEFAULT_INTERNAL_AMOUNT,
+            oxidiser: DEFAULT_INTERNAL_AMOUNT * DEFAULT_OXIDISER_RATIO,
+            fuel: 0.,
+            burning: false,
+            thermal_conductivity: DEFAULT_THERMAL_CONDUCTIVITY,
+            heat_capacity: DEFAULT_HEAT_CAPACITY,
             effects: HashMap::new(),
             flags: vec![],
             forces_push_up,
@@ -104,8 +126,23 @@ impl Atmospherics {
         // Return kpa
         (((self.amount * 0.08206 * self.temperature) / 2000.) * 101325.) / 1000.
     }
+    pub fn is_flammable(&self) -> bool {
+        self.fuel >= MINIMUM_COMBUSTION_FUEL && self.oxidiser >= MINIMUM_COMBUSTION_OXIDISER
+    }
+    // Add or remove gas while keeping the ratio of oxidiser and fuel intact.
+    pub fn scale_amount(&mut self, new_amount: f32) {
+        if self.amount > 0. {
+            let ratio = new_amount / self.amount;
+            self.oxidiser *= ratio;
+            self.fuel *= ratio;
+        }
+        self.amount = new_amount;
+    }
 }
 
+pub const MINIMUM_COMBUSTION_FUEL: f32 = 0.5;
+pub const MINIMUM_COMBUSTION_OXIDISER: f32 = 0.5;
+
 #[derive(Default)]
 pub struct MapHolders {
     pub holders: HashMap<Entity, MapHolderData>,
@@ -155,3 +192,9 @@ impl Default for RigidBodyForcesAccumulation {
         }
     }
 }
+
+// Ignitions sent in between diffusion steps, applied by the next one.
+#[derive(Default)]
+pub struct PendingIgnitions {
+    pub cell_ids: Vec<Vec2Int>,
+}
@@ -0,0 +1,120 @@
+use bevy_app::EventReader;
+use bevy_ecs::system::ResMut;
+use rand::Rng;
+
+use crate::space::core::atmospherics::{
+    events::IgniteAtmospherics,
+    functions::{get_atmos_id, get_atmos_index},
+    resources::{AtmosphericsResource, PendingIgnitions, CELCIUS_KELVIN_OFFSET},
+};
+
+// Mol of fuel burnt per diffusion step in a burning cell.
+const COMBUSTION_RATE: f32 = 0.05;
+// Mol of oxidiser consumed per mol of fuel burnt.
+const OXIDISER_PER_FUEL: f32 = 2.;
+// Kelvin * mol released per mol of fuel burnt.
+const COMBUSTION_HEAT: f32 = 800.;
+// Flammable gas ignites by itself once it gets this hot.
+const AUTO_IGNITION_TEMPERATURE: f32 = 300. + CELCIUS_KELVIN_OFFSET;
+// Chance per diffusion step that a burning cell sets an adjacent flammable cell alight.
+const FIRE_SPREAD_CHANCE: f32 = 0.1;
+
+// Runs every frame so no ignition event expires before the fixed diffusion step gets to it.
+pub fn queue_ignitions(
+    mut ignite_events: EventReader<IgniteAtmospherics>,
+    mut pending_ignitions: ResMut<PendingIgnitions>,
+) {
+    for event in ignite_events.iter() {
+        pending_ignitions.cell_ids.push(event.cell_id);
+    }
+}
+
+pub fn atmos_combustion(
+    mut pending_ignitions: ResMut<PendingIgnitions>,
+    mut atmospherics_resource: ResMut<AtmosphericsResource>,
+) {
+    for cell_id in pending_ignitions.cell_ids.drain(..) {
+        if AtmosphericsResource::is_id_out_of_range(cell_id) {
+            continue;
+        }
+
+        let atmospherics = atmospherics_resource
+            .atmospherics
+            .get_mut(get_atmos_index(cell_id))
+            .unwrap();
+
+        if !atmospherics.blocked && atmospherics.is_flammable() {
+            atmospherics.burning = true;
+        }
+    }
+
+    let mut rng = rand::thread_rng();
+
+    let mut spread_cell_ids = vec![];
+
+    for (i, atmospherics) in atmospherics_resource.atmospherics.iter_mut().enumerate() {
+        if atmospherics.blocked || atmospherics.fuel <= 0. {
+            atmospherics.burning = false;
+            continue;
+        }
+
+        if !atmospherics.burning {
+            if atmospherics.temperature >= AUTO_IGNITION_TEMPERATURE && atmospherics.is_flammable()
+            {
+                atmospherics.burning = true;
+            } else {
+                continue;
+            }
+        }
+
+        if !atmospherics.is_flammable() {
+            atmospherics.burning = false;
+            continue;
+        }
+
+        let burnt_fuel = COMBUSTION_RATE
+            .min(atmospherics.fuel)
+            .min(atmospherics.oxidiser / OXIDISER_PER_FUEL);
+
+        atmospherics.fuel -= burnt_fuel;
+        atmospherics.oxidiser -= burnt_fuel * OXIDISER_PER_FUEL;
+        atmospherics.amount -= burnt_fuel * OXIDISER_PER_FUEL;
+
+        if atmospherics.amount > 0. {
+            atmospherics.temperature += (burnt_fuel * COMBUSTION_HEAT) / atmospherics.amount;
+        }
+
+        if rng.gen::<f32>() < FIRE_SPREAD_CHANCE {
+            spread_cell_ids.push(get_atmos_id(i));
+        }
+    }
+
+    for cell_id in spread_cell_ids {
+        for j in 0..4 {
+            let mut adjacent_cell_id = cell_id.clone();
+
+            if j == 0 {
+                adjacent_cell_id.x += 1;
+            } else if j == 1 {
+                adjacent_cell_id.x -= 1;
+            } else if j == 2 {
+                adjacent_cell_id.y += 1;
+            } else {
+                adjacent_cell_id.y -= 1;
+            }
+
+            if AtmosphericsResource::is_id_out_of_range(adjacent_cell_id) {
+                continue;
+            }
+
+            let adjacent_atmospherics = atmospherics_resource
+                .atmospherics
+                .get_mut(get_atmos_index(adjacent_cell_id))
+                .unwrap();
+
+            if !adjacent_atmospherics.blocked && adjacent_atmospherics.is_flammable() {
+                adjacent_atmospherics.burning = true;
+            }
+        }
+    }
+}
@@ -71,6 +71,8 @@ pub fn atmos_diffusion(
 
         let mut total_temperature = 0.;
         let mut total_amount = 0.;
+        let mut total_oxidiser = 0.;
+        let mut total_fuel = 0.;
 
         let mut non_blocking_adjacents: u8 = 0;
 
@@ -101,6 +103,8 @@ pub fn atmos_diffusion(
                             non_blocking_adjacents += 1;
                             total_temperature += a.temperature;
                             total_amount += a.amount;
+                            total_oxidiser += a.oxidiser;
+                            total_fuel += a.fuel;
                         }
                         out_of_range = false;
                     }
@@ -130,6 +134,12 @@ pub fn atmos_diffusion(
         let new_amount = (current_cell_atmos.amount
             + AMOUNT_DIFFUSIVITY * (total_amount / non_blocking_adjacents as f32))
             / (1. + AMOUNT_DIFFUSIVITY);
+        let new_oxidiser = (current_cell_atmos.oxidiser
+            + AMOUNT_DIFFUSIVITY * (total_oxidiser / non_blocking_adjacents as f32))
+            / (1. + AMOUNT_DIFFUSIVITY);
+        let new_fuel = (current_cell_atmos.fuel
+            + AMOUNT_DIFFUSIVITY * (total_fuel / non_blocking_adjacents as f32))
+            / (1. + AMOUNT_DIFFUSIVITY);
 
         let current_cell_atmos = atmospherics
             .atmospherics
@@ -138,5 +148,7 @@ pub fn atmos_diffusion(
 
         current_cell_atmos.temperature = new_temperature;
         current_cell_atmos.amount = new_amount;
+        current_cell_atmos.oxidiser = new_oxidiser;
+        current_cell_atmos.fuel = new_fuel;
     }
 }
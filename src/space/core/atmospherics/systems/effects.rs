@@ -64,13 +64,15 @@ pub fn atmos_effects(
             }
         }
 
-        atmospherics.amount += total_amount_additive * ATMOS_EFFECT_SPEED;
+        let mut new_amount = atmospherics.amount + total_amount_additive * ATMOS_EFFECT_SPEED;
         atmospherics.temperature += total_temperature_additive * ATMOS_EFFECT_SPEED;
 
-        if atmospherics.amount < 0. {
-            atmospherics.amount = 0.;
+        if new_amount < 0. {
+            new_amount = 0.;
         }
 
+        atmospherics.scale_amount(new_amount);
+
         if atmospherics.temperature < -270.45 + CELCIUS_KELVIN_OFFSET {
             atmospherics.temperature = -270.45 + CELCIUS_KELVIN_OFFSET;
         }
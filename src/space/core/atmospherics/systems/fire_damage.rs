@@ -0,0 +1,136 @@
+use bevy_ecs::system::{Query, Res, ResMut};
+use bevy_rapier3d::prelude::RigidBodyPositionComponent;
+
+use crate::space::core::{
+    atmospherics::{functions::get_atmos_index, resources::AtmosphericsResource},
+    gridmap::{
+        functions::gridmap_functions::world_to_cell_id,
+        resources::{GridmapMain, Vec2Int, Vec3Int},
+    },
+    health::components::{DamageModel, Health},
+};
+
+// Burn damage dealt per atmospherics tick (1/4 s) to anything standing in fire.
+// Humanoids get it spread over their limbs.
+const FIRE_ENTITY_BURN_DAMAGE: f32 = 6.;
+const FIRE_STRUCTURE_BURN_DAMAGE: f32 = 1.;
+
+pub fn atmos_fire_damage(
+    atmospherics_resource: Res<AtmosphericsResource>,
+    mut gridmap_main: ResMut<GridmapMain>,
+    mut health_entities: Query<(&mut Health, &RigidBodyPositionComponent)>,
+) {
+    let entity_damage_model = DamageModel {
+        burn: FIRE_ENTITY_BURN_DAMAGE,
+        ..Default::default()
+    };
+
+    for (mut health_component, rigid_body_position_component) in health_entities.iter_mut() {
+        let cell_id = world_to_cell_id(rigid_body_position_component.position.translation.into());
+        let cell_id2 = Vec2Int {
+            x: cell_id.x,
+            y: cell_id.z,
+        };
+
+        if AtmosphericsResource::is_id_out_of_range(cell_id2) {
+            continue;
+        }
+
+        if !atmospherics_resource
+            .atmospherics
+            .get(get_atmos_index(cell_id2))
+            .unwrap()
+            .burning
+        {
+            continue;
+        }
+
+        health_component.apply_environment_damage(&entity_damage_model);
+    }
+
+    let structure_damage_model = DamageModel {
+        burn: FIRE_STRUCTURE_BURN_DAMAGE,
+        ..Default::default()
+    };
+
+    for (cell_data_id, cell_data) in gridmap_main.grid_data.iter_mut() {
+        if cell_data_id.y != -1 && cell_data_id.y != 0 {
+            continue;
+        }
+
+        let cell_id2 = Vec2Int {
+            x: cell_data_id.x,
+            y: cell_data_id.z,
+        };
+
+        if AtmosphericsResource::is_id_out_of_range(cell_id2) {
+            continue;
+        }
+
+        let atmos_index = get_atmos_index(cell_id2);
+
+        let burning;
+
+        if cell_data_id.y == 0 {
+            // Walls block the atmosphere of their own cell, they burn from the side instead.
+            burning = get_adjacent_burning(cell_data_id, &atmospherics_resource);
+        } else {
+            burning = atmospherics_resource
+                .atmospherics
+                .get(atmos_index)
+                .unwrap()
+                .burning;
+        }
+
+        if burning {
+            cell_data
+                .health
+                .apply_environment_damage(&structure_damage_model);
+        }
+    }
+}
+
+fn get_adjacent_burning(cell_id: &Vec3Int, atmospherics_resource: &AtmosphericsResource) -> bool {
+    let own_cell_id = Vec2Int {
+        x: cell_id.x,
+        y: cell_id.z,
+    };
+
+    if atmospherics_resource
+        .atmospherics
+        .get(get_atmos_index(own_cell_id))
+        .unwrap()
+        .burning
+    {
+        return true;
+    }
+
+    for j in 0..4 {
+        let mut adjacent_cell_id = own_cell_id.clone();
+
+        if j == 0 {
+            adjacent_cell_id.x += 1;
+        } else if j == 1 {
+            adjacent_cell_id.x -= 1;
+        } else if j == 2 {
+            adjacent_cell_id.y += 1;
+        } else {
+            adjacent_cell_id.y -= 1;
+        }
+
+        if AtmosphericsResource::is_id_out_of_range(adjacent_cell_id) {
+            continue;
+        }
+
+        if atmospherics_resource
+            .atmospherics
+            .get(get_atmos_index(adjacent_cell_id))
+            .unwrap()
+            .burning
+        {
+            return true;
+        }
+    }
+
+    false
+}
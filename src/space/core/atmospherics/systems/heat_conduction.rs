@@ -0,0 +1,101 @@
+use bevy_ecs::system::ResMut;
+
+use crate::space::core::{
+    atmospherics::{
+        functions::get_atmos_index,
+        resources::{AtmosphericsResource, EffectType, CELCIUS_KELVIN_OFFSET},
+    },
+    gridmap::resources::{Vec2Int, FOV_MAP_WIDTH},
+};
+
+const SPACE_TEMPERATURE: f32 = -270.45 + CELCIUS_KELVIN_OFFSET;
+// Radiating into space is a lot slower than conducting into air.
+const SPACE_RADIATION_FACTOR: f32 = 0.01;
+
+// Diffusion skips blocked cells, here they exchange heat with whatever surrounds them.
+// Heat capacity of air is the amount of mol in the cell.
+pub fn atmos_heat_conduction(mut atmospherics_resource: ResMut<AtmosphericsResource>) {
+    let default_x = FOV_MAP_WIDTH as i16 / 2;
+
+    let mut current_cell_id = Vec2Int {
+        x: -default_x - 1,
+        y: -default_x,
+    };
+
+    for _i in 0..FOV_MAP_WIDTH * FOV_MAP_WIDTH {
+        current_cell_id.x += 1;
+
+        if current_cell_id.x > default_x {
+            current_cell_id.x = -default_x;
+            current_cell_id.y += 1;
+        }
+
+        let current_i = get_atmos_index(current_cell_id);
+
+        if !atmospherics_resource.atmospherics[current_i].blocked {
+            continue;
+        }
+
+        for j in 0..4 {
+            let mut adjacent_cell_id = current_cell_id.clone();
+
+            if j == 0 {
+                adjacent_cell_id.x += 1;
+            } else if j == 1 {
+                adjacent_cell_id.x -= 1;
+            } else if j == 2 {
+                adjacent_cell_id.y += 1;
+            } else {
+                adjacent_cell_id.y -= 1;
+            }
+
+            if AtmosphericsResource::is_id_out_of_range(adjacent_cell_id) {
+                continue;
+            }
+
+            let adjacent_i = get_atmos_index(adjacent_cell_id);
+
+            let current = &atmospherics_resource.atmospherics[current_i];
+            let adjacent = &atmospherics_resource.atmospherics[adjacent_i];
+
+            let conductivity;
+            let adjacent_heat_capacity;
+
+            if adjacent.blocked {
+                // Wall to wall pairs are visited from both sides, only exchange once.
+                if j == 1 || j == 3 {
+                    continue;
+                }
+                conductivity = current
+                    .thermal_conductivity
+                    .min(adjacent.thermal_conductivity);
+                adjacent_heat_capacity = adjacent.heat_capacity;
+            } else if adjacent.effects.contains_key(&EffectType::Floorless) {
+                let current = &mut atmospherics_resource.atmospherics[current_i];
+                current.temperature -= current.thermal_conductivity
+                    * SPACE_RADIATION_FACTOR
+                    * (current.temperature - SPACE_TEMPERATURE);
+                continue;
+            } else {
+                conductivity = current.thermal_conductivity;
+                adjacent_heat_capacity = adjacent.amount;
+            }
+
+            if adjacent_heat_capacity <= 0. || current.heat_capacity <= 0. {
+                continue;
+            }
+
+            let heat = conductivity
+                * (adjacent.temperature - current.temperature)
+                * ((current.heat_capacity * adjacent_heat_capacity)
+                    / (current.heat_capacity + adjacent_heat_capacity));
+
+            let current_heat_capacity = current.heat_capacity;
+
+            atmospherics_resource.atmospherics[current_i].temperature +=
+                heat / current_heat_capacity;
+            atmospherics_resource.atmospherics[adjacent_i].temperature -=
+                heat / adjacent_heat_capacity;
+        }
+    }
+}
@@ -11,7 +11,10 @@ use crate::space::core::{
     atmospherics::{
         events::NetMapDisplayAtmospherics,
         functions::{get_atmos_id, get_atmos_index},
-        resources::{AtmosphericsResource, MapHolderData, MapHolders, CELCIUS_KELVIN_OFFSET},
+        resources::{
+            AtmosphericsResource, MapHolderData, MapHolders, CELCIUS_KELVIN_OFFSET,
+            MINIMUM_COMBUSTION_FUEL,
+        },
     },
     connected_player::components::ConnectedPlayer,
     gridmap::{
@@ -31,6 +34,7 @@ enum SelectedDisplayMode {
     Temperature,
     Pressure,
     Liveable,
+    Fire,
 }
 
 pub fn atmospherics_map(
@@ -50,6 +54,8 @@ pub fn atmospherics_map(
                     show_temperature = SelectedDisplayMode::Pressure;
                 } else if selected_display_mode == "atmospherics_liveable" {
                     show_temperature = SelectedDisplayMode::Liveable;
+                } else if selected_display_mode == "atmospherics_fire" {
+                    show_temperature = SelectedDisplayMode::Fire;
                 } else {
                     continue;
                 }
@@ -208,6 +214,16 @@ pub fn atmospherics_map(
             let new_tile_color;
 
             match show_temperature {
+                _ if atmospherics_data.burning => {
+                    // Fire is dangerous whatever is being looked at.
+                    item = get_overlay_tile_item(&OverlayTile::Red);
+                    new_tile_color = OverlayTile::Red;
+                }
+                SelectedDisplayMode::Fire => {
+                    let tile_color = fuel_to_tile_color(atmospherics_data.fuel);
+                    item = get_overlay_tile_item(&tile_color);
+                    new_tile_color = tile_color;
+                }
                 SelectedDisplayMode::Temperature => {
                     let tile_color = temperature_to_tile_color(atmospherics_data.temperature);
                     item = get_overlay_tile_item(&tile_color);
@@ -390,6 +406,16 @@ fn temperature_to_tile_color(temperature: f32) -> OverlayTile {
     }
 }
 
+fn fuel_to_tile_color(fuel: f32) -> OverlayTile {
+    if fuel >= MINIMUM_COMBUSTION_FUEL {
+        OverlayTile::Orange
+    } else if fuel > 0. {
+        OverlayTile::Yellow
+    } else {
+        OverlayTile::Green
+    }
+}
+
 pub const MINIMUM_LIVABLE_PRESSURE: f32 = 90.;
 pub const MAXIMUM_LIVABLE_PRESSURE: f32 = 180.;
 
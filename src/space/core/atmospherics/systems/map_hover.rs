@@ -35,7 +35,7 @@ pub fn atmospherics_map_hover(
 
                 let cell_atmos = atmospherics.atmospherics.get(cell_i).unwrap();
 
-                let data;
+                let mut data;
 
                 if cell_atmos.blocked {
                     data = "".to_string();
@@ -48,6 +48,15 @@ pub fn atmospherics_map_hover(
                         + "Pressure: "
                         + &cell_atmos.get_pressure().floor().to_string()
                         + " kpa";
+                    if cell_atmos.fuel > 0. {
+                        data = data
+                            + "\nFuel: "
+                            + &((cell_atmos.fuel * 10.).floor() / 10.).to_string()
+                            + " mol";
+                    }
+                    if cell_atmos.burning {
+                        data = data + "\nBurning";
+                    }
                 }
 
                 match display_atmos_state.holders.get_mut(&map_holder_entity) {
@@ -1,5 +1,8 @@
+pub mod combustion;
 pub mod diffusion;
 pub mod effects;
+pub mod fire_damage;
+pub mod heat_conduction;
 pub mod map;
 pub mod map_hover;
 pub mod notices;
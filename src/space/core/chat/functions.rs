@@ -415,9 +415,35 @@ pub fn new_global_message(
     }
 }
 
+// Italic text only the given entity gets to read, does nothing if it is not controlled by a player.
+pub fn new_personal_message(
+    net_new_chat_message_event: &mut EventWriter<NetChatMessage>,
+    handle_to_entity: &Res<HandleToEntity>,
+    entity: Entity,
+    text: &str,
+) {
+    match handle_to_entity.inv_map.get(&entity) {
+        Some(handle) => {
+            net_new_chat_message_event.send(NetChatMessage {
+                handle: *handle,
+                message: ReliableServerMessage::ChatMessage(
+                    "[font=".to_owned() + FURTHER_ITALIC_FONT + "]" + text + "[/font]",
+                ),
+            });
+        }
+        None => {}
+    }
+}
+
 pub enum MessagingPlayerState {
     SoftConnected,
     Alive,
+    // Too weak to use the radio.
+    Critical,
+    // Cannot speak at all.
+    Unconscious,
+    // Only takes part in the global chat.
+    Dead,
 }
 
 pub fn new_chat_message(
@@ -442,6 +468,10 @@ pub fn new_chat_message(
     mut net_send_entity_updates_option: Option<&mut EventWriter<NetSendEntityUpdates>>,
     messaging_player_state: &MessagingPlayerState,
 ) {
+    if matches!(messaging_player_state, &MessagingPlayerState::Unconscious) {
+        return;
+    }
+
     if raw_message.len() > 500 {
         raw_message = raw_message[..500].to_string();
     }
@@ -517,7 +547,9 @@ pub fn new_chat_message(
         }
     }
 
-    if matches!(messaging_player_state, &MessagingPlayerState::SoftConnected) {
+    if matches!(messaging_player_state, &MessagingPlayerState::SoftConnected)
+        || matches!(messaging_player_state, &MessagingPlayerState::Dead)
+    {
         radio_channel = RadioChannel::Global;
     }
 
@@ -563,6 +595,10 @@ pub fn new_chat_message(
         exclusive_proximity = false;
     }
 
+    if matches!(messaging_player_state, &MessagingPlayerState::Critical) {
+        exclusive_proximity = true;
+    }
+
     if message.len() == 0 {
         return;
     }
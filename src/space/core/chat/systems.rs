@@ -13,6 +13,8 @@ use crate::space::core::{
         resources::HandleToEntity,
     },
     entity::events::NetSendEntityUpdates,
+    health::components::HealthState,
+    humanoid::components::Humanoid,
     pawn::components::{Pawn, PersistentPlayerData, SpaceJobsEnum},
     sensable::components::Sensable,
 };
@@ -37,6 +39,7 @@ pub fn chat_message_input_event(
     mut net_new_chat_message_event: EventWriter<NetChatMessage>,
     mut net_send_entity_updates: EventWriter<NetSendEntityUpdates>,
     global_listeners: Query<(&ConnectedPlayer, &PersistentPlayerData)>,
+    humanoids: Query<&Humanoid>,
 ) {
     for chat_message_input_event in chat_message_input_events.iter() {
         let player_pawn_entity;
@@ -51,6 +54,28 @@ pub fn chat_message_input_event(
                 let translation = player_components.1.position.translation;
                 player_position = Vec3::new(translation.x, translation.y, translation.z);
 
+                let messaging_player_state;
+
+                match humanoids.get(player_pawn_entity) {
+                    Ok(humanoid_component) => match humanoid_component.health_state {
+                        HealthState::Healthy => {
+                            messaging_player_state = MessagingPlayerState::Alive;
+                        }
+                        HealthState::Critical => {
+                            messaging_player_state = MessagingPlayerState::Critical;
+                        }
+                        HealthState::Unconscious => {
+                            messaging_player_state = MessagingPlayerState::Unconscious;
+                        }
+                        HealthState::Dead => {
+                            messaging_player_state = MessagingPlayerState::Dead;
+                        }
+                    },
+                    Err(_rr) => {
+                        messaging_player_state = MessagingPlayerState::Alive;
+                    }
+                }
+
                 new_chat_message(
                     &mut net_new_chat_message_event,
                     &handle_to_entity,
@@ -66,7 +91,7 @@ pub fn chat_message_input_event(
                     &global_listeners,
                     Some(&player_pawn_entity),
                     Some(&mut net_send_entity_updates),
-                    &MessagingPlayerState::Alive,
+                    &messaging_player_state,
                 );
             }
             Err(_) => {
@@ -0,0 +1,17 @@
+use bevy_ecs::prelude::Component;
+
+// Items that hold raegents, the raegents themselves are kept in the raegent container of their Health component.
+#[derive(Component)]
+pub struct ReagentHolder {
+    pub capacity: f32,
+    pub transfer_amount: f32,
+    pub transfer_method: ReagentTransferMethod,
+}
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum ReagentTransferMethod {
+    // Syringes, can also draw from other holders.
+    Inject,
+    // Beakers and pill bottles, fed to whoever is targetted.
+    Ingest,
+}
@@ -0,0 +1,9 @@
+use bevy_ecs::entity::Entity;
+
+pub struct InputTransferReagents {
+    pub user: Entity,
+    pub holder: Entity,
+    pub target: Entity,
+    // Draw from the target into the holder instead of the other way around.
+    pub draw: bool,
+}
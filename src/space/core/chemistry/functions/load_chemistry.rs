@@ -0,0 +1,63 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use serde::de::DeserializeOwned;
+
+use crate::space::core::chemistry::resources::{ReactionDefinition, ReagentDefinition};
+
+const REAGENTS_FILE: &str = "reagents.json";
+const REACTIONS_FILE: &str = "reactions.json";
+
+pub fn load_chemistry(
+    directory: &Path,
+) -> Result<(HashMap<String, ReagentDefinition>, Vec<ReactionDefinition>), String> {
+    let reagent_definitions: Vec<ReagentDefinition> =
+        read_definitions(&directory.join(REAGENTS_FILE))?;
+    let reactions: Vec<ReactionDefinition> = read_definitions(&directory.join(REACTIONS_FILE))?;
+
+    let mut reagents = HashMap::new();
+
+    for reagent in reagent_definitions {
+        if reagent.name.is_empty() {
+            return Err("Reagent without a name.".to_string());
+        }
+        if reagent.metabolism_rate < 0. {
+            return Err(reagent.name + ": metabolism_rate cannot be negative.");
+        }
+        if reagents.contains_key(&reagent.name) {
+            return Err("Duplicate reagent ".to_string() + &reagent.name);
+        }
+        reagents.insert(reagent.name.clone(), reagent);
+    }
+
+    // Catch typos in the data files at startup rather than when the reaction never happens.
+    for reaction in reactions.iter() {
+        for (reagent_name, ratio) in reaction.reactants.iter().chain(reaction.products.iter()) {
+            if !reagents.contains_key(reagent_name) {
+                return Err(reaction.name.to_string() + ": unknown reagent " + reagent_name);
+            }
+            if *ratio <= 0. {
+                return Err(reaction.name.to_string() + ": ratios have to be above zero.");
+            }
+        }
+    }
+
+    Ok((reagents, reactions))
+}
+
+fn read_definitions<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>, String> {
+    let raw_json;
+
+    match fs::read_to_string(path) {
+        Ok(s) => {
+            raw_json = s;
+        }
+        Err(rr) => {
+            return Err(path.to_string_lossy().to_string() + ": " + &rr.to_string());
+        }
+    }
+
+    match serde_json::from_str(&raw_json) {
+        Ok(definitions) => Ok(definitions),
+        Err(rr) => Err(path.to_string_lossy().to_string() + ": " + &rr.to_string()),
+    }
+}
@@ -0,0 +1,3 @@
+pub mod load_chemistry;
+pub mod react;
+pub mod reagent_tab_actions;
@@ -0,0 +1,42 @@
+use crate::space::core::{
+    chemistry::resources::ReactionDefinition, health::components::RaegentContainer,
+};
+
+// Products can react again, limit the passes so reactions that undo each other cannot loop forever.
+const MAX_REACTION_PASSES: u8 = 8;
+const REACTION_EPSILON: f32 = 0.001;
+
+pub fn react(container: &mut RaegentContainer, reactions: &Vec<ReactionDefinition>) {
+    for _pass in 0..MAX_REACTION_PASSES {
+        let mut reacted = false;
+
+        for reaction in reactions.iter() {
+            if reaction.reactants.is_empty() {
+                continue;
+            }
+
+            let mut times = f32::MAX;
+
+            for (reactant, ratio) in reaction.reactants.iter() {
+                times = times.min(container.get_amount(reactant) / ratio);
+            }
+
+            if times <= REACTION_EPSILON {
+                continue;
+            }
+
+            for (reactant, ratio) in reaction.reactants.iter() {
+                container.remove(reactant, ratio * times);
+            }
+            for (product, ratio) in reaction.products.iter() {
+                container.add(product, ratio * times);
+            }
+
+            reacted = true;
+        }
+
+        if !reacted {
+            break;
+        }
+    }
+}
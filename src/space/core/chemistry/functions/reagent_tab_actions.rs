@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use bevy_ecs::{entity::Entity, system::Query};
+
+use crate::space::core::{
+    chemistry::components::ReagentTransferMethod,
+    data_link::components::DataLink,
+    entity::{components::EntityData, resources::EntityDataResource},
+    gridmap::resources::CellData,
+    inventory::components::Inventory,
+    networking::resources::GridMapType,
+    pawn::functions::can_reach_entity::REACH_DISTANCE,
+    tab_actions::components::TabAction,
+};
+
+// Tab actions the holder offers while it is in the active hand.
+pub fn get_reagent_tab_actions(
+    holder_entity: Entity,
+    transfer_method: ReagentTransferMethod,
+) -> Vec<TabAction> {
+    let mut tab_actions = vec![];
+
+    let transfer_text;
+
+    match transfer_method {
+        ReagentTransferMethod::Inject => {
+            transfer_text = "Inject";
+        }
+        ReagentTransferMethod::Ingest => {
+            transfer_text = "Feed";
+        }
+    }
+
+    tab_actions.push(TabAction {
+        id: "reagenttransfer".to_string(),
+        text: transfer_text.to_string(),
+        tab_list_priority: 60,
+        prerequisite_check: Arc::new(reagent_use_action),
+        belonging_entity: Some(holder_entity),
+    });
+
+    if transfer_method == ReagentTransferMethod::Inject {
+        tab_actions.push(TabAction {
+            id: "reagentdraw".to_string(),
+            text: "Draw".to_string(),
+            tab_list_priority: 59,
+            prerequisite_check: Arc::new(reagent_use_action),
+            belonging_entity: Some(holder_entity),
+        });
+    }
+
+    tab_actions
+}
+
+pub fn reagent_use_action(
+    self_tab_entity_option: Option<Entity>,
+    entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    distance: f32,
+    inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    match (self_tab_entity_option, entity_id_bits_option) {
+        (Some(self_tab_entity), Some(target_entity_bits)) => {
+            distance < REACH_DISTANCE
+                && self_tab_entity.to_bits() != target_entity_bits
+                && inventory_component.has_item(self_tab_entity)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,63 @@
+use std::path::Path;
+
+use bevy_app::{App, Plugin};
+use bevy_core::FixedTimestep;
+use bevy_ecs::{
+    schedule::{ParallelSystemDescriptorCoercion, SystemSet},
+    system::ResMut,
+};
+use bevy_log::info;
+
+use crate::space::StartupLabels;
+
+use self::{
+    events::InputTransferReagents,
+    functions::load_chemistry::load_chemistry,
+    resources::ChemistryResource,
+    systems::{
+        metabolism::{metabolism, METABOLISM_INTERVAL},
+        reagent_examine_text::reagent_examine_text,
+        transfer_reagents::transfer_reagents,
+    },
+};
+
+pub mod components;
+pub mod events;
+pub mod functions;
+pub mod resources;
+pub mod systems;
+
+pub fn startup_chemistry(mut chemistry: ResMut<ChemistryResource>) {
+    match load_chemistry(&Path::new("data").join("chemistry")) {
+        Ok((reagents, reactions)) => {
+            chemistry.reagents = reagents;
+            chemistry.reactions = reactions;
+        }
+        Err(rr) => {
+            panic!("Failed to load chemistry: {}", rr);
+        }
+    }
+
+    info!(
+        "Loaded {} reagents and {} reactions.",
+        chemistry.reagents.len(),
+        chemistry.reactions.len()
+    );
+}
+
+pub struct ChemistryPlugin;
+
+impl Plugin for ChemistryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChemistryResource>()
+            .add_event::<InputTransferReagents>()
+            .add_startup_system(startup_chemistry.before(StartupLabels::BuildGridmap))
+            .add_system(transfer_reagents)
+            .add_system(reagent_examine_text)
+            .add_system_set(
+                SystemSet::new()
+                    .with_run_criteria(FixedTimestep::step(METABOLISM_INTERVAL))
+                    .with_system(metabolism),
+            );
+    }
+}
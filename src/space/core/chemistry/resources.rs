@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::{FromWorld, World};
+use serde::Deserialize;
+
+pub struct ChemistryResource {
+    pub reagents: HashMap<String, ReagentDefinition>,
+    pub reactions: Vec<ReactionDefinition>,
+}
+
+impl FromWorld for ChemistryResource {
+    fn from_world(_world: &mut World) -> Self {
+        ChemistryResource {
+            reagents: HashMap::new(),
+            reactions: vec![],
+        }
+    }
+}
+
+// The effects are per unit metabolised, negative values heal.
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct ReagentDefinition {
+    pub name: String,
+    pub examine_name: String,
+    // Units metabolised per metabolism tick, zero for raegents that stay in the body.
+    pub metabolism_rate: f32,
+    pub brute: f32,
+    pub burn: f32,
+    pub toxin: f32,
+    pub overdose_threshold: Option<f32>,
+    pub overdose_toxin: f32,
+}
+
+impl Default for ReagentDefinition {
+    fn default() -> Self {
+        Self {
+            name: "".to_string(),
+            examine_name: "".to_string(),
+            metabolism_rate: 0.5,
+            brute: 0.,
+            burn: 0.,
+            toxin: 0.,
+            overdose_threshold: None,
+            overdose_toxin: 0.,
+        }
+    }
+}
+
+// Reactants and products are given as ratios, the reaction happens as often as the reactants allow.
+#[derive(Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ReactionDefinition {
+    pub name: String,
+    pub reactants: HashMap<String, f32>,
+    pub products: HashMap<String, f32>,
+}
@@ -0,0 +1,69 @@
+use bevy_ecs::system::{Query, Res};
+
+use crate::space::core::{
+    chemistry::resources::ChemistryResource,
+    health::components::{Health, HealthContainer},
+};
+
+// In seconds.
+pub const METABOLISM_INTERVAL: f64 = 2.;
+
+pub fn metabolism(mut healths: Query<&mut Health>, chemistry: Res<ChemistryResource>) {
+    for mut health_component in healths.iter_mut() {
+        if health_component.raegent_container.raegents.is_empty() {
+            continue;
+        }
+
+        // Only bodies metabolise, raegents inside items stay where they are.
+        match &health_component.health_container {
+            HealthContainer::Humanoid(_) => {}
+            HealthContainer::Entity(_) => {
+                continue;
+            }
+        }
+
+        let mut brute = 0.;
+        let mut burn = 0.;
+        let mut toxin = 0.;
+
+        let raegent_names: Vec<String> = health_component
+            .raegent_container
+            .raegents
+            .keys()
+            .cloned()
+            .collect();
+
+        for raegent_name in raegent_names {
+            let amount = health_component.raegent_container.get_amount(&raegent_name);
+
+            match chemistry.reagents.get(&raegent_name) {
+                Some(reagent) => {
+                    let metabolised = health_component
+                        .raegent_container
+                        .remove(&raegent_name, reagent.metabolism_rate);
+
+                    brute += reagent.brute * metabolised;
+                    burn += reagent.burn * metabolised;
+                    toxin += reagent.toxin * metabolised;
+
+                    match reagent.overdose_threshold {
+                        Some(threshold) => {
+                            if amount > threshold {
+                                toxin += reagent.overdose_toxin * metabolised;
+                            }
+                        }
+                        None => {}
+                    }
+                }
+                None => {
+                    // Raegents that are no longer defined wash out at once.
+                    health_component
+                        .raegent_container
+                        .remove(&raegent_name, amount);
+                }
+            }
+        }
+
+        health_component.apply_raegent_effects(brute, burn, toxin);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod metabolism;
+pub mod reagent_examine_text;
+pub mod transfer_reagents;
@@ -0,0 +1,60 @@
+use bevy_ecs::{
+    prelude::{Changed, With},
+    system::{Query, Res},
+};
+
+use crate::space::core::{
+    chat::functions::FURTHER_ITALIC_FONT,
+    chemistry::{components::ReagentHolder, resources::ChemistryResource},
+    examinable::components::Examinable,
+    health::components::Health,
+};
+
+// Kept clear of the texts assigned by prototypes.
+pub const REAGENT_EXAMINE_TEXT_ID: u32 = 100;
+
+pub fn reagent_examine_text(
+    mut reagent_holders: Query<(&Health, &mut Examinable), (Changed<Health>, With<ReagentHolder>)>,
+    chemistry: Res<ChemistryResource>,
+) {
+    for (health_component, mut examinable_component) in reagent_holders.iter_mut() {
+        let contents = &health_component.raegent_container.raegents;
+
+        let text;
+
+        if contents.is_empty() {
+            text = "[font=".to_string() + FURTHER_ITALIC_FONT + "]It is empty.[/font]";
+        } else {
+            let mut names: Vec<&String> = contents.keys().collect();
+            names.sort();
+
+            let mut listing = "".to_string();
+
+            for name in names {
+                let examine_name;
+                match chemistry.reagents.get(name) {
+                    Some(reagent) => {
+                        examine_name = reagent.examine_name.as_str();
+                    }
+                    None => {
+                        examine_name = name.as_str();
+                    }
+                }
+                if !listing.is_empty() {
+                    listing = listing + ", ";
+                }
+                listing = listing + &format!("{:.1}", contents[name]) + " units of " + examine_name;
+            }
+
+            text = "[font=".to_string()
+                + FURTHER_ITALIC_FONT
+                + "]It contains "
+                + &listing
+                + ".[/font]";
+        }
+
+        examinable_component
+            .assigned_texts
+            .insert(REAGENT_EXAMINE_TEXT_ID, text);
+    }
+}
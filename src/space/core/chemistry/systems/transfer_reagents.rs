@@ -0,0 +1,231 @@
+use bevy_app::{EventReader, EventWriter};
+use bevy_ecs::system::{Query, Res};
+use bevy_math::Vec3;
+use bevy_rapier3d::prelude::RigidBodyPositionComponent;
+
+use crate::space::core::{
+    chat::{events::NetChatMessage, functions::new_personal_message},
+    chemistry::{
+        components::{ReagentHolder, ReagentTransferMethod},
+        events::InputTransferReagents,
+        functions::react::react,
+        resources::ChemistryResource,
+    },
+    connected_player::resources::HandleToEntity,
+    examinable::components::Examinable,
+    health::components::{Health, HealthContainer},
+    inventory::components::Inventory,
+    pawn::functions::can_reach_entity::REACH_DISTANCE,
+};
+
+pub fn transfer_reagents(
+    mut transfer_events: EventReader<InputTransferReagents>,
+    inventories: Query<&Inventory>,
+    rigid_body_positions: Query<&RigidBodyPositionComponent>,
+    reagent_holders: Query<&ReagentHolder>,
+    mut healths: Query<&mut Health>,
+    examinables: Query<&Examinable>,
+    chemistry: Res<ChemistryResource>,
+    handle_to_entity: Res<HandleToEntity>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+) {
+    for event in transfer_events.iter() {
+        match inventories.get(event.user) {
+            Ok(inventory_component) => {
+                if inventory_component.get_active_slot_entity() != Some(event.holder) {
+                    continue;
+                }
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let holder_component;
+
+        match reagent_holders.get(event.holder) {
+            Ok(c) => {
+                holder_component = c;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        match (
+            rigid_body_positions.get(event.user),
+            rigid_body_positions.get(event.target),
+        ) {
+            (Ok(user_position), Ok(target_position)) => {
+                let user_translation: Vec3 = user_position.position.translation.into();
+                let target_translation: Vec3 = target_position.position.translation.into();
+                if user_translation.distance(target_translation) > REACH_DISTANCE {
+                    continue;
+                }
+            }
+            _ => {
+                continue;
+            }
+        }
+
+        let target_holder_option = reagent_holders.get(event.target).ok();
+
+        let target_is_body;
+
+        match healths.get(event.target) {
+            Ok(target_health_component) => match target_health_component.health_container {
+                HealthContainer::Humanoid(_) => {
+                    target_is_body = true;
+                }
+                HealthContainer::Entity(_) => {
+                    target_is_body = false;
+                }
+            },
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let source_entity;
+        let destination_entity;
+        let room;
+
+        if event.draw {
+            // Only syringes draw, and only out of other holders.
+            match target_holder_option {
+                Some(_) => {
+                    if holder_component.transfer_method != ReagentTransferMethod::Inject {
+                        continue;
+                    }
+                }
+                None => {
+                    continue;
+                }
+            }
+            source_entity = event.target;
+            destination_entity = event.holder;
+            room = holder_component.capacity - get_total(&healths, event.holder);
+        } else {
+            source_entity = event.holder;
+            destination_entity = event.target;
+            match target_holder_option {
+                Some(target_holder_component) => {
+                    room = target_holder_component.capacity - get_total(&healths, event.target);
+                }
+                None => {
+                    if !target_is_body {
+                        continue;
+                    }
+                    room = holder_component.transfer_amount;
+                }
+            }
+        }
+
+        let amount = holder_component
+            .transfer_amount
+            .min(room)
+            .min(get_total(&healths, source_entity));
+
+        if amount <= 0. {
+            new_personal_message(
+                &mut net_chat_message,
+                &handle_to_entity,
+                event.user,
+                "There is nothing to transfer or no room left.",
+            );
+            continue;
+        }
+
+        let taken;
+
+        match healths.get_mut(source_entity) {
+            Ok(mut source_health_component) => {
+                taken = source_health_component.raegent_container.take(amount);
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        match healths.get_mut(destination_entity) {
+            Ok(mut destination_health_component) => {
+                destination_health_component.raegent_container.merge(taken);
+                react(
+                    &mut destination_health_component.raegent_container,
+                    &chemistry.reactions,
+                );
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let target_name;
+
+        match examinables.get(event.target) {
+            Ok(examinable_component) => {
+                target_name = examinable_component.name.get_name().to_string();
+            }
+            Err(_rr) => {
+                target_name = "it".to_string();
+            }
+        }
+
+        let user_text;
+
+        if event.draw {
+            user_text = "You draw ".to_string()
+                + &format!("{:.1}", amount)
+                + " units from "
+                + &target_name
+                + ".";
+        } else {
+            let verb;
+            match (target_is_body, holder_component.transfer_method) {
+                (true, ReagentTransferMethod::Inject) => {
+                    verb = "You inject ";
+                }
+                (true, ReagentTransferMethod::Ingest) => {
+                    verb = "You feed ";
+                }
+                (false, _) => {
+                    verb = "You transfer ";
+                }
+            }
+            user_text =
+                verb.to_string() + &format!("{:.1}", amount) + " units into " + &target_name + ".";
+        }
+
+        new_personal_message(
+            &mut net_chat_message,
+            &handle_to_entity,
+            event.user,
+            &user_text,
+        );
+
+        if target_is_body && !event.draw && event.target != event.user {
+            let target_text;
+            match holder_component.transfer_method {
+                ReagentTransferMethod::Inject => {
+                    target_text = "You feel a tiny prick!";
+                }
+                ReagentTransferMethod::Ingest => {
+                    target_text = "Something is forced down your throat!";
+                }
+            }
+            new_personal_message(
+                &mut net_chat_message,
+                &handle_to_entity,
+                event.target,
+                target_text,
+            );
+        }
+    }
+}
+
+fn get_total(healths: &Query<&mut Health>, entity: bevy_ecs::entity::Entity) -> f32 {
+    match healths.get(entity) {
+        Ok(health_component) => health_component.raegent_container.get_total(),
+        Err(_rr) => 0.,
+    }
+}
@@ -0,0 +1,103 @@
+use bevy_core::Timer;
+use bevy_ecs::prelude::Component;
+use bevy_math::Vec3;
+use rand::Rng;
+
+// How precise a ranged weapon is. All spreads are in degrees and span both sides of where the holder aims.
+#[derive(Component)]
+pub struct WeaponAccuracy {
+    pub base_spread: f32,
+    pub recoil_per_shot: f32,
+    pub max_recoil: f32,
+    // Degrees of recoil that wear off per second.
+    pub recovery: f32,
+    pub moving_penalty: f32,
+    // Sprinting makes the weapon at least this unsteady, it wears off like recoil.
+    pub sprinting_penalty: f32,
+    pub recoil: f32,
+}
+
+impl WeaponAccuracy {
+    pub fn get_spread(&self, moving: bool) -> f32 {
+        let mut spread = self.base_spread + self.recoil;
+        if moving {
+            spread += self.moving_penalty;
+        }
+        spread
+    }
+
+    // Returns the angle in radians the shot deviates from where it was aimed and kicks the weapon up.
+    pub fn fire(&mut self, moving: bool) -> f32 {
+        let half_spread = self.get_spread(moving).to_radians() / 2.;
+
+        if self.recoil < self.max_recoil {
+            self.recoil = (self.recoil + self.recoil_per_shot).min(self.max_recoil);
+        }
+
+        if half_spread <= 0. {
+            return 0.;
+        }
+
+        rand::thread_rng().gen_range(-half_spread..half_spread)
+    }
+
+    pub fn sprinted(&mut self) {
+        if self.recoil < self.sprinting_penalty {
+            self.recoil = self.sprinting_penalty;
+        }
+    }
+
+    pub fn recover(&mut self, delta_seconds: f32) {
+        self.recoil = (self.recoil - self.recovery * delta_seconds).max(0.);
+    }
+}
+
+// Attacks that land this soon after the guard went up get parried.
+const PARRY_TIME: f32 = 0.25;
+// Degrees in front of the defender the guard covers.
+const BLOCK_ARC: f32 = 120.;
+
+// Humanoids holding up their guard against melee attacks.
+#[derive(Component)]
+pub struct Blocking {
+    // The direction the guard faces, as the angle of an attack would be.
+    pub angle: f32,
+    pub parry_timer: Timer,
+}
+
+impl Blocking {
+    pub fn new(angle: f32) -> Self {
+        Self {
+            angle,
+            parry_timer: Timer::from_seconds(PARRY_TIME, false),
+        }
+    }
+
+    pub fn is_parrying(&self) -> bool {
+        !self.parry_timer.finished()
+    }
+
+    // Whether an attack coming from this direction runs into the guard.
+    pub fn covers(&self, direction: Vec3) -> bool {
+        let forward = Vec3::new(self.angle.cos(), 0., -self.angle.sin());
+        let direction = Vec3::new(direction.x, 0., direction.z).normalize_or_zero();
+
+        forward.dot(direction) >= (BLOCK_ARC.to_radians() / 2.).cos()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum LaserSurfaceKind {
+    // Bounces lasers off.
+    Reflective,
+    // Lets lasers pass through.
+    Refractive,
+}
+
+// How cells and entities treat lasers that run into them.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct LaserSurface {
+    pub kind: LaserSurfaceKind,
+    // Part of the energy of the laser that is left after the surface.
+    pub energy_factor: f32,
+}
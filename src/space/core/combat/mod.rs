@@ -1,16 +1,144 @@
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+};
+
 use bevy_app::{App, Plugin};
-use bevy_ecs::schedule::ParallelSystemDescriptorCoercion;
+use bevy_ecs::{schedule::ParallelSystemDescriptorCoercion, system::ResMut};
+use bevy_log::{info, warn};
 
-use crate::space::UpdateLabels;
+use crate::space::{core::configuration::functions::is_valid_file_name, UpdateLabels};
 
-use self::systems::attack;
+use self::{
+    resources::{
+        CombatLog, CombatRules, HitboxHistory, PendingBallisticImpacts, SafeZone, SafeZoneRaw,
+        COMBAT_LOG_CSV_HEADER,
+    },
+    systems::{attack, ballistic_impacts, record_hitbox_history, weapon_recoil_recovery},
+};
 
+pub mod components;
+pub mod resources;
 pub mod systems;
 
+// Start the server with --combat-log <name> to also append the combat log to data/combat_logs/<name>.csv.
+pub fn startup_combat_log(mut combat_log: ResMut<CombatLog>) {
+    let args: Vec<String> = env::args().collect();
+
+    for (i, arg) in args.iter().enumerate() {
+        if arg != "--combat-log" {
+            continue;
+        }
+
+        let name;
+
+        match args.get(i + 1) {
+            Some(n) => {
+                name = n;
+            }
+            None => {
+                warn!("--combat-log requires a log name.");
+                return;
+            }
+        }
+
+        if !is_valid_file_name(name) {
+            warn!("Invalid combat log name {}.", name);
+            return;
+        }
+
+        let directory = Path::new("data").join("combat_logs");
+
+        match fs::create_dir_all(&directory) {
+            Ok(_) => {}
+            Err(rr) => {
+                warn!("Failed to create the combat log directory: {}", rr);
+                return;
+            }
+        }
+
+        let path = directory.join(name.to_string() + ".csv");
+        let is_new = !path.exists();
+
+        match OpenOptions::new().create(true).append(true).open(&path) {
+            Ok(mut file) => {
+                if is_new {
+                    match writeln!(file, "{}", COMBAT_LOG_CSV_HEADER) {
+                        Ok(_) => {}
+                        Err(rr) => {
+                            warn!("Failed to write to the combat log file: {}", rr);
+                        }
+                    }
+                }
+                combat_log.file_option = Some(file);
+                info!("Writing the combat log to {}.", path.to_string_lossy());
+            }
+            Err(rr) => {
+                warn!("Failed to open the combat log file: {}", rr);
+            }
+        }
+
+        return;
+    }
+}
+
+// Maps without a safezones.json have no safe zones.
+pub fn startup_safe_zones(mut combat_rules: ResMut<CombatRules>) {
+    let safe_zones_json = Path::new("data")
+        .join("maps")
+        .join("bullseye")
+        .join("safezones.json");
+
+    if !safe_zones_json.exists() {
+        return;
+    }
+
+    let raw_json;
+
+    match fs::read_to_string(&safe_zones_json) {
+        Ok(s) => {
+            raw_json = s;
+        }
+        Err(rr) => {
+            warn!("Failed to read safezones.json: {}", rr);
+            return;
+        }
+    }
+
+    let safe_zones_raw: Vec<SafeZoneRaw>;
+
+    match serde_json::from_str(&raw_json) {
+        Ok(s) => {
+            safe_zones_raw = s;
+        }
+        Err(rr) => {
+            warn!("Failed to parse safezones.json: {}", rr);
+            return;
+        }
+    }
+
+    for raw in safe_zones_raw.iter() {
+        combat_rules.safe_zones.push(SafeZone::new(raw));
+    }
+
+    info!("Loaded {} safe zones.", combat_rules.safe_zones.len());
+}
+
 pub struct CombatPlugin;
 
 impl Plugin for CombatPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(attack.after(UpdateLabels::StandardCharacters));
+        app.init_resource::<PendingBallisticImpacts>()
+            .init_resource::<HitboxHistory>()
+            .init_resource::<CombatLog>()
+            .init_resource::<CombatRules>()
+            .add_startup_system(startup_combat_log)
+            .add_startup_system(startup_safe_zones)
+            .add_system(record_hitbox_history.before(attack))
+            .add_system(attack.after(UpdateLabels::StandardCharacters))
+            .add_system(ballistic_impacts.after(attack))
+            .add_system(weapon_recoil_recovery.before(UpdateLabels::StandardCharacters));
     }
 }
@@ -0,0 +1,378 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::File,
+    io::Write,
+};
+
+use bevy_core::Timer;
+use bevy_ecs::{
+    entity::Entity,
+    prelude::{FromWorld, World},
+};
+use bevy_log::warn;
+use bevy_math::Vec3;
+use bevy_rapier3d::rapier::math::{Isometry, Real};
+use serde::Deserialize;
+
+use crate::space::core::{
+    gridmap::{
+        functions::gridmap_functions::world_to_cell_id,
+        resources::{Vec2Int, Vec3Int},
+    },
+    health::{
+        components::{DamageModel, HitResult},
+        events::Attack,
+    },
+    pawn::components::SpaceJobsEnum,
+};
+
+// A ballistic projectile that is on its way to something it will hit.
+pub struct BallisticImpact {
+    pub attack: Attack,
+    pub entity_option: Option<Entity>,
+    pub cell_id_option: Option<Vec3Int>,
+    pub hit_point: Vec3,
+    pub damage_factor: f32,
+    // Time the projectile takes to get there, finished straight away for hitscan.
+    pub timer: Timer,
+}
+
+pub struct PendingBallisticImpacts {
+    pub impacts: Vec<BallisticImpact>,
+}
+
+impl FromWorld for PendingBallisticImpacts {
+    fn from_world(_world: &mut World) -> Self {
+        PendingBallisticImpacts { impacts: vec![] }
+    }
+}
+
+// Where a hittable entity was at one point in time.
+#[derive(Clone, Copy)]
+pub struct HitboxState {
+    pub rigid_body_position: Vec3,
+    pub collider_position: Isometry<Real>,
+}
+
+pub struct HitboxSnapshot {
+    // Milliseconds since startup, the same time stamps transform updates are sent with.
+    pub time_stamp: u64,
+    pub hitboxes: HashMap<Entity, HitboxState>,
+}
+
+// One snapshot per tick, going back as far as attacks are allowed to be rewound.
+pub struct HitboxHistory {
+    pub snapshots: VecDeque<HitboxSnapshot>,
+}
+
+impl FromWorld for HitboxHistory {
+    fn from_world(_world: &mut World) -> Self {
+        HitboxHistory {
+            snapshots: VecDeque::new(),
+        }
+    }
+}
+
+impl HitboxHistory {
+    // Interpolates between the snapshots around the time stamp. None when the time stamp is not in the past.
+    pub fn get_rewound(&self, time_stamp: u64) -> Option<HashMap<Entity, HitboxState>> {
+        let newer_index;
+
+        match self
+            .snapshots
+            .iter()
+            .position(|snapshot| snapshot.time_stamp >= time_stamp)
+        {
+            Some(index) => {
+                newer_index = index;
+            }
+            None => {
+                return None;
+            }
+        }
+
+        let newer = &self.snapshots[newer_index];
+
+        if newer_index == 0 {
+            return Some(newer.hitboxes.clone());
+        }
+
+        let older = &self.snapshots[newer_index - 1];
+
+        let factor =
+            (time_stamp - older.time_stamp) as f32 / (newer.time_stamp - older.time_stamp) as f32;
+
+        let mut hitboxes = HashMap::new();
+
+        for (entity, newer_state) in newer.hitboxes.iter() {
+            match older.hitboxes.get(entity) {
+                Some(older_state) => {
+                    hitboxes.insert(
+                        *entity,
+                        HitboxState {
+                            rigid_body_position: older_state
+                                .rigid_body_position
+                                .lerp(newer_state.rigid_body_position, factor),
+                            collider_position: older_state
+                                .collider_position
+                                .lerp_slerp(&newer_state.collider_position, factor),
+                        },
+                    );
+                }
+                None => {
+                    hitboxes.insert(*entity, *newer_state);
+                }
+            }
+        }
+
+        Some(hitboxes)
+    }
+}
+
+// Attack resolutions the combat log keeps in memory, the oldest get dropped first.
+const COMBAT_LOG_CAPACITY: usize = 4096;
+
+pub const COMBAT_LOG_CSV_HEADER: &str =
+    "time,attacker_entity,attacker_name,weapon_name,target_entity,target_cell,target_name,limb,brute,burn,toxin,hit_result,x,y,z";
+
+#[derive(Clone)]
+pub enum CombatLogTarget {
+    Entity(Entity, String),
+    Cell(Vec3Int, String),
+    Nothing,
+}
+
+// The outcome of an attack against one target.
+pub struct CombatLogRecord {
+    // Seconds since startup.
+    pub time: f64,
+    pub attacker_entity: Entity,
+    pub attacker_name: String,
+    pub weapon_name: String,
+    pub target: CombatLogTarget,
+    pub limb: String,
+    // The damage that was dealt to the target, after blocking and falloff.
+    pub damage_model: DamageModel,
+    pub hit_result: HitResult,
+    pub position: Vec3,
+}
+
+impl CombatLogRecord {
+    pub fn new(
+        time: f64,
+        attack_event: &Attack,
+        target: CombatLogTarget,
+        damage_model: DamageModel,
+        hit_result: HitResult,
+        position: Vec3,
+    ) -> Self {
+        Self {
+            time,
+            attacker_entity: attack_event.attacker_entity,
+            attacker_name: attack_event.attacker_name.to_string(),
+            weapon_name: attack_event.weapon_name.to_string(),
+            target,
+            limb: attack_event.targetted_limb.to_string(),
+            damage_model,
+            hit_result,
+            position,
+        }
+    }
+
+    pub fn involves(&self, entity: Entity) -> bool {
+        if self.attacker_entity == entity {
+            return true;
+        }
+        match &self.target {
+            CombatLogTarget::Entity(target_entity, _) => *target_entity == entity,
+            _ => false,
+        }
+    }
+
+    pub fn get_target_name(&self) -> &str {
+        match &self.target {
+            CombatLogTarget::Entity(_, name) => name,
+            CombatLogTarget::Cell(_, name) => name,
+            CombatLogTarget::Nothing => "nothing",
+        }
+    }
+
+    pub fn get_hit_result_name(&self) -> &str {
+        match self.hit_result {
+            HitResult::HitSoft => "hit",
+            HitResult::Blocked => "blocked",
+            HitResult::Missed => "missed",
+        }
+    }
+
+    pub fn to_csv_line(&self) -> String {
+        let target_entity;
+        let target_cell;
+
+        match &self.target {
+            CombatLogTarget::Entity(entity, _) => {
+                target_entity = entity.to_bits().to_string();
+                target_cell = "".to_string();
+            }
+            CombatLogTarget::Cell(cell_id, _) => {
+                target_entity = "".to_string();
+                target_cell = cell_id.x.to_string()
+                    + " "
+                    + &cell_id.y.to_string()
+                    + " "
+                    + &cell_id.z.to_string();
+            }
+            CombatLogTarget::Nothing => {
+                target_entity = "".to_string();
+                target_cell = "".to_string();
+            }
+        }
+
+        [
+            format!("{:.3}", self.time),
+            self.attacker_entity.to_bits().to_string(),
+            self.attacker_name.to_string(),
+            self.weapon_name.to_string(),
+            target_entity,
+            target_cell,
+            self.get_target_name().to_string(),
+            self.limb.to_string(),
+            self.damage_model.brute.to_string(),
+            self.damage_model.burn.to_string(),
+            self.damage_model.toxin.to_string(),
+            self.get_hit_result_name().to_string(),
+            self.position.x.to_string(),
+            self.position.y.to_string(),
+            self.position.z.to_string(),
+        ]
+        .iter()
+        .map(|field| to_csv_field(field))
+        .collect::<Vec<String>>()
+        .join(",")
+    }
+}
+
+// Names can hold commas, quotes and line breaks, quote every field so they stay in their column.
+fn to_csv_field(field: &str) -> String {
+    "\"".to_string() + &field.replace('"', "\"\"") + "\""
+}
+
+// Who did what to whom, for admins looking into deaths and injuries.
+pub struct CombatLog {
+    pub records: VecDeque<CombatLogRecord>,
+    // Records also get appended to this file when the server was started with --combat-log.
+    pub file_option: Option<File>,
+}
+
+impl FromWorld for CombatLog {
+    fn from_world(_world: &mut World) -> Self {
+        CombatLog {
+            records: VecDeque::new(),
+            file_option: None,
+        }
+    }
+}
+
+impl CombatLog {
+    pub fn record(&mut self, record: CombatLogRecord) {
+        match &mut self.file_option {
+            Some(file) => match writeln!(file, "{}", record.to_csv_line()) {
+                Ok(_) => {}
+                Err(rr) => {
+                    warn!("Failed to write to the combat log file: {}", rr);
+                }
+            },
+            None => {}
+        }
+
+        self.records.push_back(record);
+
+        if self.records.len() > COMBAT_LOG_CAPACITY {
+            self.records.pop_front();
+        }
+    }
+}
+
+// A rectangle of cells on the map in which pawns cannot hurt each other, both corners are included.
+pub struct SafeZone {
+    pub name: String,
+    pub min: Vec2Int,
+    pub max: Vec2Int,
+}
+
+// Safe zones as they are listed in safezones.json of the map, corners are [x, z] cell ids.
+#[derive(Deserialize)]
+pub struct SafeZoneRaw {
+    pub name: String,
+    pub min: [i16; 2],
+    pub max: [i16; 2],
+}
+
+impl SafeZone {
+    pub fn new(raw: &SafeZoneRaw) -> Self {
+        Self {
+            name: raw.name.to_string(),
+            min: Vec2Int {
+                x: raw.min[0].min(raw.max[0]),
+                y: raw.min[1].min(raw.max[1]),
+            },
+            max: Vec2Int {
+                x: raw.min[0].max(raw.max[0]),
+                y: raw.min[1].max(raw.max[1]),
+            },
+        }
+    }
+
+    pub fn contains(&self, position: Vec3) -> bool {
+        let cell_id = world_to_cell_id(position);
+        cell_id.x >= self.min.x
+            && cell_id.x <= self.max.x
+            && cell_id.z >= self.min.y
+            && cell_id.z <= self.max.y
+    }
+}
+
+// Who may hurt whom. Changes made through RCON last until the server restarts.
+pub struct CombatRules {
+    pub pvp: bool,
+    // Whether pawns of the same department can hurt each other.
+    pub department_damage: bool,
+    pub safe_zones: Vec<SafeZone>,
+}
+
+impl FromWorld for CombatRules {
+    fn from_world(_world: &mut World) -> Self {
+        CombatRules {
+            pvp: true,
+            department_damage: true,
+            safe_zones: vec![],
+        }
+    }
+}
+
+impl CombatRules {
+    // Why one pawn may not attack another, None when the attack is allowed.
+    pub fn get_prevented_reason(
+        &self,
+        attacker_job: SpaceJobsEnum,
+        attacker_position: Vec3,
+        target_job: SpaceJobsEnum,
+        target_position: Vec3,
+    ) -> Option<String> {
+        if !self.pvp {
+            return Some("player versus player combat is disabled".to_string());
+        }
+
+        if !self.department_damage && attacker_job == target_job {
+            return Some("you cannot hurt members of your own department".to_string());
+        }
+
+        for safe_zone in self.safe_zones.iter() {
+            if safe_zone.contains(attacker_position) || safe_zone.contains(target_position) {
+                return Some(safe_zone.name.to_string() + " is a safe zone");
+            }
+        }
+
+        None
+    }
+}
@@ -1,18 +1,40 @@
+use std::collections::HashMap;
+
 use bevy_app::{EventReader, EventWriter};
+use bevy_core::{Time, Timer};
 use bevy_ecs::{
     entity::Entity,
+    prelude::{With, Without},
     system::{Commands, Query, Res, ResMut},
 };
 use bevy_math::{Quat, Vec3};
-use bevy_rapier3d::prelude::{
-    ColliderHandle, Cuboid, InteractionGroups, QueryPipeline, QueryPipelineColliderComponentsQuery,
-    QueryPipelineColliderComponentsSet, Ray, RigidBodyPositionComponent,
+use bevy_rapier3d::{
+    prelude::{
+        ColliderHandle, ColliderPositionComponent, Cuboid, Cylinder, InteractionGroups,
+        QueryPipeline, QueryPipelineColliderComponentsQuery, QueryPipelineColliderComponentsSet,
+        Ray, RigidBodyPositionComponent, Shape,
+    },
+    rapier::{
+        math::{Isometry, Real},
+        parry::query::intersection_test,
+    },
 };
 use bevy_transform::components::Transform;
 
 use crate::space::core::{
-    chat::events::NetChatMessage,
-    connected_player::resources::HandleToEntity,
+    atmospherics::{
+        events::IgniteAtmospherics, functions::get_atmos_index, resources::AtmosphericsResource,
+    },
+    chat::{events::NetChatMessage, functions::new_personal_message},
+    combat::{
+        components::{Blocking, LaserSurface, LaserSurfaceKind, WeaponAccuracy},
+        resources::{
+            BallisticImpact, CombatLog, CombatLogRecord, CombatLogTarget, CombatRules,
+            HitboxHistory, HitboxSnapshot, HitboxState, PendingBallisticImpacts,
+        },
+    },
+    configuration::resources::LagCompensation,
+    connected_player::{components::ViewLatency, resources::HandleToEntity},
     examinable::components::Examinable,
     gridmap::{
         components::Cell,
@@ -21,20 +43,22 @@ use crate::space::core::{
             get_cell_name::get_cell_name,
             gridmap_functions::{cell_id_to_world, world_to_cell_id},
         },
-        resources::{GridmapData, GridmapMain, Vec3Int},
+        resources::{GridmapData, GridmapMain, Vec2Int, Vec3Int},
     },
     health::{
-        components::{DamageType, Health, HitResult},
-        events::Attack,
+        components::{DamageModel, DamageType, Health, HitResult},
+        events::{Attack, InflictStatusEffects},
     },
-    inventory_item::components::CombatType,
+    inventory_item::components::{CombatType, MeleeShape},
     networking::resources::NetProjectileType,
+    pawn::components::Pawn,
     physics::functions::{get_bit_masks, ColliderGroup},
+    rigid_body::components::RigidBodyDisabled,
     senser::components::Senser,
     sfx::resources::SfxAutoDestroyTimers,
 };
 
-use bevy_rapier3d::physics::IntoEntity;
+use bevy_rapier3d::physics::{IntoEntity, IntoHandle};
 
 #[derive(Debug)]
 struct AttackResult {
@@ -45,9 +69,18 @@ struct AttackResult {
     collider_handle: ColliderHandle,
     is_combat_obstacle: bool,
     is_laser_obstacle: bool,
+    // Where the collider was at the view time of the attacker.
+    rewound_collider_position: Option<Isometry<Real>>,
 }
 
 const ATTACK_HEIGHT: f32 = 1.6;
+// Part of the damage of a melee attack that gets through a raised guard.
+const BLOCKED_DAMAGE_FACTOR: f32 = 0.4;
+const MAX_LASER_BOUNCES: u8 = 3;
+// Lasers that have lost more of their energy than this fade out instead of bouncing on.
+const MIN_LASER_ENERGY: f32 = 0.1;
+// Meters stepped back along a laser to find the open cell in front of whatever it hit.
+const LASER_IGNITION_STEP: f32 = 0.25;
 
 pub fn attack(
     mut attack_events: EventReader<Attack>,
@@ -55,18 +88,66 @@ pub fn attack(
     collider_query: QueryPipelineColliderComponentsQuery,
     mut rigidbody_query: Query<(&mut Health, &Examinable, &RigidBodyPositionComponent)>,
     mut world_cells: ResMut<GridmapMain>,
-    physics_cells: Query<&Cell>,
+    entity_queries: (
+        Query<&Cell>,
+        Query<&Blocking>,
+        Query<&LaserSurface>,
+        Query<&Pawn>,
+    ),
     mut net_message_event: EventWriter<NetChatMessage>,
     handle_to_entity: Res<HandleToEntity>,
     mut commands: Commands,
     mut sfx_auto_destroy_timers: ResMut<SfxAutoDestroyTimers>,
     mut projectile_fov: EventWriter<ProjectileFOV>,
     sensers: Query<(Entity, &Senser)>,
-    gridmap_data: Res<GridmapData>,
+    world_resources: (Res<GridmapData>, Res<AtmosphericsResource>),
+    attack_events_out: (
+        EventWriter<IgniteAtmospherics>,
+        EventWriter<InflictStatusEffects>,
+    ),
+    combat_resources: (
+        ResMut<PendingBallisticImpacts>,
+        ResMut<CombatLog>,
+        Res<CombatRules>,
+    ),
+    lag_compensation_resources: (
+        Res<Time>,
+        Res<LagCompensation>,
+        Res<HitboxHistory>,
+        Query<&ViewLatency>,
+    ),
 ) {
+    let (physics_cells, blockers, laser_surfaces, pawns) = entity_queries;
+    let (gridmap_data, atmospherics_resource) = world_resources;
+    let (mut ignite_atmospherics, mut inflict_status_effects) = attack_events_out;
+    let (mut pending_ballistic_impacts, mut combat_log, combat_rules) = combat_resources;
+    let (time, lag_compensation, hitbox_history, view_latencies) = lag_compensation_resources;
+
+    let current_time_stamp = time.time_since_startup().as_millis() as u64;
+
     for attack_event in attack_events.iter() {
         let direction_additive = Vec3::new(-attack_event.angle.cos(), 0., attack_event.angle.sin());
 
+        // Hittable entities get tested where the attacker saw them rather than where they are now.
+        let rewound_hitboxes_option;
+
+        match view_latencies.get(attack_event.attacker_entity) {
+            Ok(view_latency_component) => {
+                let rewind_millis = view_latency_component
+                    .millis
+                    .min(lag_compensation.max_rewind_millis);
+                if rewind_millis > 0 {
+                    rewound_hitboxes_option = hitbox_history
+                        .get_rewound(current_time_stamp.saturating_sub(rewind_millis));
+                } else {
+                    rewound_hitboxes_option = None;
+                }
+            }
+            Err(_rr) => {
+                rewound_hitboxes_option = None;
+            }
+        }
+
         let attacker_cell_id = world_to_cell_id(attack_event.attacker_position);
 
         let mut sound_transform = Transform {
@@ -99,24 +180,46 @@ pub fn attack(
                     cast_vertical_extents = 0.1;
                 }
 
+                let attack_origin = Vec3::new(
+                    attack_event.attacker_position.x,
+                    attack_height,
+                    attack_event.attacker_position.z,
+                );
+
+                let cast_position: Isometry<Real>;
+                let cast_shape: Box<dyn Shape>;
+
+                match attack_event.melee_shape {
+                    MeleeShape::Thrust => {
+                        cast_position = (
+                            attack_origin - additive,
+                            Quat::from_rotation_y(attack_event.angle),
+                        )
+                            .into();
+                        cast_shape = Box::new(Cuboid::new(
+                            Vec3::new(attack_event.range, cast_vertical_extents, 0.1).into(),
+                        ));
+                    }
+                    MeleeShape::Sweep(_arc) => {
+                        // Everything around the attacker, whatever is outside the arc gets dropped after.
+                        cast_position = (attack_origin, Quat::IDENTITY).into();
+                        cast_shape =
+                            Box::new(Cylinder::new(cast_vertical_extents, attack_event.range));
+                    }
+                }
+
                 query_pipeline.intersections_with_shape(
                     &QueryPipelineColliderComponentsSet(&collider_query),
-                    &(
-                        Vec3::new(
-                            attack_event.attacker_position.x,
-                            attack_height,
-                            attack_event.attacker_position.z,
-                        ) - additive,
-                        Quat::from_rotation_y(attack_event.angle),
-                    )
-                        .into(),
-                    &Cuboid::new(Vec3::new(attack_event.range, cast_vertical_extents, 0.1).into()),
+                    &cast_position,
+                    &*cast_shape,
                     interaction_groups,
                     None,
                     |collider_handle| {
                         let collider_entity = collider_handle.entity();
 
-                        if collider_entity == attack_event.attacker_entity {
+                        if collider_entity == attack_event.attacker_entity
+                            || is_rewound(collider_entity, &rewound_hitboxes_option)
+                        {
                             return true;
                         }
 
@@ -149,6 +252,7 @@ pub fn attack(
                                     collider_handle,
                                     is_combat_obstacle: health_component.is_combat_obstacle,
                                     is_laser_obstacle: health_component.is_laser_obstacle,
+                                    rewound_collider_position: None,
                                 });
                             }
                             Err(_rr) => {}
@@ -173,6 +277,7 @@ pub fn attack(
                                     is_laser_obstacle: !gridmap_data
                                         .non_laser_obstacle_cells_list
                                         .contains(&cell_data.item),
+                                    rewound_collider_position: None,
                                 });
                             }
                             Err(_rr) => {}
@@ -182,11 +287,36 @@ pub fn attack(
                     },
                 );
 
+                add_rewound_hits(
+                    &mut hit_entities,
+                    &rewound_hitboxes_option,
+                    attack_event,
+                    attack_event.attacker_position,
+                    &cast_position,
+                    &*cast_shape,
+                    interaction_groups,
+                    &collider_query,
+                    &mut rigidbody_query,
+                );
+
+                match attack_event.melee_shape {
+                    MeleeShape::Thrust => {}
+                    MeleeShape::Sweep(arc) => {
+                        let min_dot = (arc.to_radians() / 2.).cos();
+                        hit_entities.retain(|attack_result| {
+                            let direction =
+                                attack_result.rigid_body_position - attack_event.attacker_position;
+                            let direction =
+                                Vec3::new(direction.x, 0., direction.z).normalize_or_zero();
+                            direction == Vec3::ZERO
+                                || (-direction_additive).dot(direction) >= min_dot
+                        });
+                    }
+                }
+
                 hit_entities.sort_by(|a, b| b.distance.partial_cmp(&a.distance).unwrap());
                 hit_entities.reverse();
 
-                let mut hit_result = HitResult::Missed;
-
                 let mut hit_entity = None;
 
                 match attack_event.targetted_entity {
@@ -268,34 +398,148 @@ pub fn attack(
                     }
                 }
 
+                let mut hit_targets = vec![];
+
                 match hit_entity {
-                    Some(attack_result) => match attack_result.entity_option {
+                    Some(attack_result) => {
+                        hit_targets.push(attack_result);
+                    }
+                    None => {}
+                }
+
+                match attack_event.melee_shape {
+                    MeleeShape::Thrust => {}
+                    MeleeShape::Sweep(_arc) => {
+                        // Sweeps hit every obstacle in their arc that is not behind a wall.
+                        for attack_result in hit_entities.iter() {
+                            if attack_result.entity_option.is_none()
+                                || !attack_result.is_combat_obstacle
+                                || hit_targets.iter().any(|hit_target| {
+                                    hit_target.collider_handle == attack_result.collider_handle
+                                })
+                            {
+                                continue;
+                            }
+                            if is_sweep_obstructed(
+                                attack_result,
+                                &hit_entities,
+                                attack_origin,
+                                &query_pipeline,
+                                &collider_query,
+                                interaction_groups,
+                            ) {
+                                continue;
+                            }
+                            hit_targets.push(attack_result);
+                        }
+                    }
+                }
+
+                // The sound of the attack is that of its best hit.
+                let mut sound_hit_result = HitResult::Missed;
+
+                for attack_result in hit_targets.iter() {
+                    let mut hit_result = HitResult::Missed;
+                    let mut dealt_damage_model = attack_event.damage_model.clone();
+
+                    match attack_result.entity_option {
                         Some(collider_entity) => match rigidbody_query.get_mut(collider_entity) {
                             Ok((
                                 mut health_component,
                                 examinable_component,
                                 rigid_body_position_component,
                             )) => {
-                                let attacked_cell_id = world_to_cell_id(
-                                    rigid_body_position_component.position.translation.into(),
-                                );
+                                let attacked_position: Vec3 =
+                                    rigid_body_position_component.position.translation.into();
+                                let attacked_cell_id = world_to_cell_id(attacked_position);
+
+                                // Defenders that face the attack with their guard up block or parry it.
+                                let mut blocked = false;
+                                let mut parried = false;
+
+                                match blockers.get(collider_entity) {
+                                    Ok(blocking_component) => {
+                                        if blocking_component.covers(
+                                            attack_event.attacker_position - attacked_position,
+                                        ) {
+                                            blocked = true;
+                                            parried = blocking_component.is_parrying();
+                                        }
+                                    }
+                                    Err(_rr) => {}
+                                }
 
-                                hit_result = health_component.apply_damage(
-                                    &attack_event.targetted_limb,
-                                    &attack_event.damage_model,
+                                if is_attack_prevented(
+                                    attack_event,
+                                    collider_entity,
+                                    attacked_position,
+                                    &pawns,
+                                    &combat_rules,
                                     &mut net_message_event,
                                     &handle_to_entity,
-                                    &attacker_cell_id,
-                                    &attacked_cell_id,
-                                    &sensers,
-                                    &attack_event.attacker_name,
-                                    &examinable_component.name.get_a_name(),
-                                    &DamageType::Melee,
-                                    &attack_event.weapon_name,
-                                    &attack_event.weapon_a_name,
-                                    &attack_event.offense_words,
-                                    &attack_event.trigger_words,
-                                );
+                                ) {
+                                    hit_result = HitResult::Blocked;
+                                    dealt_damage_model = DamageModel::default();
+                                } else if parried {
+                                    hit_result = HitResult::Blocked;
+                                    dealt_damage_model = DamageModel::default();
+
+                                    new_personal_message(
+                                        &mut net_message_event,
+                                        &handle_to_entity,
+                                        collider_entity,
+                                        &("You parry the attack of ".to_string()
+                                            + &attack_event.attacker_name
+                                            + "!"),
+                                    );
+                                    new_personal_message(
+                                        &mut net_message_event,
+                                        &handle_to_entity,
+                                        attack_event.attacker_entity,
+                                        &(examinable_component.name.get_name().to_string()
+                                            + " parries your attack!"),
+                                    );
+                                } else {
+                                    let damage_model;
+
+                                    if blocked {
+                                        damage_model =
+                                            attack_event.damage_model.scaled(BLOCKED_DAMAGE_FACTOR);
+                                    } else {
+                                        damage_model = attack_event.damage_model.clone();
+                                    }
+
+                                    hit_result = health_component.apply_damage(
+                                        &attack_event.targetted_limb,
+                                        &damage_model,
+                                        &mut net_message_event,
+                                        &handle_to_entity,
+                                        &attacker_cell_id,
+                                        &attacked_cell_id,
+                                        &sensers,
+                                        &attack_event.attacker_name,
+                                        &examinable_component.name.get_a_name(),
+                                        &DamageType::Melee,
+                                        &attack_event.weapon_name,
+                                        &attack_event.weapon_a_name,
+                                        &attack_event.offense_words,
+                                        &attack_event.trigger_words,
+                                    );
+
+                                    if blocked {
+                                        hit_result = HitResult::Blocked;
+                                    }
+
+                                    if matches!(hit_result, HitResult::HitSoft) {
+                                        send_status_effects(
+                                            collider_entity,
+                                            attack_event,
+                                            &mut inflict_status_effects,
+                                        );
+                                    }
+
+                                    dealt_damage_model = damage_model;
+                                }
                             }
                             Err(_rr) => {}
                         },
@@ -321,11 +565,48 @@ pub fn attack(
                                 &attack_event.trigger_words,
                             );
                         }
-                    },
-                    None => {}
+                    }
+
+                    combat_log.record(CombatLogRecord::new(
+                        time.seconds_since_startup(),
+                        attack_event,
+                        get_log_target(
+                            attack_result.entity_option,
+                            attack_result.cell_id_option,
+                            &rigidbody_query,
+                            &world_cells,
+                            &gridmap_data,
+                        ),
+                        dealt_damage_model,
+                        hit_result,
+                        attack_result.rigid_body_position,
+                    ));
+
+                    match hit_result {
+                        HitResult::HitSoft => {
+                            sound_hit_result = HitResult::HitSoft;
+                        }
+                        HitResult::Blocked => {
+                            if matches!(sound_hit_result, HitResult::Missed) {
+                                sound_hit_result = HitResult::Blocked;
+                            }
+                        }
+                        HitResult::Missed => {}
+                    }
                 }
 
-                match hit_result {
+                if hit_targets.is_empty() {
+                    combat_log.record(CombatLogRecord::new(
+                        time.seconds_since_startup(),
+                        attack_event,
+                        CombatLogTarget::Nothing,
+                        attack_event.damage_model.clone(),
+                        HitResult::Missed,
+                        attack_event.attacker_position,
+                    ));
+                }
+
+                match sound_hit_result {
                     crate::space::core::health::components::HitResult::HitSoft => {
                         attack_event.combat_sound_set.spawn_hit_sfx(
                             &mut commands,
@@ -367,10 +648,6 @@ pub fn attack(
                         let interaction_groups =
                             InteractionGroups::new(collider_groups.0, collider_groups.1);
 
-                        let additive = direction_additive * *laser_range;
-
-                        let mut hit_entities: Vec<AttackResult> = vec![];
-
                         let colliders = &QueryPipelineColliderComponentsSet(&collider_query);
 
                         let attack_height;
@@ -393,243 +670,414 @@ pub fn attack(
                             attack_event.attacker_position.z,
                         );
 
-                        let projectile_rough_end_position = projectile_start_position - additive;
+                        // Every bounce off a reflective surface starts a new segment of the laser.
+                        let mut segment_start_position = projectile_start_position;
+                        let mut segment_direction_additive = direction_additive;
+                        let mut segment_angle = attack_event.angle;
+                        let mut segment_range = *laser_range;
+                        let mut reflected_from: Option<ColliderHandle> = None;
+                        let mut bounces = 0;
+                        let mut energy = 1.;
 
-                        query_pipeline.intersections_with_shape(
-                            colliders,
-                            &(
+                        loop {
+                            let additive = segment_direction_additive * segment_range;
+
+                            let mut hit_entities: Vec<AttackResult> = vec![];
+
+                            let projectile_rough_end_position = segment_start_position - additive;
+
+                            let cast_position: Isometry<Real> = (
                                 projectile_rough_end_position,
-                                Quat::from_rotation_y(attack_event.angle),
+                                Quat::from_rotation_y(segment_angle),
                             )
-                                .into(),
-                            &Cuboid::new(
-                                Vec3::new(*laser_range, cast_vertical_extents, 0.1).into(),
-                            ),
-                            interaction_groups,
-                            None,
-                            |collider_handle| {
-                                let collider_entity = collider_handle.entity();
+                                .into();
+                            let cast_shape = Cuboid::new(
+                                Vec3::new(segment_range, cast_vertical_extents, 0.1).into(),
+                            );
 
-                                if collider_entity == attack_event.attacker_entity {
-                                    return true;
-                                }
+                            query_pipeline.intersections_with_shape(
+                                colliders,
+                                &cast_position,
+                                &cast_shape,
+                                interaction_groups,
+                                None,
+                                |collider_handle| {
+                                    let collider_entity = collider_handle.entity();
+
+                                    if collider_entity == attack_event.attacker_entity
+                                        || is_rewound(collider_entity, &rewound_hitboxes_option)
+                                    {
+                                        return true;
+                                    }
 
-                                let projectile_weapon_entity = attack_event.weapon_entity.unwrap();
+                                    let projectile_weapon_entity =
+                                        attack_event.weapon_entity.unwrap();
 
-                                if collider_entity == projectile_weapon_entity {
-                                    return true;
-                                }
+                                    if collider_entity == projectile_weapon_entity {
+                                        return true;
+                                    }
 
-                                match rigidbody_query.get_mut(collider_entity) {
-                                    Ok((
-                                        health_component,
-                                        _examinable_component,
-                                        rigid_body_position_component,
-                                    )) => {
-                                        let position = Vec3::new(
-                                            rigid_body_position_component.position.translation.x,
-                                            rigid_body_position_component.position.translation.y,
-                                            rigid_body_position_component.position.translation.z,
-                                        );
+                                    match rigidbody_query.get_mut(collider_entity) {
+                                        Ok((
+                                            health_component,
+                                            _examinable_component,
+                                            rigid_body_position_component,
+                                        )) => {
+                                            let position = Vec3::new(
+                                                rigid_body_position_component
+                                                    .position
+                                                    .translation
+                                                    .x,
+                                                rigid_body_position_component
+                                                    .position
+                                                    .translation
+                                                    .y,
+                                                rigid_body_position_component
+                                                    .position
+                                                    .translation
+                                                    .z,
+                                            );
 
-                                        sound_transform.translation = position;
-
-                                        hit_entities.push(AttackResult {
-                                            entity_option: Some(collider_entity),
-                                            cell_id_option: None,
-                                            distance: attack_event
-                                                .attacker_position
-                                                .distance(position),
-                                            rigid_body_position: position,
-                                            collider_handle,
-                                            is_combat_obstacle: health_component.is_combat_obstacle,
-                                            is_laser_obstacle: health_component.is_laser_obstacle,
-                                        });
+                                            sound_transform.translation = position;
+
+                                            hit_entities.push(AttackResult {
+                                                entity_option: Some(collider_entity),
+                                                cell_id_option: None,
+                                                distance: segment_start_position.distance(position),
+                                                rigid_body_position: position,
+                                                collider_handle,
+                                                is_combat_obstacle: health_component
+                                                    .is_combat_obstacle,
+                                                is_laser_obstacle: health_component
+                                                    .is_laser_obstacle,
+                                                rewound_collider_position: None,
+                                            });
+                                        }
+                                        Err(_rr) => {}
                                     }
-                                    Err(_rr) => {}
-                                }
 
-                                match physics_cells.get(collider_entity) {
-                                    Ok(cell_component) => {
-                                        let position = cell_id_to_world(cell_component.id);
-
-                                        sound_transform.translation = position;
-
-                                        let cell_data = world_cells
-                                            .grid_data
-                                            .get_mut(&cell_component.id)
-                                            .unwrap();
-
-                                        let r = AttackResult {
-                                            entity_option: None,
-                                            cell_id_option: Some(cell_component.id),
-                                            distance: attack_event
-                                                .attacker_position
-                                                .distance(position),
-                                            rigid_body_position: position,
-                                            collider_handle,
-                                            is_combat_obstacle: !gridmap_data
-                                                .non_combat_obstacle_cells_list
-                                                .contains(&cell_data.item),
-                                            is_laser_obstacle: !gridmap_data
-                                                .non_laser_obstacle_cells_list
-                                                .contains(&cell_data.item),
-                                        };
-
-                                        hit_entities.push(r);
+                                    match physics_cells.get(collider_entity) {
+                                        Ok(cell_component) => {
+                                            let position = cell_id_to_world(cell_component.id);
+
+                                            sound_transform.translation = position;
+
+                                            let cell_data = world_cells
+                                                .grid_data
+                                                .get_mut(&cell_component.id)
+                                                .unwrap();
+
+                                            let r = AttackResult {
+                                                entity_option: None,
+                                                cell_id_option: Some(cell_component.id),
+                                                distance: segment_start_position.distance(position),
+                                                rigid_body_position: position,
+                                                collider_handle,
+                                                is_combat_obstacle: !gridmap_data
+                                                    .non_combat_obstacle_cells_list
+                                                    .contains(&cell_data.item),
+                                                is_laser_obstacle: !gridmap_data
+                                                    .non_laser_obstacle_cells_list
+                                                    .contains(&cell_data.item),
+                                                rewound_collider_position: None,
+                                            };
+
+                                            hit_entities.push(r);
+                                        }
+                                        Err(_rr) => {}
                                     }
-                                    Err(_rr) => {}
-                                }
 
-                                true
-                            },
-                        );
+                                    true
+                                },
+                            );
 
-                        hit_entities.sort_by(|a, b| b.distance.partial_cmp(&a.distance).unwrap());
-                        hit_entities.reverse();
+                            add_rewound_hits(
+                                &mut hit_entities,
+                                &rewound_hitboxes_option,
+                                attack_event,
+                                segment_start_position,
+                                &cast_position,
+                                &cast_shape,
+                                interaction_groups,
+                                &collider_query,
+                                &mut rigidbody_query,
+                            );
 
-                        let mut hit_result = HitResult::Missed;
+                            // The surface the laser bounced off lies right at the start of the new segment.
+                            hit_entities.retain(|attack_result| {
+                                Some(attack_result.collider_handle) != reflected_from
+                            });
 
-                        let mut hit_entity = None;
+                            hit_entities
+                                .sort_by(|a, b| b.distance.partial_cmp(&a.distance).unwrap());
+                            hit_entities.reverse();
 
-                        match attack_event.targetted_entity {
-                            //projectile fired and targetted an entity.
-                            Some(targetted_entity) => {
-                                let mut found = false;
-                                let mut first_blocker = None;
-                                for attack_result in hit_entities.iter() {
-                                    match attack_result.entity_option {
-                                        Some(entity) => {
-                                            if targetted_entity == entity {
-                                                hit_entity = Some(attack_result);
-                                                found = true;
-                                                break;
+                            // Only the first segment goes for what the attacker targetted.
+                            let targetted_entity;
+                            let targetted_cell;
+
+                            if bounces == 0 {
+                                targetted_entity = attack_event.targetted_entity;
+                                targetted_cell = attack_event.targetted_cell;
+                            } else {
+                                targetted_entity = None;
+                                targetted_cell = None;
+                            }
+
+                            let mut hit_result = HitResult::Missed;
+
+                            let mut hit_entity = None;
+
+                            match targetted_entity {
+                                //projectile fired and targetted an entity.
+                                Some(targetted_entity) => {
+                                    let mut found = false;
+                                    let mut first_blocker = None;
+                                    for attack_result in hit_entities.iter() {
+                                        match attack_result.entity_option {
+                                            Some(entity) => {
+                                                if targetted_entity == entity {
+                                                    hit_entity = Some(attack_result);
+                                                    found = true;
+                                                    break;
+                                                }
                                             }
+                                            None => {}
                                         }
-                                        None => {}
-                                    }
 
-                                    if attack_result.is_combat_obstacle
-                                        && attack_result.is_laser_obstacle
-                                    {
-                                        first_blocker = Some(attack_result);
-                                        break;
+                                        if attack_result.is_combat_obstacle
+                                            && attack_result.is_laser_obstacle
+                                        {
+                                            first_blocker = Some(attack_result);
+                                            break;
+                                        }
                                     }
-                                }
-                                if !found {
-                                    match first_blocker {
-                                        Some(ff) => {
-                                            hit_entity = Some(ff);
+                                    if !found {
+                                        match first_blocker {
+                                            Some(ff) => {
+                                                hit_entity = Some(ff);
+                                            }
+                                            None => {}
                                         }
-                                        None => {}
                                     }
                                 }
-                            }
-                            None => {
-                                match attack_event.targetted_cell {
-                                    // Projectile fired and targetted a cell.
-                                    Some(targetted_cell) => {
-                                        let mut found = false;
-                                        let mut first_blocker = None;
-                                        for attack_result in hit_entities.iter() {
-                                            match attack_result.cell_id_option {
-                                                Some(cell_id) => {
-                                                    if targetted_cell == cell_id {
-                                                        hit_entity = Some(attack_result);
-                                                        found = true;
-                                                        break;
+                                None => {
+                                    match targetted_cell {
+                                        // Projectile fired and targetted a cell.
+                                        Some(targetted_cell) => {
+                                            let mut found = false;
+                                            let mut first_blocker = None;
+                                            for attack_result in hit_entities.iter() {
+                                                match attack_result.cell_id_option {
+                                                    Some(cell_id) => {
+                                                        if targetted_cell == cell_id {
+                                                            hit_entity = Some(attack_result);
+                                                            found = true;
+                                                            break;
+                                                        }
                                                     }
+                                                    None => {}
                                                 }
-                                                None => {}
-                                            }
 
-                                            if attack_result.is_combat_obstacle
-                                                && attack_result.is_laser_obstacle
-                                            {
-                                                first_blocker = Some(attack_result);
-                                                break;
+                                                if attack_result.is_combat_obstacle
+                                                    && attack_result.is_laser_obstacle
+                                                {
+                                                    first_blocker = Some(attack_result);
+                                                    break;
+                                                }
                                             }
-                                        }
-                                        if !found {
-                                            match first_blocker {
-                                                Some(ff) => {
-                                                    hit_entity = Some(ff);
+                                            if !found {
+                                                match first_blocker {
+                                                    Some(ff) => {
+                                                        hit_entity = Some(ff);
+                                                    }
+                                                    None => {}
                                                 }
-                                                None => {}
                                             }
                                         }
-                                    }
-                                    None => {
-                                        // Projectile fired without targetting
-                                        for res in hit_entities.iter() {
-                                            if res.is_combat_obstacle && res.is_laser_obstacle {
-                                                hit_entity = Some(res);
-                                                break;
+                                        None => {
+                                            // Projectile fired without targetting
+                                            for res in hit_entities.iter() {
+                                                if res.is_combat_obstacle && res.is_laser_obstacle {
+                                                    hit_entity = Some(res);
+                                                    break;
+                                                }
                                             }
                                         }
                                     }
                                 }
                             }
-                        }
-                        let mut hit_point: Vec3;
-
-                        match hit_entity {
-                            Some(attack_result) => {
-                                let ray = Ray::new(
-                                    projectile_start_position.into(),
-                                    (attack_result.rigid_body_position - projectile_start_position)
-                                        .into(),
-                                );
-                                let max_toi = attack_result.distance * 1.2;
+                            let hit_distance;
+
+                            match hit_entity {
+                                Some(attack_result) => {
+                                    hit_distance = attack_result.distance;
+                                }
+                                None => {
+                                    hit_distance = f32::MAX;
+                                }
+                            }
+
+                            // Refractive surfaces the laser passes through on the way take some of its energy.
+                            for attack_result in hit_entities.iter() {
+                                if attack_result.distance >= hit_distance {
+                                    break;
+                                }
+                                match get_laser_surface(
+                                    attack_result,
+                                    &laser_surfaces,
+                                    &world_cells,
+                                    &gridmap_data,
+                                ) {
+                                    Some(laser_surface) => match laser_surface.kind {
+                                        LaserSurfaceKind::Refractive => {
+                                            energy *= laser_surface.energy_factor;
+                                        }
+                                        LaserSurfaceKind::Reflective => {}
+                                    },
+                                    None => {}
+                                }
+                            }
 
-                                if let Some((_hit_collider_handle, hit_toi)) = query_pipeline
-                                    .cast_ray(
-                                        colliders,
+                            let damage_model = attack_event.damage_model.scaled(energy);
+                            let mut dealt_damage_model = damage_model.clone();
+
+                            let mut hit_point: Vec3;
+                            let mut reflection = None;
+
+                            match hit_entity {
+                                Some(attack_result) => {
+                                    let ray = Ray::new(
+                                        segment_start_position.into(),
+                                        (attack_result.rigid_body_position
+                                            - segment_start_position)
+                                            .into(),
+                                    );
+                                    let max_toi = attack_result.distance * 1.2;
+
+                                    if let Some((hit_toi, hit_normal)) = cast_ray_at_hit(
+                                        attack_result,
                                         &ray,
                                         max_toi,
-                                        true,
+                                        &query_pipeline,
+                                        &collider_query,
                                         interaction_groups,
-                                        Some(&|collider_handle| {
-                                            collider_handle == attack_result.collider_handle
-                                        }),
-                                    )
-                                {
-                                    hit_point = ray.point_at(hit_toi).into();
+                                    ) {
+                                        hit_point = ray.point_at(hit_toi).into();
 
-                                    match attack_result.entity_option {
-                                        Some(_) => {}
-                                        None => {
-                                            hit_point.y = ATTACK_HEIGHT;
+                                        match attack_result.entity_option {
+                                            Some(_) => {}
+                                            None => {
+                                                hit_point.y = ATTACK_HEIGHT;
+                                            }
                                         }
-                                    }
 
-                                    sound_transform.translation = hit_point;
-
-                                    match attack_result.entity_option {
-                                        Some(collider_entity) => {
-                                            match rigidbody_query.get_mut(collider_entity) {
-                                                Ok((
-                                                    mut health_component,
-                                                    examinable_component,
-                                                    rigid_body_position_component,
-                                                )) => {
-                                                    let attacked_cell_id = world_to_cell_id(
-                                                        rigid_body_position_component
-                                                            .position
-                                                            .translation
-                                                            .into(),
-                                                    );
+                                        sound_transform.translation = hit_point;
+
+                                        let laser_surface_option = get_laser_surface(
+                                            attack_result,
+                                            &laser_surfaces,
+                                            &world_cells,
+                                            &gridmap_data,
+                                        );
 
-                                                    hit_result = health_component.apply_damage(
+                                        match laser_surface_option {
+                                            Some(LaserSurface {
+                                                kind: LaserSurfaceKind::Reflective,
+                                                energy_factor,
+                                            }) => {
+                                                // Reflective surfaces send the laser on instead of taking the hit.
+                                                hit_result = HitResult::Blocked;
+                                                dealt_damage_model = DamageModel::default();
+                                                reflection = Some((
+                                                    hit_normal,
+                                                    attack_result.collider_handle,
+                                                    energy_factor,
+                                                ));
+                                            }
+                                            _ => match attack_result.entity_option {
+                                                Some(collider_entity) => {
+                                                    match rigidbody_query.get_mut(collider_entity) {
+                                                        Ok((
+                                                            mut health_component,
+                                                            examinable_component,
+                                                            rigid_body_position_component,
+                                                        )) => {
+                                                            let attacked_position: Vec3 =
+                                                                rigid_body_position_component
+                                                                    .position
+                                                                    .translation
+                                                                    .into();
+                                                            let attacked_cell_id =
+                                                                world_to_cell_id(attacked_position);
+
+                                                            if is_attack_prevented(
+                                                                attack_event,
+                                                                collider_entity,
+                                                                attacked_position,
+                                                                &pawns,
+                                                                &combat_rules,
+                                                                &mut net_message_event,
+                                                                &handle_to_entity,
+                                                            ) {
+                                                                hit_result = HitResult::Blocked;
+                                                                dealt_damage_model =
+                                                                    DamageModel::default();
+                                                            } else {
+                                                                hit_result = health_component
+                                                                    .apply_damage(
+                                                                        &attack_event
+                                                                            .targetted_limb,
+                                                                        &damage_model,
+                                                                        &mut net_message_event,
+                                                                        &handle_to_entity,
+                                                                        &attacker_cell_id,
+                                                                        &attacked_cell_id,
+                                                                        &sensers,
+                                                                        &attack_event.attacker_name,
+                                                                        &examinable_component
+                                                                            .name
+                                                                            .get_a_name(),
+                                                                        &DamageType::Projectile,
+                                                                        &attack_event.weapon_name,
+                                                                        &attack_event.weapon_a_name,
+                                                                        &attack_event.offense_words,
+                                                                        &attack_event.trigger_words,
+                                                                    );
+
+                                                                if matches!(
+                                                                    hit_result,
+                                                                    HitResult::HitSoft
+                                                                ) {
+                                                                    send_status_effects(
+                                                                        collider_entity,
+                                                                        attack_event,
+                                                                        &mut inflict_status_effects,
+                                                                    );
+                                                                }
+                                                            }
+                                                        }
+                                                        Err(_rr) => {}
+                                                    }
+                                                }
+                                                None => {
+                                                    let attacked_cell_id =
+                                                        attack_result.cell_id_option.unwrap();
+                                                    let cell_data = world_cells
+                                                        .grid_data
+                                                        .get_mut(&attacked_cell_id)
+                                                        .unwrap();
+
+                                                    hit_result = cell_data.health.apply_damage(
                                                         &attack_event.targetted_limb,
-                                                        &attack_event.damage_model,
+                                                        &damage_model,
                                                         &mut net_message_event,
                                                         &handle_to_entity,
                                                         &attacker_cell_id,
                                                         &attacked_cell_id,
                                                         &sensers,
                                                         &attack_event.attacker_name,
-                                                        &examinable_component.name.get_a_name(),
+                                                        &get_cell_name(cell_data, &gridmap_data),
                                                         &DamageType::Projectile,
                                                         &attack_event.weapon_name,
                                                         &attack_event.weapon_a_name,
@@ -637,37 +1085,10 @@ pub fn attack(
                                                         &attack_event.trigger_words,
                                                     );
                                                 }
-                                                Err(_rr) => {}
-                                            }
-                                        }
-                                        None => {
-                                            let attacked_cell_id =
-                                                attack_result.cell_id_option.unwrap();
-                                            let cell_data = world_cells
-                                                .grid_data
-                                                .get_mut(&attacked_cell_id)
-                                                .unwrap();
-
-                                            hit_result = cell_data.health.apply_damage(
-                                                &attack_event.targetted_limb,
-                                                &attack_event.damage_model,
-                                                &mut net_message_event,
-                                                &handle_to_entity,
-                                                &attacker_cell_id,
-                                                &attacked_cell_id,
-                                                &sensers,
-                                                &attack_event.attacker_name,
-                                                &get_cell_name(cell_data, &gridmap_data),
-                                                &DamageType::Projectile,
-                                                &attack_event.weapon_name,
-                                                &attack_event.weapon_a_name,
-                                                &attack_event.offense_words,
-                                                &attack_event.trigger_words,
-                                            );
+                                            },
                                         }
-                                    }
 
-                                    match hit_result {
+                                        match hit_result {
                                         crate::space::core::health::components::HitResult::HitSoft => {
                                             attack_event.combat_sound_set.spawn_hit_sfx(&mut commands, sound_transform, &mut sfx_auto_destroy_timers);
                                         },
@@ -676,32 +1097,958 @@ pub fn attack(
                                         },
                                         crate::space::core::health::components::HitResult::Missed => {},
                                     }
-                                } else {
+                                    } else {
+                                        hit_point = attack_result.rigid_body_position;
+                                    };
+                                }
+                                None => {
+                                    hit_point = projectile_rough_end_position;
+                                }
+                            }
+
+                            let log_target;
+
+                            match hit_entity {
+                                Some(attack_result) => {
+                                    log_target = get_log_target(
+                                        attack_result.entity_option,
+                                        attack_result.cell_id_option,
+                                        &rigidbody_query,
+                                        &world_cells,
+                                        &gridmap_data,
+                                    );
+                                }
+                                None => {
+                                    log_target = CombatLogTarget::Nothing;
+                                }
+                            }
+
+                            combat_log.record(CombatLogRecord::new(
+                                time.seconds_since_startup(),
+                                attack_event,
+                                log_target,
+                                dealt_damage_model,
+                                hit_result,
+                                hit_point,
+                            ));
+
+                            // Lasers set flammable gas alight where they end.
+                            match get_laser_ignition_cell(
+                                segment_start_position,
+                                hit_point,
+                                &atmospherics_resource,
+                            ) {
+                                Some(cell_id) => {
+                                    ignite_atmospherics.send(IgniteAtmospherics { cell_id });
+                                }
+                                None => {}
+                            }
+
+                            let c_start_pos;
+
+                            if bounces == 0 {
+                                c_start_pos =
+                                    projectile_start_position - (direction_additive * 0.5);
+                            } else {
+                                c_start_pos = segment_start_position;
+                            }
+
+                            if bounces > 0 || c_start_pos.distance(hit_point) > 0.8 {
+                                projectile_fov.send(ProjectileFOV {
+                                    laser_projectile: NetProjectileType::Laser(
+                                        *laser_color,
+                                        *laser_height,
+                                        *laser_radius,
+                                        c_start_pos,
+                                        hit_point,
+                                    ),
+                                });
+                            }
+
+                            let hit_normal: Vec3;
+                            let reflected_collider_handle;
+                            let energy_factor;
+
+                            match reflection {
+                                Some((n, h, f)) => {
+                                    hit_normal = n;
+                                    reflected_collider_handle = h;
+                                    energy_factor = f;
+                                }
+                                None => {
+                                    break;
+                                }
+                            }
+
+                            energy *= energy_factor;
+
+                            let normal =
+                                Vec3::new(hit_normal.x, 0., hit_normal.z).normalize_or_zero();
+                            let travelled = segment_start_position.distance(hit_point);
+
+                            if normal == Vec3::ZERO
+                                || bounces >= MAX_LASER_BOUNCES
+                                || energy < MIN_LASER_ENERGY
+                                || travelled >= segment_range
+                            {
+                                break;
+                            }
+
+                            let direction = -segment_direction_additive;
+                            let reflected_direction =
+                                direction - 2. * direction.dot(normal) * normal;
+
+                            segment_direction_additive = -reflected_direction;
+                            segment_angle = (-reflected_direction.z).atan2(reflected_direction.x);
+                            segment_range -= travelled;
+                            segment_start_position =
+                                Vec3::new(hit_point.x, segment_start_position.y, hit_point.z);
+                            reflected_from = Some(reflected_collider_handle);
+                            bounces += 1;
+                        }
+                    }
+                    crate::space::core::inventory_item::components::ProjectileType::Ballistic(
+                        ballistic,
+                    ) => {
+                        attack_event.combat_sound_set.spawn_default_sfx(
+                            &mut commands,
+                            sound_transform,
+                            &mut sfx_auto_destroy_timers,
+                        );
+
+                        let collider_groups = get_bit_masks(ColliderGroup::Standard);
+                        let interaction_groups =
+                            InteractionGroups::new(collider_groups.0, collider_groups.1);
+
+                        let colliders = &QueryPipelineColliderComponentsSet(&collider_query);
+
+                        let attack_height;
+                        let cast_vertical_extents;
+
+                        if attack_event.weapon_entity.is_none()
+                            || !attack_event.targetted_entity.is_none()
+                            || !attack_event.targetted_cell.is_none()
+                        {
+                            attack_height = 1.;
+                            cast_vertical_extents = 1.;
+                        } else {
+                            attack_height = ATTACK_HEIGHT;
+                            cast_vertical_extents = 0.1;
+                        }
+
+                        let projectile_start_position = Vec3::new(
+                            attack_event.attacker_position.x,
+                            attack_height,
+                            attack_event.attacker_position.z,
+                        );
+
+                        let hit_entities = get_projectile_hits(
+                            attack_event,
+                            projectile_start_position,
+                            ballistic.range,
+                            cast_vertical_extents,
+                            &query_pipeline,
+                            colliders,
+                            interaction_groups,
+                            &mut rigidbody_query,
+                            &physics_cells,
+                            &world_cells,
+                            &gridmap_data,
+                            &rewound_hitboxes_option,
+                            &collider_query,
+                        );
+
+                        let mut penetration_factor = 1.;
+                        let mut penetrations = 0;
+                        let mut impacted = false;
+                        let mut decals = vec![];
+                        let mut projectile_end_position =
+                            projectile_start_position - (direction_additive * ballistic.range);
+
+                        for attack_result in hit_entities.iter() {
+                            let is_target;
+                            let mut penetrates = false;
+
+                            match attack_result.entity_option {
+                                Some(entity) => {
+                                    is_target = attack_event.targetted_entity == Some(entity);
+                                }
+                                None => {
+                                    let cell_id = attack_result.cell_id_option.unwrap();
+                                    is_target = attack_event.targetted_cell == Some(cell_id);
+
+                                    if !is_target && penetrations < ballistic.max_penetrations {
+                                        match world_cells.grid_data.get(&cell_id) {
+                                            Some(cell_data) => {
+                                                match gridmap_data
+                                                    .main_id_name_map
+                                                    .get(&cell_data.item)
+                                                {
+                                                    Some(cell_name) => {
+                                                        penetrates = ballistic
+                                                            .penetrable_cells
+                                                            .contains(cell_name);
+                                                    }
+                                                    None => {}
+                                                }
+                                            }
+                                            None => {}
+                                        }
+                                    }
+                                }
+                            }
+
+                            if !is_target && !attack_result.is_combat_obstacle {
+                                continue;
+                            }
+
+                            let ray = Ray::new(
+                                projectile_start_position.into(),
+                                (attack_result.rigid_body_position - projectile_start_position)
+                                    .into(),
+                            );
+
+                            let mut hit_point: Vec3;
+                            let mut hit_normal = Vec3::ZERO;
+
+                            match cast_ray_at_hit(
+                                attack_result,
+                                &ray,
+                                attack_result.distance * 1.2,
+                                &query_pipeline,
+                                &collider_query,
+                                interaction_groups,
+                            ) {
+                                Some((hit_toi, normal)) => {
+                                    hit_point = ray.point_at(hit_toi).into();
+                                    hit_normal = normal;
+                                }
+                                None => {
                                     hit_point = attack_result.rigid_body_position;
-                                };
+                                }
+                            }
+
+                            if attack_result.entity_option.is_none() {
+                                hit_point.y = ATTACK_HEIGHT;
+                                decals.push((hit_point, hit_normal));
+                            }
+
+                            let distance = projectile_start_position.distance(hit_point);
+
+                            let travel_time;
+
+                            match ballistic.speed_option {
+                                Some(speed) => {
+                                    travel_time = distance / speed;
+                                }
+                                None => {
+                                    travel_time = 0.;
+                                }
+                            }
+
+                            pending_ballistic_impacts.impacts.push(BallisticImpact {
+                                attack: attack_event.clone(),
+                                entity_option: attack_result.entity_option,
+                                cell_id_option: attack_result.cell_id_option,
+                                hit_point,
+                                damage_factor: penetration_factor
+                                    * ballistic.get_falloff_factor(distance),
+                                timer: Timer::from_seconds(travel_time, false),
+                            });
+                            impacted = true;
+
+                            if penetrates {
+                                penetration_factor *= ballistic.penetration_damage_factor;
+                                penetrations += 1;
+                                continue;
+                            }
+
+                            projectile_end_position = hit_point;
+                            break;
+                        }
+
+                        // Hits get logged once the projectile arrives.
+                        if !impacted {
+                            combat_log.record(CombatLogRecord::new(
+                                time.seconds_since_startup(),
+                                attack_event,
+                                CombatLogTarget::Nothing,
+                                attack_event.damage_model.clone(),
+                                HitResult::Missed,
+                                projectile_end_position,
+                            ));
+                        }
+
+                        let speed;
+
+                        match ballistic.speed_option {
+                            Some(projectile_speed) => {
+                                speed = projectile_speed;
                             }
                             None => {
-                                hit_point = projectile_rough_end_position;
+                                speed = 0.;
                             }
                         }
 
-                        let c_start_pos = projectile_start_position - (direction_additive * 0.5);
-
-                        if c_start_pos.distance(hit_point) > 0.8 {
-                            projectile_fov.send(ProjectileFOV {
-                                laser_projectile: NetProjectileType::Laser(
-                                    *laser_color,
-                                    *laser_height,
-                                    *laser_radius,
-                                    c_start_pos,
-                                    hit_point,
-                                ),
-                            });
+                        projectile_fov.send(ProjectileFOV {
+                            laser_projectile: NetProjectileType::Ballistic(
+                                projectile_start_position - (direction_additive * 0.5),
+                                projectile_end_position,
+                                speed,
+                                decals,
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Apply the damage of ballistic projectiles once they have reached what they hit.
+pub fn ballistic_impacts(
+    time: Res<Time>,
+    mut pending_ballistic_impacts: ResMut<PendingBallisticImpacts>,
+    mut rigidbody_query: Query<(&mut Health, &Examinable, &RigidBodyPositionComponent)>,
+    mut world_cells: ResMut<GridmapMain>,
+    mut net_message_event: EventWriter<NetChatMessage>,
+    handle_to_entity: Res<HandleToEntity>,
+    mut commands: Commands,
+    mut sfx_auto_destroy_timers: ResMut<SfxAutoDestroyTimers>,
+    sensers: Query<(Entity, &Senser)>,
+    gridmap_data: Res<GridmapData>,
+    mut inflict_status_effects: EventWriter<InflictStatusEffects>,
+    mut combat_log: ResMut<CombatLog>,
+    pawns: Query<&Pawn>,
+    combat_rules: Res<CombatRules>,
+) {
+    for impact in pending_ballistic_impacts.impacts.iter_mut() {
+        impact.timer.tick(time.delta());
+    }
+
+    let (arrived_impacts, in_flight_impacts): (Vec<BallisticImpact>, Vec<BallisticImpact>) =
+        pending_ballistic_impacts
+            .impacts
+            .drain(..)
+            .partition(|impact| impact.timer.finished());
+
+    pending_ballistic_impacts.impacts = in_flight_impacts;
+
+    for impact in arrived_impacts {
+        let attack_event = &impact.attack;
+        let mut damage_model = attack_event.damage_model.scaled(impact.damage_factor);
+        let attacker_cell_id = world_to_cell_id(attack_event.attacker_position);
+
+        let mut hit_result;
+
+        match impact.entity_option {
+            Some(hit_entity) => match rigidbody_query.get_mut(hit_entity) {
+                Ok((mut health_component, examinable_component, rigid_body_position_component)) => {
+                    let attacked_position: Vec3 =
+                        rigid_body_position_component.position.translation.into();
+                    let attacked_cell_id = world_to_cell_id(attacked_position);
+
+                    if is_attack_prevented(
+                        attack_event,
+                        hit_entity,
+                        attacked_position,
+                        &pawns,
+                        &combat_rules,
+                        &mut net_message_event,
+                        &handle_to_entity,
+                    ) {
+                        hit_result = HitResult::Blocked;
+                        damage_model = DamageModel::default();
+                    } else {
+                        hit_result = health_component.apply_damage(
+                            &attack_event.targetted_limb,
+                            &damage_model,
+                            &mut net_message_event,
+                            &handle_to_entity,
+                            &attacker_cell_id,
+                            &attacked_cell_id,
+                            &sensers,
+                            &attack_event.attacker_name,
+                            &examinable_component.name.get_a_name(),
+                            &DamageType::Projectile,
+                            &attack_event.weapon_name,
+                            &attack_event.weapon_a_name,
+                            &attack_event.offense_words,
+                            &attack_event.trigger_words,
+                        );
+
+                        if matches!(hit_result, HitResult::HitSoft) {
+                            send_status_effects(
+                                hit_entity,
+                                attack_event,
+                                &mut inflict_status_effects,
+                            );
                         }
                     }
-                    crate::space::core::inventory_item::components::ProjectileType::Ballistic => {}
+                }
+                Err(_rr) => {
+                    continue;
+                }
+            },
+            None => {
+                let attacked_cell_id = impact.cell_id_option.unwrap();
+
+                // The cell may have been removed while the projectile was underway.
+                match world_cells.grid_data.get_mut(&attacked_cell_id) {
+                    Some(cell_data) => {
+                        let cell_name = get_cell_name(cell_data, &gridmap_data);
+
+                        hit_result = cell_data.health.apply_damage(
+                            &attack_event.targetted_limb,
+                            &damage_model,
+                            &mut net_message_event,
+                            &handle_to_entity,
+                            &attacker_cell_id,
+                            &attacked_cell_id,
+                            &sensers,
+                            &attack_event.attacker_name,
+                            &cell_name,
+                            &DamageType::Projectile,
+                            &attack_event.weapon_name,
+                            &attack_event.weapon_a_name,
+                            &attack_event.offense_words,
+                            &attack_event.trigger_words,
+                        );
+                    }
+                    None => {
+                        continue;
+                    }
+                }
+            }
+        }
+
+        let log_target = get_log_target(
+            impact.entity_option,
+            impact.cell_id_option,
+            &rigidbody_query,
+            &world_cells,
+            &gridmap_data,
+        );
+
+        combat_log.record(CombatLogRecord::new(
+            time.seconds_since_startup(),
+            attack_event,
+            log_target,
+            damage_model,
+            hit_result,
+            impact.hit_point,
+        ));
+
+        let sound_transform = Transform {
+            translation: impact.hit_point,
+            rotation: Quat::IDENTITY,
+            scale: Vec3::ONE,
+        };
+
+        match hit_result {
+            HitResult::HitSoft => {
+                attack_event.combat_sound_set.spawn_hit_sfx(
+                    &mut commands,
+                    sound_transform,
+                    &mut sfx_auto_destroy_timers,
+                );
+            }
+            HitResult::Blocked => {
+                attack_event.combat_sound_set.spawn_hit_blocked(
+                    &mut commands,
+                    sound_transform,
+                    &mut sfx_auto_destroy_timers,
+                );
+            }
+            HitResult::Missed => {}
+        }
+    }
+}
+
+// Everything a projectile sweeping along the attack angle could hit, nearest first.
+fn get_projectile_hits(
+    attack_event: &Attack,
+    projectile_start_position: Vec3,
+    range: f32,
+    cast_vertical_extents: f32,
+    query_pipeline: &QueryPipeline,
+    colliders: &QueryPipelineColliderComponentsSet,
+    interaction_groups: InteractionGroups,
+    rigidbody_query: &mut Query<(&mut Health, &Examinable, &RigidBodyPositionComponent)>,
+    physics_cells: &Query<&Cell>,
+    world_cells: &GridmapMain,
+    gridmap_data: &GridmapData,
+    rewound_hitboxes_option: &Option<HashMap<Entity, HitboxState>>,
+    collider_query: &QueryPipelineColliderComponentsQuery,
+) -> Vec<AttackResult> {
+    let direction_additive = Vec3::new(-attack_event.angle.cos(), 0., attack_event.angle.sin());
+
+    let mut hit_entities: Vec<AttackResult> = vec![];
+
+    let cast_position: Isometry<Real> = (
+        projectile_start_position - (direction_additive * range),
+        Quat::from_rotation_y(attack_event.angle),
+    )
+        .into();
+    let cast_shape = Cuboid::new(Vec3::new(range, cast_vertical_extents, 0.1).into());
+
+    query_pipeline.intersections_with_shape(
+        colliders,
+        &cast_position,
+        &cast_shape,
+        interaction_groups,
+        None,
+        |collider_handle| {
+            let collider_entity = collider_handle.entity();
+
+            if collider_entity == attack_event.attacker_entity
+                || Some(collider_entity) == attack_event.weapon_entity
+                || is_rewound(collider_entity, rewound_hitboxes_option)
+            {
+                return true;
+            }
+
+            match rigidbody_query.get_mut(collider_entity) {
+                Ok((health_component, _examinable_component, rigid_body_position_component)) => {
+                    let position: Vec3 = rigid_body_position_component.position.translation.into();
+
+                    hit_entities.push(AttackResult {
+                        entity_option: Some(collider_entity),
+                        cell_id_option: None,
+                        distance: attack_event.attacker_position.distance(position),
+                        rigid_body_position: position,
+                        collider_handle,
+                        is_combat_obstacle: health_component.is_combat_obstacle,
+                        is_laser_obstacle: health_component.is_laser_obstacle,
+                        rewound_collider_position: None,
+                    });
+                }
+                Err(_rr) => {}
+            }
+
+            match physics_cells.get(collider_entity) {
+                Ok(cell_component) => match world_cells.grid_data.get(&cell_component.id) {
+                    Some(cell_data) => {
+                        let position = cell_id_to_world(cell_component.id);
+
+                        hit_entities.push(AttackResult {
+                            entity_option: None,
+                            cell_id_option: Some(cell_component.id),
+                            distance: attack_event.attacker_position.distance(position),
+                            rigid_body_position: position,
+                            collider_handle,
+                            is_combat_obstacle: !gridmap_data
+                                .non_combat_obstacle_cells_list
+                                .contains(&cell_data.item),
+                            is_laser_obstacle: !gridmap_data
+                                .non_laser_obstacle_cells_list
+                                .contains(&cell_data.item),
+                            rewound_collider_position: None,
+                        });
+                    }
+                    None => {}
+                },
+                Err(_rr) => {}
+            }
+
+            true
+        },
+    );
+
+    add_rewound_hits(
+        &mut hit_entities,
+        rewound_hitboxes_option,
+        attack_event,
+        attack_event.attacker_position,
+        &cast_position,
+        &cast_shape,
+        interaction_groups,
+        collider_query,
+        rigidbody_query,
+    );
+
+    hit_entities.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap());
+
+    hit_entities
+}
+
+// Tells the attacker when the combat rules do not allow their attack on a pawn.
+fn is_attack_prevented(
+    attack_event: &Attack,
+    target_entity: Entity,
+    target_position: Vec3,
+    pawns: &Query<&Pawn>,
+    combat_rules: &CombatRules,
+    net_message_event: &mut EventWriter<NetChatMessage>,
+    handle_to_entity: &Res<HandleToEntity>,
+) -> bool {
+    let attacker_pawn_component;
+    let target_pawn_component;
+
+    match (
+        pawns.get(attack_event.attacker_entity),
+        pawns.get(target_entity),
+    ) {
+        (Ok(attacker), Ok(target)) => {
+            attacker_pawn_component = attacker;
+            target_pawn_component = target;
+        }
+        _ => {
+            return false;
+        }
+    }
+
+    match combat_rules.get_prevented_reason(
+        attacker_pawn_component.job,
+        attack_event.attacker_position,
+        target_pawn_component.job,
+        target_position,
+    ) {
+        Some(reason) => {
+            new_personal_message(
+                net_message_event,
+                handle_to_entity,
+                attack_event.attacker_entity,
+                &("Your attack on ".to_string()
+                    + &target_pawn_component.name
+                    + " was prevented, "
+                    + &reason
+                    + "."),
+            );
+            true
+        }
+        None => false,
+    }
+}
+
+fn get_log_target(
+    entity_option: Option<Entity>,
+    cell_id_option: Option<Vec3Int>,
+    rigidbody_query: &Query<(&mut Health, &Examinable, &RigidBodyPositionComponent)>,
+    world_cells: &GridmapMain,
+    gridmap_data: &Res<GridmapData>,
+) -> CombatLogTarget {
+    match entity_option {
+        Some(entity) => match rigidbody_query.get(entity) {
+            Ok((_health_component, examinable_component, _rigid_body_position_component)) => {
+                CombatLogTarget::Entity(entity, examinable_component.name.get_name().to_string())
+            }
+            Err(_rr) => CombatLogTarget::Entity(entity, "".to_string()),
+        },
+        None => match cell_id_option {
+            Some(cell_id) => match world_cells.grid_data.get(&cell_id) {
+                Some(cell_data) => {
+                    CombatLogTarget::Cell(cell_id, get_cell_name(cell_data, gridmap_data))
+                }
+                None => CombatLogTarget::Cell(cell_id, "".to_string()),
+            },
+            None => CombatLogTarget::Nothing,
+        },
+    }
+}
+
+fn get_laser_surface(
+    attack_result: &AttackResult,
+    laser_surfaces: &Query<&LaserSurface>,
+    world_cells: &GridmapMain,
+    gridmap_data: &GridmapData,
+) -> Option<LaserSurface> {
+    match attack_result.entity_option {
+        Some(entity) => match laser_surfaces.get(entity) {
+            Ok(laser_surface_component) => Some(*laser_surface_component),
+            Err(_rr) => None,
+        },
+        None => match attack_result.cell_id_option {
+            Some(cell_id) => match world_cells.grid_data.get(&cell_id) {
+                Some(cell_data) => match gridmap_data.main_cell_properties.get(&cell_data.item) {
+                    Some(cell_properties) => cell_properties.laser_surface,
+                    None => None,
+                },
+                None => None,
+            },
+            None => None,
+        },
+    }
+}
+
+fn is_rewound(
+    entity: Entity,
+    rewound_hitboxes_option: &Option<HashMap<Entity, HitboxState>>,
+) -> bool {
+    match rewound_hitboxes_option {
+        Some(rewound_hitboxes) => rewound_hitboxes.contains_key(&entity),
+        None => false,
+    }
+}
+
+// The physics world only knows where entities are now, rewound hitboxes are tested by hand instead.
+fn add_rewound_hits(
+    hit_entities: &mut Vec<AttackResult>,
+    rewound_hitboxes_option: &Option<HashMap<Entity, HitboxState>>,
+    attack_event: &Attack,
+    origin: Vec3,
+    cast_position: &Isometry<Real>,
+    cast_shape: &dyn Shape,
+    interaction_groups: InteractionGroups,
+    collider_query: &QueryPipelineColliderComponentsQuery,
+    rigidbody_query: &mut Query<(&mut Health, &Examinable, &RigidBodyPositionComponent)>,
+) {
+    let rewound_hitboxes;
+
+    match rewound_hitboxes_option {
+        Some(r) => {
+            rewound_hitboxes = r;
+        }
+        None => {
+            return;
+        }
+    }
+
+    for (entity, hitbox_state) in rewound_hitboxes.iter() {
+        if *entity == attack_event.attacker_entity || Some(*entity) == attack_event.weapon_entity {
+            continue;
+        }
+
+        match collider_query.get(*entity) {
+            Ok((
+                _entity,
+                _collider_position_component,
+                collider_shape_component,
+                collider_flags_component,
+            )) => {
+                if !collider_flags_component
+                    .collision_groups
+                    .test(interaction_groups)
+                {
+                    continue;
+                }
+                match intersection_test(
+                    cast_position,
+                    cast_shape,
+                    &hitbox_state.collider_position,
+                    &*collider_shape_component.0,
+                ) {
+                    Ok(true) => {}
+                    _ => {
+                        continue;
+                    }
                 }
             }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        match rigidbody_query.get_mut(*entity) {
+            Ok((health_component, _examinable_component, _rigid_body_position_component)) => {
+                hit_entities.push(AttackResult {
+                    entity_option: Some(*entity),
+                    cell_id_option: None,
+                    distance: origin.distance(hitbox_state.rigid_body_position),
+                    rigid_body_position: hitbox_state.rigid_body_position,
+                    collider_handle: entity.handle(),
+                    is_combat_obstacle: health_component.is_combat_obstacle,
+                    is_laser_obstacle: health_component.is_laser_obstacle,
+                    rewound_collider_position: Some(hitbox_state.collider_position),
+                });
+            }
+            Err(_rr) => {}
+        }
+    }
+}
+
+// Rewound hits are cast against the shape where it was, everything else against the physics world.
+fn cast_ray_at_hit(
+    attack_result: &AttackResult,
+    ray: &Ray,
+    max_toi: f32,
+    query_pipeline: &QueryPipeline,
+    collider_query: &QueryPipelineColliderComponentsQuery,
+    interaction_groups: InteractionGroups,
+) -> Option<(f32, Vec3)> {
+    match (
+        attack_result.rewound_collider_position,
+        attack_result.entity_option,
+    ) {
+        (Some(collider_position), Some(entity)) => match collider_query.get(entity) {
+            Ok((
+                _entity,
+                _collider_position_component,
+                collider_shape_component,
+                _collider_flags_component,
+            )) => collider_shape_component
+                .cast_ray_and_get_normal(&collider_position, ray, max_toi, true)
+                .map(|intersection| (intersection.toi, intersection.normal.into())),
+            Err(_rr) => None,
+        },
+        _ => query_pipeline
+            .cast_ray_and_get_normal(
+                &QueryPipelineColliderComponentsSet(collider_query),
+                ray,
+                max_toi,
+                true,
+                interaction_groups,
+                Some(&|collider_handle| collider_handle == attack_result.collider_handle),
+            )
+            .map(|(_hit_collider_handle, intersection)| {
+                (intersection.toi, intersection.normal.into())
+            }),
+    }
+}
+
+// Whether a wall or other obstacle cell stands between the attacker and a target of a sweep.
+fn is_sweep_obstructed(
+    attack_result: &AttackResult,
+    hit_entities: &Vec<AttackResult>,
+    attack_origin: Vec3,
+    query_pipeline: &QueryPipeline,
+    collider_query: &QueryPipelineColliderComponentsQuery,
+    interaction_groups: InteractionGroups,
+) -> bool {
+    let obstacle_cells: Vec<ColliderHandle> = hit_entities
+        .iter()
+        .filter(|hit_entity| hit_entity.cell_id_option.is_some() && hit_entity.is_combat_obstacle)
+        .map(|hit_entity| hit_entity.collider_handle)
+        .collect();
+
+    if obstacle_cells.is_empty() {
+        return false;
+    }
+
+    let target_position = Vec3::new(
+        attack_result.rigid_body_position.x,
+        attack_origin.y,
+        attack_result.rigid_body_position.z,
+    );
+    let distance = attack_origin.distance(target_position);
+
+    if distance == 0. {
+        return false;
+    }
+
+    let ray = Ray::new(
+        attack_origin.into(),
+        ((target_position - attack_origin) / distance).into(),
+    );
+
+    query_pipeline
+        .cast_ray(
+            &QueryPipelineColliderComponentsSet(collider_query),
+            &ray,
+            distance,
+            true,
+            interaction_groups,
+            Some(&|collider_handle| obstacle_cells.contains(&collider_handle)),
+        )
+        .is_some()
+}
+
+// The last open cell a laser passed through, lasers that end in a wall ignite the gas in front of it.
+fn get_laser_ignition_cell(
+    segment_start_position: Vec3,
+    hit_point: Vec3,
+    atmospherics_resource: &AtmosphericsResource,
+) -> Option<Vec2Int> {
+    let back = Vec3::new(
+        segment_start_position.x - hit_point.x,
+        0.,
+        segment_start_position.z - hit_point.z,
+    );
+    let distance = back.length();
+    let direction = back.normalize_or_zero();
+
+    let mut travelled = 0.;
+
+    loop {
+        let cell_id = world_to_cell_id(hit_point + direction * travelled);
+        let atmos_id = Vec2Int {
+            x: cell_id.x,
+            y: cell_id.z,
+        };
+
+        if !AtmosphericsResource::is_id_out_of_range(atmos_id)
+            && !atmospherics_resource
+                .atmospherics
+                .get(get_atmos_index(atmos_id))
+                .unwrap()
+                .blocked
+        {
+            return Some(atmos_id);
+        }
+
+        travelled += LASER_IGNITION_STEP;
+
+        if travelled > distance || direction == Vec3::ZERO {
+            return None;
+        }
+    }
+}
+
+pub fn weapon_recoil_recovery(time: Res<Time>, mut weapon_accuracies: Query<&mut WeaponAccuracy>) {
+    let delta_seconds = time.delta_seconds();
+
+    for mut weapon_accuracy_component in weapon_accuracies.iter_mut() {
+        if weapon_accuracy_component.recoil > 0. {
+            weapon_accuracy_component.recover(delta_seconds);
         }
     }
 }
+
+// Keep where every hittable entity was over the last ticks so attacks can be rewound.
+pub fn record_hitbox_history(
+    time: Res<Time>,
+    lag_compensation: Res<LagCompensation>,
+    mut hitbox_history: ResMut<HitboxHistory>,
+    hittables: Query<
+        (
+            Entity,
+            &RigidBodyPositionComponent,
+            &ColliderPositionComponent,
+        ),
+        (With<Health>, Without<RigidBodyDisabled>),
+    >,
+) {
+    let current_time_stamp = time.time_since_startup().as_millis() as u64;
+
+    let mut hitboxes = HashMap::new();
+
+    for (entity, rigid_body_position_component, collider_position_component) in hittables.iter() {
+        hitboxes.insert(
+            entity,
+            HitboxState {
+                rigid_body_position: rigid_body_position_component.position.translation.into(),
+                collider_position: collider_position_component.0 .0,
+            },
+        );
+    }
+
+    hitbox_history.snapshots.push_back(HitboxSnapshot {
+        time_stamp: current_time_stamp,
+        hitboxes,
+    });
+
+    // One snapshot past the cap stays around to interpolate from.
+    while hitbox_history.snapshots.len() > 2
+        && hitbox_history.snapshots[1].time_stamp + lag_compensation.max_rewind_millis
+            < current_time_stamp
+    {
+        hitbox_history.snapshots.pop_front();
+    }
+}
+
+// Stun and Floor flags only take effect on hits that were not blocked.
+fn send_status_effects(
+    hit_entity: Entity,
+    attack_event: &Attack,
+    inflict_status_effects: &mut EventWriter<InflictStatusEffects>,
+) {
+    let (stun, floor) = attack_event.damage_model.get_status_durations();
+
+    if stun > 0. || floor > 0. {
+        inflict_status_effects.send(InflictStatusEffects {
+            entity: hit_entity,
+            stun,
+            floor,
+        });
+    }
+}
@@ -0,0 +1,7 @@
+// Names of snapshots, saves and logs end up in file names, keep them to something harmless.
+pub fn is_valid_file_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
@@ -1,7 +1,8 @@
 use bevy_app::{App, Plugin};
 
-use self::resources::{ServerId, TickRate, MOTD};
+use self::resources::{LagCompensation, ServerId, TickRate, MOTD};
 
+pub mod functions;
 pub mod resources;
 
 pub struct ConfigurationPlugin;
@@ -10,6 +11,7 @@ impl Plugin for ConfigurationPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<TickRate>()
             .init_resource::<ServerId>()
-            .init_resource::<MOTD>();
+            .init_resource::<MOTD>()
+            .init_resource::<LagCompensation>();
     }
 }
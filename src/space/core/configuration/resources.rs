@@ -37,6 +37,19 @@ impl FromWorld for TickRate {
     }
 }
 
+// Attacks get resolved against the world as the attacker saw it, but never further back than this.
+pub struct LagCompensation {
+    pub max_rewind_millis: u64,
+}
+
+impl FromWorld for LagCompensation {
+    fn from_world(_world: &mut World) -> Self {
+        LagCompensation {
+            max_rewind_millis: 250,
+        }
+    }
+}
+
 // Used for client, we can send this ID as an entityUpdate to the client which indicates it does not belong
 // to a specific entity and it should be customly assigned to something such as UIs and other stuff which
 // are not real server entities but just client GUI instances.
@@ -36,3 +36,9 @@ pub struct SetupPhase;
 
 #[derive(Component)]
 pub struct OnBoard;
+
+// How far behind the server the world state is that the player is looking at, in milliseconds.
+#[derive(Component)]
+pub struct ViewLatency {
+    pub millis: u64,
+}
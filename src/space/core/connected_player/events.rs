@@ -122,6 +122,11 @@ pub struct InputSprinting {
     pub is_sprinting: bool,
 }
 
+pub struct InputBlock {
+    pub entity: Entity,
+    pub is_blocking: bool,
+}
+
 pub struct InputToggleAutoMove {
     pub entity: Entity,
 }
@@ -141,6 +146,11 @@ pub struct InputMouseDirectionUpdate {
     pub time_stamp: u64,
 }
 
+pub struct InputViewTimeStamp {
+    pub entity: Entity,
+    pub time_stamp: u64,
+}
+
 pub struct InputMovementInput {
     pub player_entity: Entity,
     pub vector: Vec2,
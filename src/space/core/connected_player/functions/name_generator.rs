@@ -1,4 +1,4 @@
-use bevy_ecs::system::{Res, ResMut};
+use bevy_ecs::system::Res;
 use rand::seq::SliceRandom;
 
 use crate::space::core::pawn::resources::UsedNames;
@@ -256,7 +256,7 @@ pub fn get_full_name(gender: bool, unique: bool, used_names: &Res<UsedNames>) ->
     full_name
 }
 
-pub fn get_dummy_name(used_names: &mut ResMut<UsedNames>) -> String {
+pub fn get_dummy_name(used_names: &mut UsedNames) -> String {
     let return_name = "Dummy ".to_string() + &used_names.dummy_i.to_string();
 
     used_names.dummy_i += 1;
@@ -6,12 +6,13 @@ use crate::space::UpdateLabels;
 
 use self::{
     events::{
-        BoardingPlayer, InputAltItemAttack, InputAttackCell, InputAttackEntity, InputBuildGraphics,
-        InputExamineEntity, InputExamineMap, InputMouseAction, InputMouseDirectionUpdate,
-        InputMovementInput, InputSceneReady, InputSelectBodyPart, InputSprinting,
-        InputTabDataEntity, InputTabDataMap, InputToggleAutoMove, InputToggleCombatMode,
-        InputUIInput, InputUIInputTransmitText, InputUserName, NetDoneBoarding, NetExamineEntity,
-        NetOnBoarding, NetOnNewPlayerConnection, NetOnSetupUI, NetOnSpawning, NetSendServerTime,
+        BoardingPlayer, InputAltItemAttack, InputAttackCell, InputAttackEntity, InputBlock,
+        InputBuildGraphics, InputExamineEntity, InputExamineMap, InputMouseAction,
+        InputMouseDirectionUpdate, InputMovementInput, InputSceneReady, InputSelectBodyPart,
+        InputSprinting, InputTabDataEntity, InputTabDataMap, InputToggleAutoMove,
+        InputToggleCombatMode, InputUIInput, InputUIInputTransmitText, InputUserName,
+        InputViewTimeStamp, NetDoneBoarding, NetExamineEntity, NetOnBoarding,
+        NetOnNewPlayerConnection, NetOnSetupUI, NetOnSpawning, NetSendServerTime,
         NetSendWorldEnvironment, NetTabData, NetUIInputTransmitData, NetUpdatePlayerCount,
         NetUserName, TextTreeInputSelection,
     },
@@ -24,7 +25,7 @@ use self::{
         scene_ready_event::scene_ready_event, send_server_time::send_server_time,
         text_tree_input_selection::text_tree_input_selection, ui_input_event::ui_input_event,
         ui_input_transmit_data_event::ui_input_transmit_data_event,
-        update_player_count::update_player_count,
+        update_player_count::update_player_count, view_time_stamp::view_time_stamp,
     },
 };
 
@@ -44,6 +45,7 @@ impl Plugin for ConnectedPlayerPlugin {
             .add_event::<InputExamineEntity>()
             .add_event::<InputExamineMap>()
             .add_event::<InputSprinting>()
+            .add_event::<InputBlock>()
             .add_event::<InputToggleCombatMode>()
             .add_event::<NetUserName>()
             .add_event::<InputTabDataEntity>()
@@ -91,6 +93,8 @@ impl Plugin for ConnectedPlayerPlugin {
             .add_event::<NetDoneBoarding>()
             .add_event::<NetOnSetupUI>()
             .add_event::<InputUIInput>()
-            .add_event::<InputSceneReady>();
+            .add_event::<InputSceneReady>()
+            .add_event::<InputViewTimeStamp>()
+            .add_system(view_time_stamp);
     }
 }
@@ -16,9 +16,10 @@ use crate::space::{
         },
         examinable::components::Examinable,
         gridmap::functions::examine_cell::EXAMINATION_EMPTY,
-        health::components::Health,
+        health::components::{Health, HealthContainer, BLOOD},
         humanoid::components::Humanoid,
         inventory::components::Inventory,
+        medical::{components::MedicalScanner, functions::get_scan_report},
         networking::resources::ReliableServerMessage,
         sensable::components::Sensable,
         senser::components::Senser,
@@ -34,6 +35,8 @@ pub fn examine_entity(
     q0: Query<(&Examinable, &Sensable, &Health)>,
     q1: Query<(&Examinable, &Sensable, &Health, &Inventory, &Humanoid)>,
     q2: Query<&Examinable>,
+    examiner_inventories: Query<&Inventory>,
+    medical_scanners: Query<&MedicalScanner>,
 ) {
     for examine_event in examine_entity_events.iter() {
         let entity_reference = Entity::from_bits(examine_event.examine_entity_bits);
@@ -54,13 +57,43 @@ pub fn examine_entity(
                 inventory_component,
                 standard_character_component,
             )) => {
-                let text = generate_human_examine_text(
+                let mut text = generate_human_examine_text(
                     &standard_character_component.character_name,
                     Some(inventory_component),
                     &q2,
                     health_component,
+                    standard_character_component.health_state,
                 );
 
+                // Examining with a medical scanner in the active hand adds the damage of every limb.
+                let mut holds_scanner = false;
+
+                match examiner_inventories.get(examine_event.entity) {
+                    Ok(examiner_inventory_component) => {
+                        match examiner_inventory_component.get_active_slot_entity() {
+                            Some(active_item_entity) => {
+                                holds_scanner = medical_scanners.get(active_item_entity).is_ok();
+                            }
+                            None => {}
+                        }
+                    }
+                    Err(_rr) => {}
+                }
+
+                if holds_scanner {
+                    match &health_component.health_container {
+                        HealthContainer::Humanoid(humanoid_health) => {
+                            text = text
+                                + "\n"
+                                + &get_scan_report(
+                                    humanoid_health,
+                                    health_component.raegent_container.get_amount(BLOOD),
+                                );
+                        }
+                        HealthContainer::Entity(_) => {}
+                    }
+                }
+
                 net_new_chat_message_event.send(NetExamineEntity {
                     handle: examine_event.handle,
                     message: ReliableServerMessage::ChatMessage(text),
@@ -17,7 +17,7 @@ use crate::space::{
         },
         entity::{
             events::NetShowcase,
-            resources::{EntityDataResource, SpawnPawnData},
+            resources::{EntityDataResource, SpawnContext, SpawnPawn},
         },
         networking::resources::{EntityUpdateData, EntityWorldType, ReliableServerMessage},
         pawn::{components::PersistentPlayerData, resources::UsedNames},
@@ -77,25 +77,19 @@ pub fn on_setupui(
         ];
 
         HumanMalePawnBundle::spawn(
-            Transform::identity(),
             &mut commands,
-            true,
-            Some(SpawnPawnData {
-                data: (
-                    persistent_player_data_component,
-                    Some(connected_player_component),
-                    passed_inventory_setup,
-                    true,
-                    false,
-                    None,
-                    Some(&mut net_showcase),
-                    None,
-                    &entity_data,
-                ),
-            }),
-            None,
-            false,
-            HashMap::new(),
+            SpawnContext::new(Transform::identity())
+                .correct_transform()
+                .showcase(connected_player_component.handle, &mut net_showcase)
+                .pawn(SpawnPawn {
+                    persistent_player_data: persistent_player_data_component,
+                    connected_player: Some(connected_player_component),
+                    inventory_setup: passed_inventory_setup,
+                    dummy: false,
+                    used_names: None,
+                    default_user_name: None,
+                    entity_data: &entity_data,
+                }),
         );
     }
 }
@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use bevy_app::EventWriter;
 use bevy_ecs::{
     entity::Entity,
@@ -14,7 +12,7 @@ use crate::space::{
             events::NetOnSpawning,
             resources::HandleToEntity,
         },
-        entity::resources::{EntityDataResource, SpawnPawnData},
+        entity::resources::{EntityDataResource, SpawnContext, SpawnPawn},
         networking::resources::{ReliableServerMessage, ServerConfigMessage},
         pawn::{components::PersistentPlayerData, resources::UsedNames},
     },
@@ -44,26 +42,20 @@ pub fn on_spawning(
         ];
 
         let new_entity = HumanMalePawnBundle::spawn(
-            spawning_component.transform,
             &mut commands,
-            true,
-            Some(SpawnPawnData {
-                data: (
-                    persistent_player_data_component,
-                    Some(connected_player_component),
-                    passed_inventory_setup,
-                    false,
-                    false,
-                    None,
-                    None,
-                    Some(persistent_player_data_component.user_name.clone()),
-                    &entity_data,
-                ),
-            }),
-            None,
-            false,
-            HashMap::new(),
-        );
+            SpawnContext::new(spawning_component.transform)
+                .correct_transform()
+                .pawn(SpawnPawn {
+                    persistent_player_data: persistent_player_data_component,
+                    connected_player: Some(connected_player_component),
+                    inventory_setup: passed_inventory_setup,
+                    dummy: false,
+                    used_names: None,
+                    default_user_name: Some(persistent_player_data_component.user_name.clone()),
+                    entity_data: &entity_data,
+                }),
+        )
+        .entity;
 
         let handle = *handle_to_entity.inv_map.get(&entity_id).unwrap();
 
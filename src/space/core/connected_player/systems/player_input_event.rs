@@ -3,13 +3,14 @@ use bevy_ecs::system::Query;
 use bevy_log::warn;
 
 use crate::space::core::{
-    connected_player::events::{InputMovementInput, InputSprinting},
+    connected_player::events::{InputBlock, InputMovementInput, InputSprinting},
     pawn::components::ControllerInput,
 };
 
 pub fn player_input_event(
     mut movement_input_event: EventReader<InputMovementInput>,
     mut sprinting_input_event: EventReader<InputSprinting>,
+    mut block_input_event: EventReader<InputBlock>,
     mut query: Query<&mut ControllerInput>,
 ) {
     for new_event in movement_input_event.iter() {
@@ -41,4 +42,15 @@ pub fn player_input_event(
             }
         }
     }
+
+    for new_event in block_input_event.iter() {
+        match query.get_mut(new_event.entity) {
+            Ok(mut player_input_component) => {
+                player_input_component.blocking = new_event.is_blocking;
+            }
+            Err(_rr) => {
+                warn!("Couldn't process player input (block_input_event): couldn't find player_entity.");
+            }
+        }
+    }
 }
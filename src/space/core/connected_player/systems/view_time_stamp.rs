@@ -0,0 +1,43 @@
+use bevy_app::EventReader;
+use bevy_core::Time;
+use bevy_ecs::{
+    prelude::With,
+    system::{Commands, Query, Res},
+};
+
+use crate::space::core::connected_player::{
+    components::{ConnectedPlayer, ViewLatency},
+    events::InputViewTimeStamp,
+};
+
+// Unreliable messages arrive with jitter, only let every sample move the estimate a bit.
+const LATENCY_SMOOTHING: u64 = 4;
+
+pub fn view_time_stamp(
+    mut view_time_stamp_events: EventReader<InputViewTimeStamp>,
+    mut view_latencies: Query<Option<&mut ViewLatency>, With<ConnectedPlayer>>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    let current_time_stamp = time.time_since_startup().as_millis() as u64;
+
+    for event in view_time_stamp_events.iter() {
+        let sample = current_time_stamp.saturating_sub(event.time_stamp);
+
+        match view_latencies.get_mut(event.entity) {
+            Ok(view_latency_component_option) => match view_latency_component_option {
+                Some(mut view_latency_component) => {
+                    view_latency_component.millis =
+                        (view_latency_component.millis * (LATENCY_SMOOTHING - 1) + sample)
+                            / LATENCY_SMOOTHING;
+                }
+                None => {
+                    commands
+                        .entity(event.entity)
+                        .insert(ViewLatency { millis: sample });
+                }
+            },
+            Err(_rr) => {}
+        }
+    }
+}
@@ -1,4 +1,11 @@
+pub mod rcon_atmos_fuel;
+pub mod rcon_atmos_snapshot;
 pub mod rcon_authorization;
+pub mod rcon_combat_log;
+pub mod rcon_combat_rules;
+pub mod rcon_lag_compensation;
+pub mod rcon_pipe_valve;
 pub mod rcon_spawn_entity;
 pub mod rcon_spawn_held_entity;
 pub mod rcon_status;
+pub mod rcon_world_save;
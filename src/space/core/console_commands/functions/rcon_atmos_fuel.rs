@@ -0,0 +1,63 @@
+use bevy_app::EventWriter;
+use bevy_ecs::system::ResMut;
+
+use crate::space::core::{
+    atmospherics::{functions::get_atmos_index, resources::AtmosphericsResource},
+    console_commands::events::NetConsoleCommands,
+    gridmap::resources::Vec2Int,
+    networking::resources::ReliableServerMessage,
+    pawn::functions::{CONSOLE_ERROR_COLOR, CONSOLE_SUCCESS_COLOR},
+};
+
+pub fn rcon_atmos_fuel(
+    cell_id: Vec2Int,
+    amount: f32,
+    command_executor_handle: u32,
+    net_console_commands: &mut EventWriter<NetConsoleCommands>,
+    atmospherics_resource: &mut ResMut<AtmosphericsResource>,
+) {
+    if AtmosphericsResource::is_id_out_of_range(cell_id) {
+        net_console_commands.send(NetConsoleCommands {
+            handle: command_executor_handle,
+            message: ReliableServerMessage::ConsoleWriteLine(
+                "[color=".to_string() + CONSOLE_ERROR_COLOR + "]Cell is out of range.[/color]",
+            ),
+        });
+        return;
+    }
+
+    let atmospherics = atmospherics_resource
+        .atmospherics
+        .get_mut(get_atmos_index(cell_id))
+        .unwrap();
+
+    if atmospherics.blocked {
+        net_console_commands.send(NetConsoleCommands {
+            handle: command_executor_handle,
+            message: ReliableServerMessage::ConsoleWriteLine(
+                "[color=".to_string() + CONSOLE_ERROR_COLOR + "]Cell is blocked.[/color]",
+            ),
+        });
+        return;
+    }
+
+    let added_amount = amount.max(0.);
+
+    atmospherics.fuel += added_amount;
+    atmospherics.amount += added_amount;
+
+    net_console_commands.send(NetConsoleCommands {
+        handle: command_executor_handle,
+        message: ReliableServerMessage::ConsoleWriteLine(
+            "[color=".to_string()
+                + CONSOLE_SUCCESS_COLOR
+                + "]Added "
+                + &added_amount.to_string()
+                + " mol of fuel to ("
+                + &cell_id.x.to_string()
+                + ","
+                + &cell_id.y.to_string()
+                + ").[/color]",
+        ),
+    });
+}
@@ -0,0 +1,80 @@
+use bevy_app::EventWriter;
+use bevy_ecs::system::ResMut;
+
+use crate::space::core::{
+    atmospherics::{
+        functions::snapshot::{export_atmospherics_snapshot, load_atmospherics_snapshot},
+        resources::AtmosphericsResource,
+    },
+    console_commands::events::NetConsoleCommands,
+    networking::resources::ReliableServerMessage,
+    pawn::functions::{CONSOLE_ERROR_COLOR, CONSOLE_SUCCESS_COLOR},
+};
+
+pub fn rcon_atmos_snapshot(
+    name: String,
+    command_executor_handle: u32,
+    net_console_commands: &mut EventWriter<NetConsoleCommands>,
+    atmospherics_resource: &ResMut<AtmosphericsResource>,
+) {
+    match export_atmospherics_snapshot(&name, atmospherics_resource) {
+        Ok(paths) => {
+            let mut message =
+                "[color=".to_string() + CONSOLE_SUCCESS_COLOR + "]Exported atmospherics snapshot:";
+            for path in paths {
+                message = message + "\n" + &path.to_string_lossy();
+            }
+            message = message + "[/color]";
+            net_console_commands.send(NetConsoleCommands {
+                handle: command_executor_handle,
+                message: ReliableServerMessage::ConsoleWriteLine(message),
+            });
+        }
+        Err(rr) => {
+            net_console_commands.send(NetConsoleCommands {
+                handle: command_executor_handle,
+                message: ReliableServerMessage::ConsoleWriteLine(
+                    "[color=".to_string()
+                        + CONSOLE_ERROR_COLOR
+                        + "]Failed to export atmospherics snapshot: "
+                        + &rr
+                        + "[/color]",
+                ),
+            });
+        }
+    }
+}
+
+pub fn rcon_atmos_snapshot_load(
+    name: String,
+    command_executor_handle: u32,
+    net_console_commands: &mut EventWriter<NetConsoleCommands>,
+    atmospherics_resource: &mut ResMut<AtmosphericsResource>,
+) {
+    match load_atmospherics_snapshot(&name, atmospherics_resource) {
+        Ok(loaded_cells) => {
+            net_console_commands.send(NetConsoleCommands {
+                handle: command_executor_handle,
+                message: ReliableServerMessage::ConsoleWriteLine(
+                    "[color=".to_string()
+                        + CONSOLE_SUCCESS_COLOR
+                        + "]Loaded "
+                        + &loaded_cells.to_string()
+                        + " atmospherics cells.[/color]",
+                ),
+            });
+        }
+        Err(rr) => {
+            net_console_commands.send(NetConsoleCommands {
+                handle: command_executor_handle,
+                message: ReliableServerMessage::ConsoleWriteLine(
+                    "[color=".to_string()
+                        + CONSOLE_ERROR_COLOR
+                        + "]Failed to load atmospherics snapshot: "
+                        + &rr
+                        + "[/color]",
+                ),
+            });
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use bevy_app::EventWriter;
+use bevy_ecs::{entity::Entity, system::ResMut};
+
+use crate::space::core::{
+    combat::resources::{CombatLog, CombatLogRecord},
+    connected_player::functions::player_selector_to_entities::player_selector_to_entities,
+    console_commands::events::NetConsoleCommands,
+    networking::resources::ReliableServerMessage,
+    pawn::{functions::CONSOLE_SUCCESS_COLOR, resources::UsedNames},
+};
+
+// Keep the console readable, older records can still be found in the log file.
+const MAX_LISTED_RECORDS: usize = 100;
+
+// Minutes of zero or less list everything the combat log still holds.
+pub fn rcon_combat_log(
+    target_selector: String,
+    minutes: i64,
+    command_executor_entity: Entity,
+    command_executor_handle: u32,
+    net_console_commands: &mut EventWriter<NetConsoleCommands>,
+    used_names: &mut ResMut<UsedNames>,
+    combat_log: &CombatLog,
+    current_time: f64,
+) {
+    let target_entities = player_selector_to_entities(
+        command_executor_entity,
+        command_executor_handle,
+        &target_selector,
+        used_names,
+        net_console_commands,
+    );
+
+    let records: Vec<&CombatLogRecord> = combat_log
+        .records
+        .iter()
+        .filter(|record| minutes <= 0 || current_time - record.time <= minutes as f64 * 60.)
+        .filter(|record| {
+            target_entities
+                .iter()
+                .any(|entity| record.involves(*entity))
+        })
+        .collect();
+
+    let mut message = "[color=".to_string()
+        + CONSOLE_SUCCESS_COLOR
+        + "]"
+        + &records.len().to_string()
+        + " combat log records found";
+
+    if records.len() > MAX_LISTED_RECORDS {
+        message = message + ", showing the last " + &MAX_LISTED_RECORDS.to_string();
+    }
+
+    message = message + ".[/color]";
+
+    for record in records
+        .iter()
+        .skip(records.len().saturating_sub(MAX_LISTED_RECORDS))
+    {
+        message = message
+            + "\n["
+            + &format!("{:.1}", (current_time - record.time) / 60.)
+            + " min ago] "
+            + &record.attacker_name
+            + " -> "
+            + record.get_target_name()
+            + " ("
+            + &record.limb
+            + ") with "
+            + &record.weapon_name
+            + ": "
+            + record.get_hit_result_name()
+            + ", "
+            + &format!("{:.1}", record.damage_model.brute)
+            + " brute, "
+            + &format!("{:.1}", record.damage_model.burn)
+            + " burn, "
+            + &format!("{:.1}", record.damage_model.toxin)
+            + " toxin at "
+            + &format!("{:.1}", record.position.x)
+            + ", "
+            + &format!("{:.1}", record.position.z);
+    }
+
+    net_console_commands.send(NetConsoleCommands {
+        handle: command_executor_handle,
+        message: ReliableServerMessage::ConsoleWriteLine(message),
+    });
+}
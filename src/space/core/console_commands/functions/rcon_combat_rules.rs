@@ -0,0 +1,57 @@
+use bevy_app::EventWriter;
+use bevy_ecs::system::ResMut;
+
+use crate::space::core::{
+    combat::resources::CombatRules,
+    console_commands::events::NetConsoleCommands,
+    networking::resources::ReliableServerMessage,
+    pawn::functions::{CONSOLE_ERROR_COLOR, CONSOLE_SUCCESS_COLOR},
+};
+
+// Overrides last until the server restarts, safe zones come from the map.
+pub fn rcon_combat_rules(
+    rule: String,
+    enabled: bool,
+    command_executor_handle: u32,
+    net_console_commands: &mut EventWriter<NetConsoleCommands>,
+    combat_rules: &mut ResMut<CombatRules>,
+) {
+    if rule == "pvp" {
+        combat_rules.pvp = enabled;
+    } else if rule == "department_damage" {
+        combat_rules.department_damage = enabled;
+    } else {
+        net_console_commands.send(NetConsoleCommands {
+            handle: command_executor_handle,
+            message: ReliableServerMessage::ConsoleWriteLine(
+                "[color=".to_string()
+                    + CONSOLE_ERROR_COLOR
+                    + "]Unknown combat rule \""
+                    + &rule
+                    + "\", use pvp or department_damage.[/color]",
+            ),
+        });
+        return;
+    }
+
+    let state;
+
+    if enabled {
+        state = "enabled";
+    } else {
+        state = "disabled";
+    }
+
+    net_console_commands.send(NetConsoleCommands {
+        handle: command_executor_handle,
+        message: ReliableServerMessage::ConsoleWriteLine(
+            "[color=".to_string()
+                + CONSOLE_SUCCESS_COLOR
+                + "]Combat rule "
+                + &rule
+                + " is now "
+                + state
+                + ".[/color]",
+        ),
+    });
+}
@@ -0,0 +1,41 @@
+use bevy_app::EventWriter;
+use bevy_ecs::system::ResMut;
+
+use crate::space::core::{
+    configuration::resources::LagCompensation,
+    console_commands::events::NetConsoleCommands,
+    networking::resources::ReliableServerMessage,
+    pawn::functions::{CONSOLE_ERROR_COLOR, CONSOLE_SUCCESS_COLOR},
+};
+
+pub fn rcon_lag_compensation(
+    max_rewind_millis: i64,
+    command_executor_handle: u32,
+    net_console_commands: &mut EventWriter<NetConsoleCommands>,
+    lag_compensation: &mut ResMut<LagCompensation>,
+) {
+    if max_rewind_millis < 0 {
+        net_console_commands.send(NetConsoleCommands {
+            handle: command_executor_handle,
+            message: ReliableServerMessage::ConsoleWriteLine(
+                "[color=".to_string()
+                    + CONSOLE_ERROR_COLOR
+                    + "]The rewind window cannot be negative.[/color]",
+            ),
+        });
+        return;
+    }
+
+    lag_compensation.max_rewind_millis = max_rewind_millis as u64;
+
+    net_console_commands.send(NetConsoleCommands {
+        handle: command_executor_handle,
+        message: ReliableServerMessage::ConsoleWriteLine(
+            "[color=".to_string()
+                + CONSOLE_SUCCESS_COLOR
+                + "]Attacks now get rewound up to "
+                + &max_rewind_millis.to_string()
+                + " ms.[/color]",
+        ),
+    });
+}
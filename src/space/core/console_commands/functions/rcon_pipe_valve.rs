@@ -0,0 +1,50 @@
+use bevy_app::EventWriter;
+use bevy_ecs::system::ResMut;
+
+use crate::space::core::{
+    console_commands::events::NetConsoleCommands,
+    gridmap::resources::Vec3Int,
+    networking::resources::ReliableServerMessage,
+    pawn::functions::{CONSOLE_ERROR_COLOR, CONSOLE_SUCCESS_COLOR},
+    pipe_networks::resources::PipeNetworksResource,
+};
+
+pub fn rcon_pipe_valve(
+    cell_id: Vec3Int,
+    open: bool,
+    command_executor_handle: u32,
+    net_console_commands: &mut EventWriter<NetConsoleCommands>,
+    pipe_networks: &mut ResMut<PipeNetworksResource>,
+) {
+    if pipe_networks.set_valve(&cell_id, open) {
+        let state;
+        if open {
+            state = "opened";
+        } else {
+            state = "closed";
+        }
+        net_console_commands.send(NetConsoleCommands {
+            handle: command_executor_handle,
+            message: ReliableServerMessage::ConsoleWriteLine(
+                "[color=".to_string()
+                    + CONSOLE_SUCCESS_COLOR
+                    + "]Valve at ("
+                    + &cell_id.x.to_string()
+                    + ","
+                    + &cell_id.y.to_string()
+                    + ","
+                    + &cell_id.z.to_string()
+                    + ") "
+                    + state
+                    + ".[/color]",
+            ),
+        });
+    } else {
+        net_console_commands.send(NetConsoleCommands {
+            handle: command_executor_handle,
+            message: ReliableServerMessage::ConsoleWriteLine(
+                "[color=".to_string() + CONSOLE_ERROR_COLOR + "]There is no valve there.[/color]",
+            ),
+        });
+    }
+}
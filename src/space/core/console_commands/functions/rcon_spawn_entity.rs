@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use bevy_app::EventWriter;
 use bevy_ecs::{
     entity::Entity,
@@ -16,7 +14,7 @@ use crate::space::core::{
     console_commands::events::NetConsoleCommands,
     entity::{
         functions::{isometry_to_transform::isometry_to_transform, spawn_entity::spawn_entity},
-        resources::EntityDataResource,
+        resources::{EntityDataResource, SpawnContext, SpawnPawn},
     },
     gridmap::resources::GridmapMain,
     networking::resources::ReliableServerMessage,
@@ -120,17 +118,19 @@ pub fn rcon_spawn_entity(
         for _i in 0..spawn_amount {
             final_result = spawn_entity(
                 entity_name.clone(),
-                individual_transform,
                 commands,
-                true,
-                Some(used_names),
                 entity_data,
-                None,
-                Some((
-                    passed_inventory_setup.clone(),
-                    persistent_player_data_component.clone(),
-                )),
-                HashMap::new(),
+                SpawnContext::new(individual_transform)
+                    .correct_transform()
+                    .pawn(SpawnPawn {
+                        persistent_player_data: &persistent_player_data_component,
+                        connected_player: None,
+                        inventory_setup: passed_inventory_setup.clone(),
+                        dummy: true,
+                        used_names: Some(&mut **used_names),
+                        default_user_name: None,
+                        entity_data,
+                    }),
             );
             individual_transform.translation.x += 0.5;
             individual_transform = entity_spawn_position_for_player(
@@ -92,18 +92,17 @@ pub fn rcon_spawn_held_entity(
 
         match available_slot {
             Some(slot) => {
-                let entity_option = spawn_held_entity(
+                let spawned_option = spawn_held_entity(
                     entity_name.clone(),
                     commands,
                     command_executor_entity,
-                    false,
                     None,
-                    &mut None,
                     &entity_data,
                 );
 
-                match entity_option {
-                    Some(entity) => {
+                match spawned_option {
+                    Some(spawned) => {
+                        let entity = spawned.entity;
                         slot.slot_item = Some(entity);
 
                         net_console_commands.send(NetConsoleCommands {
@@ -0,0 +1,26 @@
+use bevy_app::EventWriter;
+
+use crate::space::core::world_save::events::{LoadWorld, SaveWorld};
+
+// The outcome gets reported back to the executor once the save has been written.
+pub fn rcon_save_world(
+    name: String,
+    command_executor_handle: u32,
+    save_world: &mut EventWriter<SaveWorld>,
+) {
+    save_world.send(SaveWorld {
+        name,
+        handle_option: Some(command_executor_handle),
+    });
+}
+
+pub fn rcon_load_world(
+    name: String,
+    command_executor_handle: u32,
+    load_world: &mut EventWriter<LoadWorld>,
+) {
+    load_world.send(LoadWorld {
+        name,
+        handle_option: Some(command_executor_handle),
+    });
+}
@@ -1,22 +1,35 @@
 use bevy_app::{EventReader, EventWriter};
+use bevy_core::Time;
 use bevy_ecs::system::{Commands, Local, Query, Res, ResMut};
 use bevy_rapier3d::prelude::RigidBodyPositionComponent;
 
 use crate::space::core::{
+    atmospherics::resources::AtmosphericsResource,
+    combat::resources::{CombatLog, CombatRules},
+    configuration::resources::LagCompensation,
     connected_player::{components::ConnectedPlayer, resources::HandleToEntity},
     console_commands::functions::{
+        rcon_atmos_fuel::rcon_atmos_fuel,
+        rcon_atmos_snapshot::{rcon_atmos_snapshot, rcon_atmos_snapshot_load},
         rcon_authorization::{rcon_authorization, BruteforceProtection},
+        rcon_combat_log::rcon_combat_log,
+        rcon_combat_rules::rcon_combat_rules,
+        rcon_lag_compensation::rcon_lag_compensation,
+        rcon_pipe_valve::rcon_pipe_valve,
         rcon_spawn_entity::rcon_spawn_entity,
         rcon_spawn_held_entity::rcon_spawn_held_entity,
         rcon_status::rcon_status,
+        rcon_world_save::{rcon_load_world, rcon_save_world},
     },
     entity::resources::EntityDataResource,
-    gridmap::resources::GridmapMain,
+    gridmap::resources::{GridmapMain, Vec2Int, Vec3Int},
     inventory::components::Inventory,
     networking::resources::{
         ConsoleCommandVariant, ConsoleCommandVariantValues, ReliableServerMessage,
     },
     pawn::{components::Pawn, resources::UsedNames},
+    pipe_networks::resources::PipeNetworksResource,
+    world_save::events::{LoadWorld, SaveWorld},
 };
 
 use super::events::{InputConsoleCommand, NetConsoleCommands};
@@ -29,13 +42,25 @@ pub fn console_commands(
     mut inventory_components: Query<&mut Inventory>,
 
     mut net_console_commands: EventWriter<NetConsoleCommands>,
+    mut save_world: EventWriter<SaveWorld>,
+    mut load_world: EventWriter<LoadWorld>,
     mut commands: Commands,
 
     gridmap_main: Res<GridmapMain>,
     mut used_names: ResMut<UsedNames>,
     handle_to_entity: Res<HandleToEntity>,
     mut entity_data: ResMut<EntityDataResource>,
+    mut pipe_networks: ResMut<PipeNetworksResource>,
+    mut atmospherics_resource: ResMut<AtmosphericsResource>,
+    combat_resources: (
+        ResMut<LagCompensation>,
+        Res<CombatLog>,
+        Res<Time>,
+        ResMut<CombatRules>,
+    ),
 ) {
+    let (mut lag_compensation, combat_log, time, mut combat_rules) = combat_resources;
+
     for console_command_event in console_commands_events.iter() {
         if console_command_event.command_name == "rcon" {
             match &console_command_event.command_arguments[0] {
@@ -167,6 +192,207 @@ pub fn console_commands(
                 &handle_to_entity,
                 &mut entity_data,
             );
+        } else if console_command_event.command_name == "pipe_valve" {
+            let mut cell_id = Vec3Int { x: 0, y: 0, z: 0 };
+
+            for (i, argument) in console_command_event.command_arguments[0..3]
+                .iter()
+                .enumerate()
+            {
+                match argument {
+                    ConsoleCommandVariantValues::Int(value) => {
+                        if i == 0 {
+                            cell_id.x = *value as i16;
+                        } else if i == 1 {
+                            cell_id.y = *value as i16;
+                        } else {
+                            cell_id.z = *value as i16;
+                        }
+                    }
+                    _ => {
+                        return;
+                    }
+                }
+            }
+
+            let open;
+
+            match &console_command_event.command_arguments[3] {
+                ConsoleCommandVariantValues::Bool(value) => {
+                    open = *value;
+                }
+                _ => {
+                    return;
+                }
+            }
+
+            rcon_pipe_valve(
+                cell_id,
+                open,
+                console_command_event.handle,
+                &mut net_console_commands,
+                &mut pipe_networks,
+            );
+        } else if console_command_event.command_name == "atmos_fuel" {
+            let mut cell_id = Vec2Int { x: 0, y: 0 };
+
+            for (i, argument) in console_command_event.command_arguments[0..2]
+                .iter()
+                .enumerate()
+            {
+                match argument {
+                    ConsoleCommandVariantValues::Int(value) => {
+                        if i == 0 {
+                            cell_id.x = *value as i16;
+                        } else {
+                            cell_id.y = *value as i16;
+                        }
+                    }
+                    _ => {
+                        return;
+                    }
+                }
+            }
+
+            let amount;
+
+            match &console_command_event.command_arguments[2] {
+                ConsoleCommandVariantValues::Float(value) => {
+                    amount = *value;
+                }
+                _ => {
+                    return;
+                }
+            }
+
+            rcon_atmos_fuel(
+                cell_id,
+                amount,
+                console_command_event.handle,
+                &mut net_console_commands,
+                &mut atmospherics_resource,
+            );
+        } else if console_command_event.command_name == "atmos_snapshot" {
+            match &console_command_event.command_arguments[0] {
+                ConsoleCommandVariantValues::String(value) => {
+                    rcon_atmos_snapshot(
+                        value.to_string(),
+                        console_command_event.handle,
+                        &mut net_console_commands,
+                        &atmospherics_resource,
+                    );
+                }
+                _ => (),
+            }
+        } else if console_command_event.command_name == "atmos_snapshot_load" {
+            match &console_command_event.command_arguments[0] {
+                ConsoleCommandVariantValues::String(value) => {
+                    rcon_atmos_snapshot_load(
+                        value.to_string(),
+                        console_command_event.handle,
+                        &mut net_console_commands,
+                        &mut atmospherics_resource,
+                    );
+                }
+                _ => (),
+            }
+        } else if console_command_event.command_name == "save_world" {
+            match &console_command_event.command_arguments[0] {
+                ConsoleCommandVariantValues::String(value) => {
+                    rcon_save_world(
+                        value.to_string(),
+                        console_command_event.handle,
+                        &mut save_world,
+                    );
+                }
+                _ => (),
+            }
+        } else if console_command_event.command_name == "load_world" {
+            match &console_command_event.command_arguments[0] {
+                ConsoleCommandVariantValues::String(value) => {
+                    rcon_load_world(
+                        value.to_string(),
+                        console_command_event.handle,
+                        &mut load_world,
+                    );
+                }
+                _ => (),
+            }
+        } else if console_command_event.command_name == "lag_compensation" {
+            match &console_command_event.command_arguments[0] {
+                ConsoleCommandVariantValues::Int(value) => {
+                    rcon_lag_compensation(
+                        *value,
+                        console_command_event.handle,
+                        &mut net_console_commands,
+                        &mut lag_compensation,
+                    );
+                }
+                _ => (),
+            }
+        } else if console_command_event.command_name == "combatlog" {
+            let player_selector;
+
+            match &console_command_event.command_arguments[0] {
+                ConsoleCommandVariantValues::String(value) => {
+                    player_selector = value;
+                }
+                _ => {
+                    return;
+                }
+            }
+
+            let minutes;
+
+            match console_command_event.command_arguments.get(1) {
+                Some(ConsoleCommandVariantValues::Int(value)) => {
+                    minutes = *value;
+                }
+                _ => {
+                    minutes = 0;
+                }
+            }
+
+            rcon_combat_log(
+                player_selector.to_string(),
+                minutes,
+                console_command_event.entity,
+                console_command_event.handle,
+                &mut net_console_commands,
+                &mut used_names,
+                &combat_log,
+                time.seconds_since_startup(),
+            );
+        } else if console_command_event.command_name == "combat_rules" {
+            let rule;
+
+            match &console_command_event.command_arguments[0] {
+                ConsoleCommandVariantValues::String(value) => {
+                    rule = value;
+                }
+                _ => {
+                    return;
+                }
+            }
+
+            let enabled;
+
+            match &console_command_event.command_arguments[1] {
+                ConsoleCommandVariantValues::Bool(value) => {
+                    enabled = *value;
+                }
+                _ => {
+                    return;
+                }
+            }
+
+            rcon_combat_rules(
+                rule.to_string(),
+                enabled,
+                console_command_event.handle,
+                &mut net_console_commands,
+                &mut combat_rules,
+            );
         }
     }
 }
@@ -219,6 +445,124 @@ pub fn get_console_commands() -> Vec<(String, String, Vec<(String, ConsoleComman
                     ConsoleCommandVariant::String
                 ),
             ]
+        ),
+        (
+            "pipe_valve".to_string(),
+            "For server administrators only. Open or close the pipe valve at the given cell.".to_string(),
+            vec![
+                (
+                    "x".to_string(),
+                    ConsoleCommandVariant::Int
+                ),
+                (
+                    "y".to_string(),
+                    ConsoleCommandVariant::Int
+                ),
+                (
+                    "z".to_string(),
+                    ConsoleCommandVariant::Int
+                ),
+                (
+                    "open".to_string(),
+                    ConsoleCommandVariant::Bool
+                ),
+            ]
+        ),
+        (
+            "atmos_fuel".to_string(),
+            "For server administrators only. Release an amount of flammable gas into the given cell.".to_string(),
+            vec![
+                (
+                    "x".to_string(),
+                    ConsoleCommandVariant::Int
+                ),
+                (
+                    "z".to_string(),
+                    ConsoleCommandVariant::Int
+                ),
+                (
+                    "amount".to_string(),
+                    ConsoleCommandVariant::Float
+                ),
+            ]
+        ),
+        (
+            "atmos_snapshot".to_string(),
+            "For server administrators only. Export the atmospherics of the ship to a CSV file and PNG heatmaps.".to_string(),
+            vec![
+                (
+                    "name".to_string(),
+                    ConsoleCommandVariant::String
+                ),
+            ]
+        ),
+        (
+            "atmos_snapshot_load".to_string(),
+            "For server administrators only. Restore the atmospherics of the ship from an exported CSV file.".to_string(),
+            vec![
+                (
+                    "name".to_string(),
+                    ConsoleCommandVariant::String
+                ),
+            ]
+        ),
+        (
+            "save_world".to_string(),
+            "For server administrators only. Save the entities and atmospherics of the world under the given name.".to_string(),
+            vec![
+                (
+                    "name".to_string(),
+                    ConsoleCommandVariant::String
+                ),
+            ]
+        ),
+        (
+            "load_world".to_string(),
+            "For server administrators only. Replace the world with a save, connected players keep their pawns.".to_string(),
+            vec![
+                (
+                    "name".to_string(),
+                    ConsoleCommandVariant::String
+                ),
+            ]
+        ),
+        (
+            "lag_compensation".to_string(),
+            "For server administrators only. Set how many milliseconds attacks may be rewound to compensate for the latency of the attacker.".to_string(),
+            vec![
+                (
+                    "max_rewind_millis".to_string(),
+                    ConsoleCommandVariant::Int
+                ),
+            ]
+        ),
+        (
+            "combatlog".to_string(),
+            "For server administrators only. List the attacks the selected players were involved in during the last minutes, 0 minutes lists all of them.".to_string(),
+            vec![
+                (
+                    "player_selector".to_string(),
+                    ConsoleCommandVariant::String
+                ),
+                (
+                    "minutes".to_string(),
+                    ConsoleCommandVariant::Int
+                ),
+            ]
+        ),
+        (
+            "combat_rules".to_string(),
+            "For server administrators only. Enable or disable the pvp or department_damage combat rule until the server restarts.".to_string(),
+            vec![
+                (
+                    "rule".to_string(),
+                    ConsoleCommandVariant::String
+                ),
+                (
+                    "enabled".to_string(),
+                    ConsoleCommandVariant::Bool
+                ),
+            ]
         )
     ]
 }
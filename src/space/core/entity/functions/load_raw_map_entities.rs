@@ -6,7 +6,7 @@ use crate::space::{
     core::{
         entity::{
             functions::string_to_type_converters::string_transform_to_transform,
-            resources::EntityDataResource,
+            resources::{EntityDataResource, SpawnContext},
         },
         networking::resources::ConsoleCommandVariantValues,
     },
@@ -79,15 +79,12 @@ pub fn load_raw_map_entities(
             match entity_data.name_to_id.get(&raw_entity.entity_type) {
                 Some(entity_type_id) => {
                     let entity_properties = entity_data.data.get(*entity_type_id).unwrap();
-                    Some((*entity_properties.spawn_function)(
-                        entity_transform,
+                    (*entity_properties.spawn_function)(
                         commands,
-                        false,
-                        None,
-                        None,
-                        true,
-                        data,
-                    ));
+                        SpawnContext::new(entity_transform)
+                            .default_map_spawn()
+                            .properties(data),
+                    );
                 }
                 None => {}
             }
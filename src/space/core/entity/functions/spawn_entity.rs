@@ -1,40 +1,20 @@
-use std::collections::HashMap;
-
-use bevy_app::EventWriter;
-use bevy_ecs::{
-    entity::Entity,
-    system::{Commands, ResMut},
-};
+use bevy_ecs::{entity::Entity, system::Commands};
 use bevy_transform::components::Transform;
 
 use crate::space::core::{
-    entity::{
-        events::NetShowcase,
-        resources::{EntityDataResource, SpawnHeldData, SpawnPawnData},
-    },
+    entity::resources::{EntityDataResource, SpawnContext, SpawnShowcase, SpawnedEntity},
     networking::resources::ConsoleCommandVariantValues,
-    pawn::{components::PersistentPlayerData, resources::UsedNames},
 };
 
 pub fn spawn_entity(
     entity_name: String,
-    transform: Transform,
     commands: &mut Commands,
-    correct_transform: bool,
-    used_names_option: Option<&mut ResMut<UsedNames>>,
-    entity_data: &ResMut<EntityDataResource>,
-    held_data_option: Option<(
-        Entity,
-        bool,
-        Option<u32>,
-        &mut Option<&mut EventWriter<NetShowcase>>,
-    )>,
-    pawn_data_option: Option<(Vec<(String, String)>, PersistentPlayerData)>,
-    mut properties: HashMap<String, ConsoleCommandVariantValues>,
-) -> Option<Entity> {
+    entity_data: &EntityDataResource,
+    mut spawn_context: SpawnContext,
+) -> Option<SpawnedEntity> {
     let return_entity;
 
-    properties.insert(
+    spawn_context.properties.insert(
         "entity_name".to_string(),
         ConsoleCommandVariantValues::String(entity_name.clone()),
     );
@@ -43,54 +23,7 @@ pub fn spawn_entity(
         Some(entity_type_id) => {
             let entity_properties = entity_data.data.get(*entity_type_id).unwrap();
 
-            let held;
-
-            match held_data_option {
-                Some(data) => {
-                    held = Some(SpawnHeldData { data });
-                }
-                None => {
-                    held = None;
-                }
-            }
-
-            match pawn_data_option {
-                Some(data) => {
-                    let pawn = Some(SpawnPawnData {
-                        data: (
-                            &data.1,
-                            None,
-                            data.0,
-                            false,
-                            true,
-                            Some(used_names_option.unwrap()),
-                            None,
-                            None,
-                            &entity_data,
-                        ),
-                    });
-                    return_entity = Some((*entity_properties.spawn_function)(
-                        transform,
-                        commands,
-                        correct_transform,
-                        pawn,
-                        held,
-                        false,
-                        properties,
-                    ));
-                }
-                None => {
-                    return_entity = Some((*entity_properties.spawn_function)(
-                        transform,
-                        commands,
-                        correct_transform,
-                        None,
-                        held,
-                        false,
-                        properties,
-                    ));
-                }
-            }
+            return_entity = Some((*entity_properties.spawn_function)(commands, spawn_context));
         }
         None => {
             return_entity = None;
@@ -104,44 +37,17 @@ pub fn spawn_held_entity(
     entity_name: String,
     commands: &mut Commands,
     holder_entity: Entity,
-    showcase_instance: bool,
-    showcase_handle_option: Option<u32>,
-    net_showcase: &mut Option<&mut EventWriter<NetShowcase>>,
-    entity_data: &ResMut<EntityDataResource>,
-) -> Option<Entity> {
-    let return_entity;
-
-    match entity_data.name_to_id.get(&entity_name) {
-        Some(entity_type_id) => {
-            let entity_properties = entity_data.data.get(*entity_type_id).unwrap();
-
-            let mut map = HashMap::new();
-            map.insert(
-                "entity_name".to_string(),
-                ConsoleCommandVariantValues::String(entity_name),
-            );
-
-            return_entity = Some((*entity_properties.spawn_function)(
-                Transform::identity(),
-                commands,
-                false,
-                None,
-                Some(SpawnHeldData {
-                    data: (
-                        holder_entity,
-                        showcase_instance,
-                        showcase_handle_option,
-                        net_showcase,
-                    ),
-                }),
-                false,
-                map,
-            ));
-        }
-        None => {
-            return_entity = None;
+    showcase_option: Option<SpawnShowcase>,
+    entity_data: &EntityDataResource,
+) -> Option<SpawnedEntity> {
+    let mut spawn_context = SpawnContext::new(Transform::identity()).held_by(holder_entity);
+
+    match showcase_option {
+        Some(showcase) => {
+            spawn_context = spawn_context.showcase(showcase.handle, showcase.net_showcase);
         }
+        None => {}
     }
 
-    return_entity
+    spawn_entity(entity_name, commands, entity_data, spawn_context)
 }
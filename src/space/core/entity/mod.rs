@@ -15,9 +15,7 @@ use crate::space::{
     entities::{
         air_locks::spawn::AirlockBundle, computers::spawn::ComputerBundle,
         construction_tool_admin::spawn::ConstructionToolBundle,
-        counter_windows::spawn::CounterWindowBundle, helmet_security::spawn::HelmetSecurityBundle,
-        human_male_pawn::spawn::HumanMalePawnBundle,
-        jumpsuit_security::spawn::JumpsuitSecurityBundle, pistol_l1::spawn::PistolL1Bundle,
+        counter_windows::spawn::CounterWindowBundle, human_male_pawn::spawn::HumanMalePawnBundle,
     },
     PostUpdateLabels, StartupLabels,
 };
@@ -40,27 +38,6 @@ pub mod systems;
 pub fn startup_entities(mut entity_data: ResMut<EntityDataResource>) {
     let mut entities = vec![];
 
-    entities.push(EntityDataProperties {
-        name: "jumpsuitSecurity".to_string(),
-        id: entity_data.get_id_inc(),
-        spawn_function: Box::new(JumpsuitSecurityBundle::spawn),
-        ..Default::default()
-    });
-
-    entities.push(EntityDataProperties {
-        name: "helmetSecurity".to_string(),
-        id: entity_data.get_id_inc(),
-        spawn_function: Box::new(HelmetSecurityBundle::spawn),
-        ..Default::default()
-    });
-
-    entities.push(EntityDataProperties {
-        name: "pistolL1".to_string(),
-        id: entity_data.get_id_inc(),
-        spawn_function: Box::new(PistolL1Bundle::spawn),
-        ..Default::default()
-    });
-
     entities.push(EntityDataProperties {
         name: "humanDummy".to_string(),
         id: entity_data.get_id_inc(),
@@ -192,6 +169,10 @@ impl Plugin for EntityPlugin {
                     )
                     .with_system(broadcast_position_updates),
             )
-            .add_startup_system(startup_entities.before(StartupLabels::BuildGridmap));
+            .add_startup_system(
+                startup_entities
+                    .label(StartupLabels::InitEntities)
+                    .before(StartupLabels::BuildGridmap),
+            );
     }
 }
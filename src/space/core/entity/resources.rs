@@ -4,7 +4,7 @@ use bevy_app::EventWriter;
 use bevy_ecs::{
     entity::Entity,
     prelude::{FromWorld, World},
-    system::{Commands, ResMut},
+    system::Commands,
 };
 use bevy_transform::components::Transform;
 
@@ -42,43 +42,100 @@ impl FromWorld for EntityDataResource {
     }
 }
 
-pub struct SpawnPawnData<'a, 'b, 'c> {
-    pub data: (
-        &'a PersistentPlayerData,
-        Option<&'a ConnectedPlayer>,
-        Vec<(String, String)>,
-        bool,
-        bool,
-        Option<&'a mut ResMut<'b, UsedNames>>,
-        Option<&'a mut EventWriter<'b, 'c, NetShowcase>>,
-        Option<String>,
-        &'a ResMut<'a, EntityDataResource>,
-    ),
+// Everything an entity type gets told when it is asked to spawn an instance of itself.
+// Build it with SpawnContext::new and the chained setters below.
+pub struct SpawnContext<'a, 'b, 'c> {
+    pub transform: Transform,
+    // Replace the rotation of the passed transform with the default rotation of the entity.
+    pub correct_transform: bool,
+    pub default_map_spawn: bool,
+    pub properties: HashMap<String, ConsoleCommandVariantValues>,
+    // Spawn the entity inside the inventory of this entity.
+    pub holder_entity: Option<Entity>,
+    pub showcase: Option<SpawnShowcase<'a, 'b, 'c>>,
+    pub pawn: Option<SpawnPawn<'a>>,
 }
 
-pub struct SpawnHeldData<'a, 'b, 'c, 'd> {
-    pub data: (
-        Entity,
-        bool,
-        Option<u32>,
-        &'c mut Option<&'b mut EventWriter<'a, 'd, NetShowcase>>,
-    ),
+pub struct SpawnShowcase<'a, 'b, 'c> {
+    pub handle: u32,
+    pub net_showcase: &'a mut EventWriter<'b, 'c, NetShowcase>,
 }
 
+pub struct SpawnPawn<'a> {
+    pub persistent_player_data: &'a PersistentPlayerData,
+    pub connected_player: Option<&'a ConnectedPlayer>,
+    // Pairs of slot names and the names of the entities to spawn in them.
+    pub inventory_setup: Vec<(String, String)>,
+    pub dummy: bool,
+    pub used_names: Option<&'a mut UsedNames>,
+    pub default_user_name: Option<String>,
+    pub entity_data: &'a EntityDataResource,
+}
+
+impl<'a, 'b, 'c> SpawnContext<'a, 'b, 'c> {
+    pub fn new(transform: Transform) -> Self {
+        Self {
+            transform,
+            correct_transform: false,
+            default_map_spawn: false,
+            properties: HashMap::new(),
+            holder_entity: None,
+            showcase: None,
+            pawn: None,
+        }
+    }
+    pub fn correct_transform(mut self) -> Self {
+        self.correct_transform = true;
+        self
+    }
+    pub fn default_map_spawn(mut self) -> Self {
+        self.default_map_spawn = true;
+        self
+    }
+    pub fn properties(mut self, properties: HashMap<String, ConsoleCommandVariantValues>) -> Self {
+        self.properties = properties;
+        self
+    }
+    pub fn held_by(mut self, holder_entity: Entity) -> Self {
+        self.holder_entity = Some(holder_entity);
+        self
+    }
+    pub fn showcase(
+        mut self,
+        handle: u32,
+        net_showcase: &'a mut EventWriter<'b, 'c, NetShowcase>,
+    ) -> Self {
+        self.showcase = Some(SpawnShowcase {
+            handle,
+            net_showcase,
+        });
+        self
+    }
+    pub fn pawn(mut self, pawn: SpawnPawn<'a>) -> Self {
+        self.pawn = Some(pawn);
+        self
+    }
+}
+
+// The root entity that got spawned together with the entities spawned along with it, such as inventory items.
+pub struct SpawnedEntity {
+    pub entity: Entity,
+    pub children: Vec<Entity>,
+}
+
+impl SpawnedEntity {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            children: vec![],
+        }
+    }
+}
+
+pub type SpawnFunction = Box<dyn Fn(&mut Commands, SpawnContext) -> SpawnedEntity + Sync + Send>;
+
 pub struct EntityDataProperties {
-    pub spawn_function: Box<
-        dyn Fn(
-                Transform,
-                &mut Commands,
-                bool,
-                Option<SpawnPawnData>,
-                Option<SpawnHeldData>,
-                bool,
-                HashMap<String, ConsoleCommandVariantValues>,
-            ) -> Entity
-            + Sync
-            + Send,
-    >,
+    pub spawn_function: SpawnFunction,
     pub name: String,
     pub id: usize,
     pub grid_item: Option<GridItemData>,
@@ -92,7 +149,7 @@ pub struct GridItemData {
 impl Default for EntityDataProperties {
     fn default() -> Self {
         Self {
-            spawn_function: Box::new(|_, _, _, _, _, _, _| Entity::from_raw(0)),
+            spawn_function: Box::new(|_, _| SpawnedEntity::new(Entity::from_raw(0))),
             name: Default::default(),
             id: Default::default(),
             grid_item: None,
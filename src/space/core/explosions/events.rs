@@ -0,0 +1,14 @@
+use bevy_ecs::entity::Entity;
+use bevy_math::Vec3;
+
+pub struct Explosion {
+    pub position: Vec3,
+    // Whoever set it off, or the explosive itself when nobody did.
+    pub attacker_entity: Entity,
+    pub attacker_name: String,
+    pub weapon_name: String,
+    // Brute damage dealt right at the position, it fades out towards the falloff.
+    pub power: f32,
+    // Meters from the position at which the explosion has no effect anymore.
+    pub falloff: f32,
+}
@@ -0,0 +1,14 @@
+use bevy_app::{App, Plugin};
+
+use self::{events::Explosion, systems::explosions};
+
+pub mod events;
+pub mod systems;
+
+pub struct ExplosionsPlugin;
+
+impl Plugin for ExplosionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Explosion>().add_system(explosions);
+    }
+}
@@ -0,0 +1,342 @@
+use bevy_app::{EventReader, EventWriter};
+use bevy_core::Time;
+use bevy_ecs::{
+    entity::Entity,
+    prelude::Without,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_math::Vec3;
+use bevy_rapier3d::prelude::{
+    RigidBodyActivationComponent, RigidBodyMassPropsComponent, RigidBodyPositionComponent,
+    RigidBodyVelocityComponent,
+};
+use bevy_transform::components::Transform;
+
+use crate::space::{
+    core::{
+        atmospherics::{
+            events::IgniteAtmospherics,
+            functions::{get_atmos_index, get_unblocked_reach},
+            resources::AtmosphericsResource,
+        },
+        chat::{events::NetChatMessage, functions::new_personal_message},
+        combat::resources::{CombatLog, CombatLogRecord, CombatLogTarget, CombatRules},
+        connected_player::resources::HandleToEntity,
+        examinable::components::Examinable,
+        explosions::events::Explosion,
+        gridmap::{
+            events::RemoveCell,
+            functions::gridmap_functions::{cell_id_to_world, world_to_cell_id},
+            resources::{CellUpdate, GridmapData, GridmapMain, Vec2Int, Vec3Int},
+        },
+        health::components::{DamageModel, Health, HitResult},
+        networking::resources::GridMapType,
+        pawn::components::Pawn,
+        rigid_body::components::RigidBodyDisabled,
+        sfx::{components::sfx_auto_destroy, resources::SfxAutoDestroyTimers},
+    },
+    entities::sfx::combat::explosion1_sfx::Explosion1SfxBundle,
+};
+
+// Part of the power that is dealt as burn damage on top of the brute damage.
+const EXPLOSION_BURN_FACTOR: f32 = 0.5;
+// Cells are blown away once their brute and burn damage add up to this.
+const CELL_DESTRUCTION_DAMAGE: f32 = 100.;
+// Impulse given to rigid bodies per point of power.
+const EXPLOSION_IMPULSE: f32 = 0.02;
+// Kelvin and mol added to the atmospherics per point of power, the diffusion turns them into a pressure wave.
+const EXPLOSION_HEAT: f32 = 10.;
+const EXPLOSION_GAS_AMOUNT: f32 = 2.;
+
+pub fn explosions(
+    mut explosion_events: EventReader<Explosion>,
+    mut health_entities: Query<
+        (
+            Entity,
+            &mut Health,
+            &RigidBodyPositionComponent,
+            Option<&Examinable>,
+        ),
+        Without<RigidBodyDisabled>,
+    >,
+    pawns: Query<&Pawn>,
+    mut rigid_bodies: Query<
+        (
+            &RigidBodyPositionComponent,
+            &mut RigidBodyVelocityComponent,
+            &RigidBodyMassPropsComponent,
+            &mut RigidBodyActivationComponent,
+        ),
+        Without<RigidBodyDisabled>,
+    >,
+    mut gridmap_main: ResMut<GridmapMain>,
+    gridmap_data: Res<GridmapData>,
+    mut atmospherics_resource: ResMut<AtmosphericsResource>,
+    mut remove_cell_events: EventWriter<RemoveCell>,
+    mut ignite_atmospherics: EventWriter<IgniteAtmospherics>,
+    mut commands: Commands,
+    mut sfx_auto_destroy_timers: ResMut<SfxAutoDestroyTimers>,
+    combat_resources: (Res<CombatRules>, ResMut<CombatLog>, Res<Time>),
+    mut net_message_event: EventWriter<NetChatMessage>,
+    handle_to_entity: Res<HandleToEntity>,
+) {
+    let (combat_rules, mut combat_log, time) = combat_resources;
+
+    // Several explosions can take out the same cell within one tick.
+    let mut destroyed_cells = vec![];
+
+    for event in explosion_events.iter() {
+        if event.falloff <= 0. {
+            continue;
+        }
+
+        let center_cell_id = world_to_cell_id(event.position);
+        let cell_radius = (event.falloff / 2.).ceil() as i16;
+
+        // Walls and closed doors shield whatever is behind them from the explosion.
+        let reached_cells = get_unblocked_reach(
+            Vec2Int {
+                x: center_cell_id.x,
+                y: center_cell_id.z,
+            },
+            cell_radius,
+            &atmospherics_resource,
+        );
+
+        let is_reached = |cell_id: Vec3Int| -> bool {
+            reached_cells.contains(&Vec2Int {
+                x: cell_id.x,
+                y: cell_id.z,
+            })
+        };
+
+        let get_strength = |position: Vec3| -> f32 {
+            let distance = Vec3::new(position.x, 0., position.z).distance(Vec3::new(
+                event.position.x,
+                0.,
+                event.position.z,
+            ));
+            (1. - distance / event.falloff).max(0.)
+        };
+
+        let attacker_position: Vec3;
+
+        match rigid_bodies.get_component::<RigidBodyPositionComponent>(event.attacker_entity) {
+            Ok(rigid_body_position_component) => {
+                attacker_position = rigid_body_position_component.position.translation.into();
+            }
+            Err(_rr) => {
+                attacker_position = event.position;
+            }
+        }
+
+        for (
+            health_entity,
+            mut health_component,
+            rigid_body_position_component,
+            examinable_component_option,
+        ) in health_entities.iter_mut()
+        {
+            let position: Vec3 = rigid_body_position_component.position.translation.into();
+
+            let strength = get_strength(position);
+
+            if strength <= 0. || !is_reached(world_to_cell_id(position)) {
+                continue;
+            }
+
+            let target_name;
+
+            match examinable_component_option {
+                Some(examinable_component) => {
+                    target_name = examinable_component.name.get_name().to_string();
+                }
+                None => {
+                    target_name = "".to_string();
+                }
+            }
+
+            let mut prevented_reason_option = None;
+
+            // Attackers can always hurt themselves.
+            if health_entity != event.attacker_entity {
+                match (pawns.get(event.attacker_entity), pawns.get(health_entity)) {
+                    (Ok(attacker_pawn_component), Ok(target_pawn_component)) => {
+                        prevented_reason_option = combat_rules.get_prevented_reason(
+                            attacker_pawn_component.job,
+                            attacker_position,
+                            target_pawn_component.job,
+                            position,
+                        );
+                    }
+                    _ => {}
+                }
+            }
+
+            let mut damage_model = DamageModel {
+                brute: event.power * strength,
+                burn: event.power * strength * EXPLOSION_BURN_FACTOR,
+                ..Default::default()
+            };
+            let hit_result;
+
+            match prevented_reason_option {
+                Some(reason) => {
+                    new_personal_message(
+                        &mut net_message_event,
+                        &handle_to_entity,
+                        event.attacker_entity,
+                        &("Your attack on ".to_string()
+                            + &target_name
+                            + " was prevented, "
+                            + &reason
+                            + "."),
+                    );
+                    damage_model = DamageModel::default();
+                    hit_result = HitResult::Blocked;
+                }
+                None => {
+                    health_component.apply_environment_damage(&damage_model);
+                    hit_result = HitResult::HitSoft;
+                }
+            }
+
+            combat_log.record(CombatLogRecord {
+                time: time.seconds_since_startup(),
+                attacker_entity: event.attacker_entity,
+                attacker_name: event.attacker_name.to_string(),
+                weapon_name: event.weapon_name.to_string(),
+                target: CombatLogTarget::Entity(health_entity, target_name),
+                limb: "all".to_string(),
+                damage_model,
+                hit_result,
+                position,
+            });
+        }
+
+        for (
+            rigid_body_position_component,
+            mut rigid_body_velocity_component,
+            rigid_body_mass_props_component,
+            mut rigid_body_activation_component,
+        ) in rigid_bodies.iter_mut()
+        {
+            let position: Vec3 = rigid_body_position_component.position.translation.into();
+
+            let strength = get_strength(position);
+
+            if strength <= 0. || !is_reached(world_to_cell_id(position)) {
+                continue;
+            }
+
+            let mut direction = position - event.position;
+            direction.y = 0.;
+            let direction = direction.normalize_or_zero();
+
+            if direction == Vec3::ZERO {
+                continue;
+            }
+
+            let impulse = direction * event.power * strength * EXPLOSION_IMPULSE;
+
+            rigid_body_activation_component.wake_up(true);
+            rigid_body_velocity_component
+                .apply_impulse(rigid_body_mass_props_component, impulse.into());
+        }
+
+        for x in center_cell_id.x - cell_radius..center_cell_id.x + cell_radius + 1 {
+            for z in center_cell_id.z - cell_radius..center_cell_id.z + cell_radius + 1 {
+                for y in [-1, 0] {
+                    let cell_id = Vec3Int { x, y, z };
+
+                    let strength = get_strength(cell_id_to_world(cell_id));
+
+                    if strength <= 0. || !is_reached(cell_id) {
+                        continue;
+                    }
+
+                    match gridmap_main.grid_data.get_mut(&cell_id) {
+                        Some(cell_data) => {
+                            cell_data.health.apply_environment_damage(&DamageModel {
+                                brute: event.power * strength,
+                                burn: event.power * strength * EXPLOSION_BURN_FACTOR,
+                                ..Default::default()
+                            });
+
+                            // Only cells that could have been built can be blown away, the rest holds the map together.
+                            let constructable =
+                                match gridmap_data.main_cell_properties.get(&cell_data.item) {
+                                    Some(cell_properties) => cell_properties.constructable,
+                                    None => false,
+                                };
+
+                            if constructable
+                                && cell_data.health.brute + cell_data.health.burn
+                                    >= CELL_DESTRUCTION_DAMAGE
+                                && !destroyed_cells.contains(&cell_id)
+                            {
+                                destroyed_cells.push(cell_id);
+                            }
+                        }
+                        None => {}
+                    }
+
+                    if y == -1 {
+                        let atmos_id = Vec2Int { x, y: z };
+
+                        if AtmosphericsResource::is_id_out_of_range(atmos_id) {
+                            continue;
+                        }
+
+                        let atmospherics = atmospherics_resource
+                            .atmospherics
+                            .get_mut(get_atmos_index(atmos_id))
+                            .unwrap();
+
+                        if !atmospherics.blocked {
+                            atmospherics.temperature += EXPLOSION_HEAT * event.power * strength;
+                            let new_amount =
+                                atmospherics.amount + EXPLOSION_GAS_AMOUNT * event.power * strength;
+                            atmospherics.scale_amount(new_amount);
+                        }
+                    }
+                }
+            }
+        }
+
+        ignite_atmospherics.send(IgniteAtmospherics {
+            cell_id: Vec2Int {
+                x: center_cell_id.x,
+                y: center_cell_id.z,
+            },
+        });
+
+        let sfx_entity = commands
+            .spawn()
+            .insert_bundle(Explosion1SfxBundle::new(Transform::from_translation(
+                event.position,
+            )))
+            .id();
+        sfx_auto_destroy(sfx_entity, &mut sfx_auto_destroy_timers);
+    }
+
+    for cell_id in destroyed_cells {
+        let mut cell_data_clone = gridmap_main.grid_data.get(&cell_id).unwrap().clone();
+        cell_data_clone.item = -1;
+
+        gridmap_main.updates.insert(
+            cell_id,
+            CellUpdate {
+                entities_received: vec![],
+                cell_data: cell_data_clone.clone(),
+            },
+        );
+
+        remove_cell_events.send(RemoveCell {
+            handle: 0,
+            gridmap_type: GridMapType::Main,
+            id: cell_id,
+            cell_data: cell_data_clone,
+        });
+    }
+}
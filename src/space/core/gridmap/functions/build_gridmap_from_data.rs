@@ -16,7 +16,7 @@ use crate::space::core::{
         components::Cell,
         resources::{
             to_doryen_coordinates, CellData, CellDataWID, DoryenMap, GridmapData, GridmapDetails1,
-            GridmapMain, StructureHealth, Vec3Int,
+            GridmapMain, GridmapPipes, StructureHealth, Vec3Int,
         },
     },
     health::components::HealthFlag,
@@ -136,6 +136,32 @@ pub fn build_details1_gridmap(
     }
 }
 
+pub fn build_pipes_gridmap(
+    current_map_pipes_data: &Vec<CellDataWID>,
+    gridmap_pipes: &mut ResMut<GridmapPipes>,
+    gridmap_data: &mut ResMut<GridmapData>,
+) {
+    for cell_data in current_map_pipes_data.iter() {
+        let cell_id = string_vec3_to_vec3(&cell_data.id);
+
+        let cell_id_int = Vec3Int {
+            x: cell_id.x as i16,
+            y: cell_id.y as i16,
+            z: cell_id.z as i16,
+        };
+
+        gridmap_pipes.data.insert(
+            cell_id_int,
+            CellData {
+                item: *gridmap_data.pipes_name_id_map.get(&cell_data.item).unwrap(),
+                orientation: cell_data.orientation,
+                health: StructureHealth::default(),
+                entity: None,
+            },
+        );
+    }
+}
+
 // We also build cells in systems/construction_tool.rs
 pub fn spawn_main_cell(
     commands: &mut Commands,
@@ -21,6 +21,8 @@ use bevy_transform::components::Transform;
 
 use crate::space::{
     core::{
+        atmospherics::resources::{DEFAULT_HEAT_CAPACITY, DEFAULT_THERMAL_CONDUCTIVITY},
+        combat::components::{LaserSurface, LaserSurfaceKind},
         entity::{
             components::Server,
             functions::{load_raw_map_entities::load_raw_map_entities, raw_entity::RawEntity},
@@ -29,12 +31,14 @@ use crate::space::{
         gridmap::{
             functions::{
                 build_gridmap_floor::build_gridmap_floor,
-                build_gridmap_from_data::{build_details1_gridmap, build_main_gridmap},
+                build_gridmap_from_data::{
+                    build_details1_gridmap, build_main_gridmap, build_pipes_gridmap,
+                },
                 examine_cell::EXAMINATION_EMPTY,
             },
             resources::{
-                CellDataWID, DoryenMap, GridmapData, GridmapDetails1, GridmapMain, SpawnPoint,
-                SpawnPointRaw,
+                CellDataWID, DoryenMap, GridmapData, GridmapDetails1, GridmapMain, GridmapPipes,
+                SpawnPoint, SpawnPointRaw,
             },
         },
         world_environment::resources::WorldEnvironmentRaw,
@@ -63,6 +67,7 @@ use super::{
 pub fn startup_build_map(
     mut gridmap_main: ResMut<GridmapMain>,
     mut gridmap_details1: ResMut<GridmapDetails1>,
+    mut gridmap_pipes: ResMut<GridmapPipes>,
     mut gridmap_data: ResMut<GridmapData>,
     entity_data: Res<EntityDataResource>,
     mut fov_map: ResMut<DoryenMap>,
@@ -104,9 +109,27 @@ pub fn startup_build_map(
         &mut gridmap_data,
     );
 
+    let pipes_json = Path::new("data")
+        .join("maps")
+        .join("bullseye")
+        .join("pipes.json");
+    let current_map_pipes_raw_json: String = fs::read_to_string(pipes_json)
+        .expect("main.rs launch_server() Error reading map pipes.json file from drive.");
+    let current_map_pipes_data: Vec<CellDataWID> =
+        serde_json::from_str(&current_map_pipes_raw_json)
+            .expect("main.rs launch_server() Error parsing map pipes.json String.");
+
+    build_pipes_gridmap(
+        &current_map_pipes_data,
+        &mut gridmap_pipes,
+        &mut gridmap_data,
+    );
+
     info!(
         "Spawned {} map cells.",
-        current_map_main_data.len() + current_map_details1_data.len()
+        current_map_main_data.len()
+            + current_map_details1_data.len()
+            + current_map_pipes_data.len()
     );
 
     let entities_json = Path::new("data")
@@ -139,6 +162,12 @@ pub struct MainCellProperties {
     pub floor_cell: bool,
     pub atmospherics_blocker: bool,
     pub atmospherics_pushes_up: bool,
+    // Between 0 and 1, how fast heat passes through the cell when it blocks atmospherics.
+    pub thermal_conductivity: f32,
+    // Heat the cell stores per kelvin, in mol of air at default ship pressure.
+    pub heat_capacity: f32,
+    // Whether lasers bounce off or pass through the cell, they get stopped by it otherwise.
+    pub laser_surface: Option<LaserSurface>,
     pub direction_rotations: GridDirectionRotations,
 }
 
@@ -174,6 +203,9 @@ impl Default for MainCellProperties {
             floor_cell: false,
             atmospherics_blocker: true,
             atmospherics_pushes_up: false,
+            thermal_conductivity: DEFAULT_THERMAL_CONDUCTIVITY,
+            heat_capacity: DEFAULT_HEAT_CAPACITY,
+            laser_surface: None,
             direction_rotations: GridDirectionRotations::default_wall_rotations(),
         }
     }
@@ -515,6 +547,12 @@ pub fn startup_map_cells(mut gridmap_data: ResMut<GridmapData>) {
         constructable: true,
         non_fov_blocker: true,
         laser_combat_obstacle: false,
+        thermal_conductivity: 0.5 * DEFAULT_THERMAL_CONDUCTIVITY,
+        heat_capacity: 0.5 * DEFAULT_HEAT_CAPACITY,
+        laser_surface: Some(LaserSurface {
+            kind: LaserSurfaceKind::Refractive,
+            energy_factor: 0.8,
+        }),
         ..Default::default()
     });
     main_cells_data.push(MainCellProperties {
@@ -882,6 +920,17 @@ pub fn startup_misc_resources(
         serde_json::from_str(&current_map_details1ordered_cells_raw_json)
             .expect("main.rs main() Error parsing map details1ordered.json String.");
 
+    let pipesordered_cells_json = Path::new("data")
+        .join("maps")
+        .join("bullseye")
+        .join("pipesordered.json");
+    let current_map_pipesordered_cells_raw_json: String =
+        fs::read_to_string(pipesordered_cells_json)
+            .expect("main.rs main() Error reading map pipesordered.json drive.");
+    let current_map_pipesordered_cells: Vec<String> =
+        serde_json::from_str(&current_map_pipesordered_cells_raw_json)
+            .expect("main.rs main() Error parsing map pipesordered.json String.");
+
     for (i, name) in current_map_mainordered_cells.iter().rev().enumerate() {
         gridmap_data
             .main_name_id_map
@@ -900,8 +949,18 @@ pub fn startup_misc_resources(
             .insert(i as i64, name.to_string());
     }
 
+    for (i, name) in current_map_pipesordered_cells.iter().rev().enumerate() {
+        gridmap_data
+            .pipes_name_id_map
+            .insert(name.to_string(), i as i64);
+        gridmap_data
+            .pipes_id_name_map
+            .insert(i as i64, name.to_string());
+    }
+
     gridmap_data.ordered_main_names = current_map_mainordered_cells;
     gridmap_data.ordered_details1_names = current_map_details1ordered_cells;
+    gridmap_data.ordered_pipes_names = current_map_pipesordered_cells;
 
     let spawnpoints_json = Path::new("data")
         .join("maps")
@@ -939,6 +998,7 @@ pub struct GridmapPlugin;
 impl Plugin for GridmapPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<GridmapDetails1>()
+            .init_resource::<GridmapPipes>()
             .init_resource::<GridmapData>()
             .init_resource::<DoryenMap>()
             .init_resource::<SpawnPoints>()
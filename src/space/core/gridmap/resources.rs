@@ -32,10 +32,13 @@ pub struct GridmapData {
     pub placeable_items_cells_list: Vec<i64>,
     pub ordered_main_names: Vec<String>,
     pub ordered_details1_names: Vec<String>,
+    pub ordered_pipes_names: Vec<String>,
     pub main_name_id_map: HashMap<String, i64>,
     pub main_id_name_map: HashMap<i64, String>,
     pub details1_name_id_map: HashMap<String, i64>,
     pub details1_id_name_map: HashMap<i64, String>,
+    pub pipes_name_id_map: HashMap<String, i64>,
+    pub pipes_id_name_map: HashMap<i64, String>,
     pub main_text_names: HashMap<i64, RichName>,
     pub details1_text_names: HashMap<i64, RichName>,
     pub main_text_examine_desc: HashMap<i64, String>,
@@ -54,10 +57,13 @@ impl FromWorld for GridmapData {
             placeable_items_cells_list: vec![],
             ordered_main_names: vec![],
             ordered_details1_names: vec![],
+            ordered_pipes_names: vec![],
             main_name_id_map: HashMap::new(),
             main_id_name_map: HashMap::new(),
             details1_name_id_map: HashMap::new(),
             details1_id_name_map: HashMap::new(),
+            pipes_name_id_map: HashMap::new(),
+            pipes_id_name_map: HashMap::new(),
             main_text_names: HashMap::new(),
             details1_text_names: HashMap::new(),
             main_text_examine_desc: HashMap::new(),
@@ -83,6 +89,19 @@ impl FromWorld for GridmapDetails1 {
     }
 }
 
+// Pipes run underneath the other layers, this layer is not sent to clients.
+pub struct GridmapPipes {
+    pub data: HashMap<Vec3Int, CellData>,
+}
+
+impl FromWorld for GridmapPipes {
+    fn from_world(_world: &mut World) -> Self {
+        GridmapPipes {
+            data: HashMap::new(),
+        }
+    }
+}
+
 pub struct GridmapMain {
     pub grid_data: HashMap<Vec3Int, CellData>,
     pub entity_data: HashMap<Vec3Int, EntityGridData>,
@@ -136,6 +155,20 @@ impl Default for StructureHealth {
 }
 
 impl StructureHealth {
+    pub fn apply_environment_damage(&mut self, damage_model: &DamageModel) {
+        let (brute_damage, burn_damage, toxin_damage, _hit_result) = calculate_damage(
+            &self.health_flags,
+            &damage_model.damage_flags,
+            &damage_model.brute,
+            &damage_model.burn,
+            &damage_model.toxin,
+        );
+
+        self.brute += brute_damage;
+        self.burn += burn_damage;
+        self.toxin += toxin_damage;
+    }
+
     pub fn apply_damage(
         &mut self,
         _body_part: &str,
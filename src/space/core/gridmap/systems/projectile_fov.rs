@@ -26,6 +26,7 @@ pub fn projectile_fov(
         HashMap::new();
     let mut projectiles = vec![];
     let mut projectiles_i: usize = 0;
+    let mut ballistic_projectiles = vec![];
 
     for event in projectile_fov_events.iter() {
         match event.laser_projectile {
@@ -66,7 +67,46 @@ pub fn projectile_fov(
 
                 projectiles_i += 1;
             }
-            NetProjectileType::Ballistic => {}
+            NetProjectileType::Ballistic(start_pos, end_pos, _, _) => {
+                let direction = (end_pos - start_pos).normalize_or_zero();
+                let distance = start_pos.distance(end_pos);
+
+                let mut cell_ids = vec![];
+                let mut iterated_distance: f32 = 0.;
+
+                while iterated_distance < distance {
+                    cell_ids.push(world_to_cell_id(
+                        start_pos + (direction * iterated_distance),
+                    ));
+                    iterated_distance += 2.;
+                }
+                cell_ids.push(world_to_cell_id(end_pos));
+
+                ballistic_projectiles.push((event.laser_projectile.clone(), cell_ids));
+            }
+        }
+    }
+
+    // Tracers and impact decals are sent whole to everyone who can see part of their path.
+    for (senser_component, connected_player_component) in sensers.iter() {
+        if !connected_player_component.connected {
+            continue;
+        }
+
+        for (ballistic_projectile, cell_ids) in ballistic_projectiles.iter() {
+            for cell_id in cell_ids {
+                let coords = to_doryen_coordinates(cell_id.x, cell_id.z);
+
+                if senser_component.fov.is_in_fov(coords.0, coords.1) {
+                    net_projectile_fov.send(NetProjectileFOV {
+                        handle: connected_player_component.handle,
+                        message: ReliableServerMessage::FireProjectile(
+                            ballistic_projectile.clone(),
+                        ),
+                    });
+                    break;
+                }
+            }
         }
     }
 
@@ -1,6 +1,5 @@
 use bevy_app::EventReader;
-use bevy_ecs::system::{Commands, Query, ResMut};
-use doryen_fov::FovAlgorithm;
+use bevy_ecs::system::{Commands, Query, Res, ResMut};
 
 use crate::space::core::{
     atmospherics::{
@@ -13,27 +12,35 @@ use crate::space::core::{
         events::RemoveCell,
         resources::{
             to_doryen_coordinates, CellData, CellUpdate, DoryenMap, GridmapDetails1, GridmapMain,
-            StructureHealth, Vec2Int,
+            GridmapPipes, StructureHealth, Vec2Int,
         },
     },
     networking::resources::GridMapType,
     senser::components::Senser,
+    throwables::resources::SmokeClouds,
 };
 
-use super::senser_update_fov::FOV_DISTANCE;
+use super::senser_update_fov::compute_senser_fov;
 
 pub fn remove_cell(
     mut deconstruct_cell_events: EventReader<RemoveCell>,
     mut gridmap_main: ResMut<GridmapMain>,
     mut gridmap_details1: ResMut<GridmapDetails1>,
+    mut gridmap_pipes: ResMut<GridmapPipes>,
     mut fov_map: ResMut<DoryenMap>,
     mut commands: Commands,
     mut sensers: Query<(&mut Senser, &ConnectedPlayer)>,
     mut atmospherics_resource: ResMut<AtmosphericsResource>,
+    smoke_clouds: Res<SmokeClouds>,
 ) {
     for event in deconstruct_cell_events.iter() {
         match event.gridmap_type {
             GridMapType::Main => {
+                // The cell may already have been removed, for example by an earlier explosion.
+                if !gridmap_main.grid_data.contains_key(&event.id) {
+                    continue;
+                }
+
                 let coords = to_doryen_coordinates(event.id.x, event.id.z);
 
                 let mut atmospherics = atmospherics_resource
@@ -57,6 +64,11 @@ pub fn remove_cell(
                     atmospherics.blocked = false;
                     atmospherics.forces_push_up = false;
                 } else {
+                    // Pipes are laid in the floor and go with it.
+                    if gridmap_pipes.data.contains_key(&event.id) {
+                        gridmap_pipes.data.remove(&event.id);
+                    }
+
                     let mut upper_id = event.id.clone();
                     upper_id.y = 0;
 
@@ -89,18 +101,7 @@ pub fn remove_cell(
 
                 for (mut senser_component, _connected_player_component) in sensers.iter_mut() {
                     if senser_component.fov.is_in_fov(coords.0, coords.1) {
-                        senser_component.fov.clear_fov();
-                        let coords = to_doryen_coordinates(
-                            senser_component.cell_id.x,
-                            senser_component.cell_id.y,
-                        );
-                        senser_component.fov.compute_fov(
-                            &mut fov_map.map,
-                            coords.0,
-                            coords.1,
-                            FOV_DISTANCE,
-                            true,
-                        );
+                        compute_senser_fov(&mut senser_component, &mut fov_map, &smoke_clouds);
 
                         gridmap_main.updates.insert(
                             event.id,
@@ -1,4 +1,4 @@
-use bevy_ecs::system::{Query, ResMut};
+use bevy_ecs::system::{Query, Res, ResMut};
 use bevy_rapier3d::prelude::RigidBodyPositionComponent;
 use doryen_fov::FovAlgorithm;
 
@@ -8,13 +8,45 @@ use crate::space::core::{
         resources::{to_doryen_coordinates, DoryenMap, Vec2Int, FOV_MAP_WIDTH},
     },
     senser::components::Senser,
+    throwables::resources::SmokeClouds,
 };
 
 pub const FOV_DISTANCE: usize = 23;
 
+// Smoke is laid over the map only while computing what a senser sees,
+// everything else that reads the DoryenMap keeps seeing just the cells.
+pub fn compute_senser_fov(
+    senser_component: &mut Senser,
+    fov_map: &mut DoryenMap,
+    smoke_clouds: &SmokeClouds,
+) {
+    let mut smoked_coords = vec![];
+
+    for cloud in smoke_clouds.clouds.iter() {
+        for cell_id in cloud.cells.iter() {
+            let coords = to_doryen_coordinates(cell_id.x, cell_id.y);
+            if fov_map.map.is_transparent(coords.0, coords.1) {
+                fov_map.map.set_transparent(coords.0, coords.1, false);
+                smoked_coords.push(coords);
+            }
+        }
+    }
+
+    senser_component.fov.clear_fov();
+    let coords = to_doryen_coordinates(senser_component.cell_id.x, senser_component.cell_id.y);
+    senser_component
+        .fov
+        .compute_fov(&mut fov_map.map, coords.0, coords.1, FOV_DISTANCE, true);
+
+    for coords in smoked_coords {
+        fov_map.map.set_transparent(coords.0, coords.1, true);
+    }
+}
+
 pub fn senser_update_fov(
     mut senser_entities: Query<(&mut Senser, &RigidBodyPositionComponent)>,
     mut map: ResMut<DoryenMap>,
+    smoke_clouds: Res<SmokeClouds>,
 ) {
     for (mut senser_component, rigid_body_position_component) in senser_entities.iter_mut() {
         let senser_cell_id_3 =
@@ -35,11 +67,7 @@ pub fn senser_update_fov(
             // 240000 ns. 1/4th of a ms. 4x/ms (expensive.)
             // Will need a faster replacement at some point. Because scales bad with huge maps. Capped to 500x500 tiles (1kmx1km) for time being.
             // Max map size support is around 4kmx4km but will need a faster FOV algorithm for that.
-            senser_component.fov.clear_fov();
-            let coords = to_doryen_coordinates(senser_cell_id.x, senser_cell_id.y);
-            senser_component
-                .fov
-                .compute_fov(&mut map.map, coords.0, coords.1, FOV_DISTANCE, true);
+            compute_senser_fov(&mut senser_component, &mut map, &smoke_clouds);
         }
     }
 }
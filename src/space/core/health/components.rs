@@ -7,6 +7,7 @@ use bevy_ecs::{
     system::{Query, Res},
 };
 use rand::prelude::SliceRandom;
+use serde::{Deserialize, Serialize};
 
 use crate::space::core::{
     chat::events::NetChatMessage,
@@ -26,6 +27,8 @@ pub struct Health {
     pub is_combat_obstacle: bool,
     pub is_laser_obstacle: bool,
     pub is_reach_obstacle: bool,
+    // Armor of the items currently worn, per limb.
+    pub worn_armor: HashMap<String, LimbArmor>,
 }
 
 impl Default for Health {
@@ -40,6 +43,7 @@ impl Default for Health {
             is_combat_obstacle: false,
             is_laser_obstacle: true,
             is_reach_obstacle: false,
+            worn_armor: HashMap::new(),
         }
     }
 }
@@ -52,16 +56,126 @@ pub enum HealthFlag {
     TorsoBruteDefence(f32),
 }
 
+// Armor values are the percentage of damage of that type a limb is spared.
+pub const MAX_ARMOR: f32 = 90.;
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct LimbArmor {
+    pub brute: f32,
+    pub burn: f32,
+    pub toxin: f32,
+}
+
+impl LimbArmor {
+    pub fn add(&mut self, other: &LimbArmor) {
+        self.brute += other.brute;
+        self.burn += other.burn;
+        self.toxin += other.toxin;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.brute <= 0. && self.burn <= 0. && self.toxin <= 0.
+    }
+
+    pub fn reduce(&self, brute: f32, burn: f32, toxin: f32) -> (f32, f32, f32) {
+        (
+            brute * (1. - self.brute.min(MAX_ARMOR) / 100.),
+            burn * (1. - self.burn.min(MAX_ARMOR) / 100.),
+            toxin * (1. - self.toxin.min(MAX_ARMOR) / 100.),
+        )
+    }
+}
+
+// Worn items with this component protect the limbs of their wearer.
+#[derive(Component, Clone)]
+pub struct Armor {
+    pub limbs: HashMap<String, LimbArmor>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
 pub enum HealthContainer {
     Humanoid(HumanoidHealth),
     Entity(EntityContainer),
 }
 
-#[allow(dead_code)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct RaegentContainer {
-    raegents: HashMap<String, f32>,
+    pub raegents: HashMap<String, f32>,
 }
 
+impl RaegentContainer {
+    pub fn get_total(&self) -> f32 {
+        self.raegents.values().sum()
+    }
+
+    pub fn get_amount(&self, raegent_name: &str) -> f32 {
+        match self.raegents.get(raegent_name) {
+            Some(amount) => *amount,
+            None => 0.,
+        }
+    }
+
+    pub fn add(&mut self, raegent_name: &str, amount: f32) {
+        if amount <= 0. {
+            return;
+        }
+        *self.raegents.entry(raegent_name.to_string()).or_insert(0.) += amount;
+    }
+
+    // Returns how much was actually removed.
+    pub fn remove(&mut self, raegent_name: &str, amount: f32) -> f32 {
+        let removed;
+
+        match self.raegents.get_mut(raegent_name) {
+            Some(current_amount) => {
+                removed = amount.min(*current_amount);
+                *current_amount -= removed;
+                if *current_amount <= RAEGENT_EPSILON {
+                    self.raegents.remove(raegent_name);
+                }
+            }
+            None => {
+                removed = 0.;
+            }
+        }
+
+        removed
+    }
+
+    // Takes the amount out of the container, every raegent in proportion to how much of it there is.
+    pub fn take(&mut self, amount: f32) -> RaegentContainer {
+        let mut taken = RaegentContainer {
+            raegents: HashMap::new(),
+        };
+
+        let total = self.get_total();
+
+        if total <= 0. || amount <= 0. {
+            return taken;
+        }
+
+        let fraction = (amount / total).min(1.);
+
+        let names: Vec<String> = self.raegents.keys().cloned().collect();
+
+        for name in names {
+            let taken_amount = self.get_amount(&name) * fraction;
+            let removed = self.remove(&name, taken_amount);
+            taken.add(&name, removed);
+        }
+
+        taken
+    }
+
+    pub fn merge(&mut self, other: RaegentContainer) {
+        for (name, amount) in other.raegents {
+            self.add(&name, amount);
+        }
+    }
+}
+
+const RAEGENT_EPSILON: f32 = 0.001;
+
 #[allow(dead_code)]
 #[derive(PartialEq, Clone)]
 pub enum DamageFlag {
@@ -71,7 +185,7 @@ pub enum DamageFlag {
     Floor(f32),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct HumanoidHealth {
     pub head_brute: f32,
     pub head_burn: f32,
@@ -98,6 +212,7 @@ pub struct HumanoidHealth {
     pub left_leg_toxin: f32,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
 pub struct EntityContainer {
     pub brute: f32,
     pub burn: f32,
@@ -112,6 +227,37 @@ pub struct DamageModel {
     pub damage_flags: HashMap<u32, DamageFlag>,
 }
 
+impl DamageModel {
+    pub fn scaled(&self, factor: f32) -> DamageModel {
+        DamageModel {
+            brute: self.brute * factor,
+            burn: self.burn * factor,
+            toxin: self.toxin * factor,
+            damage_flags: self.damage_flags.clone(),
+        }
+    }
+
+    // The longest stun and floor durations among the damage flags, in seconds.
+    pub fn get_status_durations(&self) -> (f32, f32) {
+        let mut stun: f32 = 0.;
+        let mut floor: f32 = 0.;
+
+        for damage_flag in self.damage_flags.values() {
+            match damage_flag {
+                DamageFlag::Stun(duration) => {
+                    stun = stun.max(*duration);
+                }
+                DamageFlag::Floor(duration) => {
+                    floor = floor.max(*duration);
+                }
+                _ => {}
+            }
+        }
+
+        (stun, floor)
+    }
+}
+
 impl Default for DamageModel {
     fn default() -> Self {
         Self {
@@ -124,6 +270,7 @@ impl Default for DamageModel {
 }
 
 #[allow(dead_code)]
+#[derive(Clone, Copy)]
 pub enum HitResult {
     HitSoft,
     Blocked,
@@ -174,7 +321,156 @@ pub enum DamageType {
     Projectile,
 }
 
+// Heal the damage values in turn until the amount is used up.
+fn heal_damage(damage_values: Vec<&mut f32>, amount: f32) {
+    let mut remaining = amount;
+
+    for damage in damage_values {
+        if remaining <= 0. {
+            break;
+        }
+        let healed = remaining.min(*damage);
+        *damage -= healed;
+        remaining -= healed;
+    }
+}
+
 impl Health {
+    // Worn armor combined with the defence flags of the limb.
+    pub fn get_limb_armor(&self, limb: &str) -> LimbArmor {
+        let mut limb_armor = match self.worn_armor.get(limb) {
+            Some(worn_limb_armor) => *worn_limb_armor,
+            None => LimbArmor::default(),
+        };
+
+        for health_flag in self.health_flags.values() {
+            match health_flag {
+                HealthFlag::HeadBruteDefence(defence) => {
+                    if limb == "head" {
+                        limb_armor.brute += defence;
+                    }
+                }
+                HealthFlag::TorsoBruteDefence(defence) => {
+                    if limb == "torso" {
+                        limb_armor.brute += defence;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        limb_armor
+    }
+
+    // Effects of metabolised raegents, negative amounts heal.
+    // Harmful raegents work from within the torso, healing is spread over the whole body.
+    pub fn apply_raegent_effects(&mut self, brute: f32, burn: f32, toxin: f32) {
+        match &mut self.health_container {
+            HealthContainer::Humanoid(humanoid_health) => {
+                if brute > 0. {
+                    humanoid_health.torso_brute += brute;
+                } else {
+                    heal_damage(
+                        vec![
+                            &mut humanoid_health.torso_brute,
+                            &mut humanoid_health.head_brute,
+                            &mut humanoid_health.left_arm_brute,
+                            &mut humanoid_health.right_arm_brute,
+                            &mut humanoid_health.left_leg_brute,
+                            &mut humanoid_health.right_leg_brute,
+                        ],
+                        -brute,
+                    );
+                }
+                if burn > 0. {
+                    humanoid_health.torso_burn += burn;
+                } else {
+                    heal_damage(
+                        vec![
+                            &mut humanoid_health.torso_burn,
+                            &mut humanoid_health.head_burn,
+                            &mut humanoid_health.left_arm_burn,
+                            &mut humanoid_health.right_arm_burn,
+                            &mut humanoid_health.left_leg_burn,
+                            &mut humanoid_health.right_leg_burn,
+                        ],
+                        -burn,
+                    );
+                }
+                if toxin > 0. {
+                    humanoid_health.torso_toxin += toxin;
+                } else {
+                    heal_damage(
+                        vec![
+                            &mut humanoid_health.torso_toxin,
+                            &mut humanoid_health.head_toxin,
+                            &mut humanoid_health.left_arm_toxin,
+                            &mut humanoid_health.right_arm_toxin,
+                            &mut humanoid_health.left_leg_toxin,
+                            &mut humanoid_health.right_leg_toxin,
+                        ],
+                        -toxin,
+                    );
+                }
+            }
+            HealthContainer::Entity(item) => {
+                item.brute = (item.brute + brute).max(0.);
+                item.burn = (item.burn + burn).max(0.);
+                item.toxin = (item.toxin + toxin).max(0.);
+            }
+        }
+    }
+
+    // Damage from the environment such as fire, it is not aimed at a limb and has no attacker.
+    // Humanoids get it spread evenly over their limbs.
+    pub fn apply_environment_damage(&mut self, damage_model: &DamageModel) {
+        let (brute_damage, burn_damage, toxin_damage, _hit_result) = calculate_damage(
+            &self.health_flags,
+            &damage_model.damage_flags,
+            &damage_model.brute,
+            &damage_model.burn,
+            &damage_model.toxin,
+        );
+
+        match &mut self.health_container {
+            HealthContainer::Humanoid(humanoid_health) => {
+                let limb_count = HUMANOID_LIMBS.len() as f32;
+                let limb_brute = brute_damage / limb_count;
+                let limb_burn = burn_damage / limb_count;
+                let limb_toxin = toxin_damage / limb_count;
+
+                humanoid_health.head_brute += limb_brute;
+                humanoid_health.head_burn += limb_burn;
+                humanoid_health.head_toxin += limb_toxin;
+
+                humanoid_health.torso_brute += limb_brute;
+                humanoid_health.torso_burn += limb_burn;
+                humanoid_health.torso_toxin += limb_toxin;
+
+                humanoid_health.left_arm_brute += limb_brute;
+                humanoid_health.left_arm_burn += limb_burn;
+                humanoid_health.left_arm_toxin += limb_toxin;
+
+                humanoid_health.right_arm_brute += limb_brute;
+                humanoid_health.right_arm_burn += limb_burn;
+                humanoid_health.right_arm_toxin += limb_toxin;
+
+                humanoid_health.left_leg_brute += limb_brute;
+                humanoid_health.left_leg_burn += limb_burn;
+                humanoid_health.left_leg_toxin += limb_toxin;
+
+                humanoid_health.right_leg_brute += limb_brute;
+                humanoid_health.right_leg_burn += limb_burn;
+                humanoid_health.right_leg_toxin += limb_toxin;
+            }
+            HealthContainer::Entity(item) => {
+                item.brute += brute_damage;
+                item.burn += burn_damage;
+                item.toxin += toxin_damage;
+            }
+        }
+    }
+
     pub fn apply_damage(
         &mut self,
         body_part: &str,
@@ -200,6 +496,10 @@ impl Health {
             &damage_model.toxin,
         );
 
+        let (brute_damage, burn_damage, toxin_damage) =
+            self.get_limb_armor(body_part)
+                .reduce(brute_damage, burn_damage, toxin_damage);
+
         let attacker_cell_id_doryen = to_doryen_coordinates(attacker_cell_id.x, attacker_cell_id.z);
         let attacked_cell_id_doryen = to_doryen_coordinates(attacked_cell_id.x, attacked_cell_id.z);
 
@@ -545,3 +845,183 @@ impl Default for HumanoidHealth {
         }
     }
 }
+
+// The limb names used by targetted_limb.
+pub const HUMANOID_LIMBS: [&str; 6] = [
+    "head",
+    "torso",
+    "left_arm",
+    "right_arm",
+    "left_leg",
+    "right_leg",
+];
+
+// Total damage at which a humanoid enters each state.
+pub const CRITICAL_DAMAGE: f32 = 100.;
+pub const UNCONSCIOUS_DAMAGE: f32 = 150.;
+pub const DEATH_DAMAGE: f32 = 200.;
+
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+pub enum HealthState {
+    Healthy,
+    Critical,
+    Unconscious,
+    Dead,
+}
+
+impl HealthState {
+    pub fn can_sprint(&self) -> bool {
+        matches!(self, HealthState::Healthy)
+    }
+    pub fn is_conscious(&self) -> bool {
+        matches!(self, HealthState::Healthy | HealthState::Critical)
+    }
+}
+
+// Brute damage of a limb at which it starts bleeding and at which it breaks.
+pub const BLEEDING_DAMAGE: f32 = 30.;
+pub const BROKEN_DAMAGE: f32 = 60.;
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum LimbWound {
+    None,
+    Bleeding,
+    // Broken limbs bleed as well, broken arms cannot hold anything.
+    Broken,
+}
+
+impl LimbWound {
+    pub fn is_bleeding(&self) -> bool {
+        !matches!(self, LimbWound::None)
+    }
+}
+
+// The raegent humanoids bleed out.
+pub const BLOOD: &str = "blood";
+pub const BLOOD_VOLUME: f32 = 100.;
+
+impl HumanoidHealth {
+    pub fn get_limb_wound(&self, limb: &str) -> LimbWound {
+        let brute;
+
+        match self.get_limb_damage(limb) {
+            Some((limb_brute, _, _)) => {
+                brute = limb_brute;
+            }
+            None => {
+                return LimbWound::None;
+            }
+        }
+
+        if brute >= BROKEN_DAMAGE {
+            LimbWound::Broken
+        } else if brute >= BLEEDING_DAMAGE {
+            LimbWound::Bleeding
+        } else {
+            LimbWound::None
+        }
+    }
+
+    pub fn get_total_damage(&self) -> f32 {
+        let mut total = 0.;
+        for limb in HUMANOID_LIMBS {
+            let (brute, burn, toxin) = self.get_limb_damage(limb).unwrap();
+            total += brute + burn + toxin;
+        }
+        total
+    }
+
+    pub fn get_health_state(&self) -> HealthState {
+        let total_damage = self.get_total_damage();
+
+        if total_damage >= DEATH_DAMAGE {
+            HealthState::Dead
+        } else if total_damage >= UNCONSCIOUS_DAMAGE {
+            HealthState::Unconscious
+        } else if total_damage >= CRITICAL_DAMAGE {
+            HealthState::Critical
+        } else {
+            HealthState::Healthy
+        }
+    }
+
+    // Brute, burn and toxin damage of a limb.
+    pub fn get_limb_damage(&self, limb: &str) -> Option<(f32, f32, f32)> {
+        match limb {
+            "head" => Some((self.head_brute, self.head_burn, self.head_toxin)),
+            "torso" => Some((self.torso_brute, self.torso_burn, self.torso_toxin)),
+            "left_arm" => Some((self.left_arm_brute, self.left_arm_burn, self.left_arm_toxin)),
+            "right_arm" => Some((
+                self.right_arm_brute,
+                self.right_arm_burn,
+                self.right_arm_toxin,
+            )),
+            "left_leg" => Some((self.left_leg_brute, self.left_leg_burn, self.left_leg_toxin)),
+            "right_leg" => Some((
+                self.right_leg_brute,
+                self.right_leg_burn,
+                self.right_leg_toxin,
+            )),
+            _ => None,
+        }
+    }
+
+    // Returns false if there is no such limb.
+    pub fn heal_limb(&mut self, limb: &str, brute: f32, burn: f32, toxin: f32) -> bool {
+        let damage_values;
+
+        match limb {
+            "head" => {
+                damage_values = (
+                    &mut self.head_brute,
+                    &mut self.head_burn,
+                    &mut self.head_toxin,
+                );
+            }
+            "torso" => {
+                damage_values = (
+                    &mut self.torso_brute,
+                    &mut self.torso_burn,
+                    &mut self.torso_toxin,
+                );
+            }
+            "left_arm" => {
+                damage_values = (
+                    &mut self.left_arm_brute,
+                    &mut self.left_arm_burn,
+                    &mut self.left_arm_toxin,
+                );
+            }
+            "right_arm" => {
+                damage_values = (
+                    &mut self.right_arm_brute,
+                    &mut self.right_arm_burn,
+                    &mut self.right_arm_toxin,
+                );
+            }
+            "left_leg" => {
+                damage_values = (
+                    &mut self.left_leg_brute,
+                    &mut self.left_leg_burn,
+                    &mut self.left_leg_toxin,
+                );
+            }
+            "right_leg" => {
+                damage_values = (
+                    &mut self.right_leg_brute,
+                    &mut self.right_leg_burn,
+                    &mut self.right_leg_toxin,
+                );
+            }
+            _ => {
+                return false;
+            }
+        }
+
+        heal_damage(vec![damage_values.0], brute);
+        heal_damage(vec![damage_values.1], burn);
+        heal_damage(vec![damage_values.2], toxin);
+
+        true
+    }
+}
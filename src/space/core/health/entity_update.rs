@@ -9,7 +9,10 @@ use bevy_ecs::{
 
 use crate::space::core::{
     connected_player::components::ConnectedPlayer,
-    health::{components::Health, events::NetHealthUpdate},
+    health::{
+        components::{Health, LimbWound, HUMANOID_LIMBS},
+        events::NetHealthUpdate,
+    },
     networking::resources::{EntityUpdateData, EntityWorldType, ReliableServerMessage},
 };
 
@@ -80,6 +83,7 @@ pub fn health_ui_update(
                             right_arm_damage: UIDamageType::None,
                             left_leg_damage: UIDamageType::None,
                             right_leg_damage: UIDamageType::None,
+                            wounds: HashMap::new(),
                         },
                     );
                     client_health_ui_option =
@@ -495,6 +499,49 @@ pub fn health_ui_update(
                     }
                 }
 
+                // Wounds
+                for limb in HUMANOID_LIMBS {
+                    let wound = humanoid_health.get_limb_wound(limb);
+
+                    let cached_wound;
+
+                    match client_health_ui.wounds.get(limb) {
+                        Some(cached) => {
+                            cached_wound = *cached;
+                        }
+                        None => {
+                            cached_wound = LimbWound::None;
+                        }
+                    }
+
+                    if wound != cached_wound {
+                        client_health_ui.wounds.insert(limb.to_string(), wound);
+
+                        let wound_name;
+
+                        match wound {
+                            LimbWound::None => {
+                                wound_name = "None";
+                            }
+                            LimbWound::Bleeding => {
+                                wound_name = "Bleeding";
+                            }
+                            LimbWound::Broken => {
+                                wound_name = "Broken";
+                            }
+                        }
+
+                        entity_updates_map
+                            .entry(get_ui_limb_name(limb).to_string())
+                            .or_insert(HashMap::new())
+                            .insert(
+                                "wound".to_string(),
+                                EntityUpdateData::String(wound_name.to_string()),
+                            );
+                        new_update = true;
+                    }
+                }
+
                 if new_update && connected_player_component.connected {
                     net_health_update.send(NetHealthUpdate {
                         handle: connected_player_component.handle,
@@ -511,3 +558,14 @@ pub fn health_ui_update(
         }
     }
 }
+
+// Name of the limb's node in the health UI.
+fn get_ui_limb_name(limb: &str) -> &str {
+    match limb {
+        "left_arm" => "leftArm",
+        "right_arm" => "rightArm",
+        "left_leg" => "leftLeg",
+        "right_leg" => "rightLeg",
+        _ => limb,
+    }
+}
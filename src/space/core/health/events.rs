@@ -4,10 +4,11 @@ use bevy_math::Vec3;
 use crate::space::core::{
     gridmap::resources::Vec3Int,
     health::components::DamageModel,
-    inventory_item::components::{CombatSoundSet, CombatType},
+    inventory_item::components::{CombatSoundSet, CombatType, MeleeShape},
     networking::resources::ReliableServerMessage,
 };
 
+#[derive(Clone)]
 pub struct Attack {
     pub attacker_entity: Entity,
     pub attacker_sensed_by: Vec<Entity>,
@@ -22,6 +23,7 @@ pub struct Attack {
     pub damage_model: DamageModel,
     pub range: f32,
     pub combat_type: CombatType,
+    pub melee_shape: MeleeShape,
     pub combat_sound_set: CombatSoundSet,
     pub offense_words: Vec<String>,
     pub trigger_words: Vec<String>,
@@ -29,6 +31,13 @@ pub struct Attack {
     pub targetted_cell: Option<Vec3Int>,
 }
 
+// Stun and floor durations in seconds, zero leaves that effect alone.
+pub struct InflictStatusEffects {
+    pub entity: Entity,
+    pub stun: f32,
+    pub floor: f32,
+}
+
 pub struct NetHealthUpdate {
     pub handle: u32,
     pub message: ReliableServerMessage,
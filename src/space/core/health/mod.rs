@@ -1,19 +1,22 @@
 use bevy_app::CoreStage::PostUpdate;
 use bevy_app::{App, Plugin};
+use bevy_core::FixedTimestep;
 use bevy_ecs::schedule::SystemSet;
 
 use crate::space::PostUpdateLabels;
 
 use self::{
     entity_update::health_ui_update,
-    events::{Attack, NetHealthUpdate},
+    events::{Attack, InflictStatusEffects, NetHealthUpdate},
     resources::ClientHealthUICache,
+    systems::{bleeding, worn_armor, BLEEDING_INTERVAL},
 };
 
 pub mod components;
 pub mod entity_update;
 pub mod events;
 pub mod resources;
+pub mod systems;
 
 pub struct HealthPlugin;
 
@@ -22,6 +25,13 @@ impl Plugin for HealthPlugin {
         app.init_resource::<ClientHealthUICache>()
             .add_event::<NetHealthUpdate>()
             .add_event::<Attack>()
+            .add_event::<InflictStatusEffects>()
+            .add_system(worn_armor)
+            .add_system_set(
+                SystemSet::new()
+                    .with_run_criteria(FixedTimestep::step(BLEEDING_INTERVAL))
+                    .with_system(bleeding),
+            )
             .add_system_set_to_stage(
                 PostUpdate,
                 SystemSet::new()
@@ -5,6 +5,8 @@ use bevy_ecs::{
     prelude::{FromWorld, World},
 };
 
+use crate::space::core::health::components::LimbWound;
+
 pub struct ClientHealthUICache {
     pub cache: HashMap<Entity, ClientHealthUI>,
 }
@@ -24,6 +26,7 @@ pub struct ClientHealthUI {
     pub right_arm_damage: UIDamageType,
     pub left_leg_damage: UIDamageType,
     pub right_leg_damage: UIDamageType,
+    pub wounds: HashMap<String, LimbWound>,
 }
 
 pub enum UIDamageType {
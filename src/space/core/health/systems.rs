@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use bevy_ecs::{prelude::Changed, system::Query};
+
+use crate::space::core::{
+    health::components::{
+        Armor, Health, HealthContainer, LimbArmor, LimbWound, BLOOD, BLOOD_VOLUME, HUMANOID_LIMBS,
+    },
+    inventory::components::{Inventory, SlotType},
+};
+
+// Combine the armor of every worn item whenever the wearer's inventory changes.
+pub fn worn_armor(
+    mut wearers: Query<(&Inventory, &mut Health), Changed<Inventory>>,
+    armors: Query<&Armor>,
+) {
+    for (inventory_component, mut health_component) in wearers.iter_mut() {
+        let mut worn_armor: HashMap<String, LimbArmor> = HashMap::new();
+
+        for slot in inventory_component.slots.iter() {
+            // Items held in the hands are not worn.
+            if matches!(slot.slot_type, SlotType::Generic) {
+                continue;
+            }
+
+            match slot.slot_item {
+                Some(item_entity) => match armors.get(item_entity) {
+                    Ok(armor_component) => {
+                        for (limb, limb_armor) in armor_component.limbs.iter() {
+                            worn_armor
+                                .entry(limb.to_string())
+                                .or_insert(LimbArmor::default())
+                                .add(limb_armor);
+                        }
+                    }
+                    Err(_rr) => {}
+                },
+                None => {}
+            }
+        }
+
+        health_component.worn_armor = worn_armor;
+    }
+}
+
+// In seconds.
+pub const BLEEDING_INTERVAL: f64 = 2.;
+
+// Blood lost per interval for each bleeding limb.
+const BLEEDING_RATE: f32 = 1.;
+const BROKEN_BLEEDING_RATE: f32 = 2.;
+const BLOOD_REGENERATION: f32 = 0.1;
+// Below this much blood the body starts to take damage.
+const BLOOD_LOSS_THRESHOLD: f32 = 60.;
+const BLOOD_LOSS_TOXIN: f32 = 2.;
+
+pub fn bleeding(mut healths: Query<&mut Health>) {
+    for mut health_component in healths.iter_mut() {
+        let mut blood_loss = 0.;
+
+        match &health_component.health_container {
+            HealthContainer::Humanoid(humanoid_health) => {
+                for limb in HUMANOID_LIMBS {
+                    match humanoid_health.get_limb_wound(limb) {
+                        LimbWound::None => {}
+                        LimbWound::Bleeding => {
+                            blood_loss += BLEEDING_RATE;
+                        }
+                        LimbWound::Broken => {
+                            blood_loss += BROKEN_BLEEDING_RATE;
+                        }
+                    }
+                }
+            }
+            HealthContainer::Entity(_) => {
+                continue;
+            }
+        }
+
+        let blood = health_component.raegent_container.get_amount(BLOOD);
+
+        if blood_loss > 0. {
+            health_component.raegent_container.remove(BLOOD, blood_loss);
+        } else if blood < BLOOD_VOLUME {
+            health_component
+                .raegent_container
+                .add(BLOOD, BLOOD_REGENERATION.min(BLOOD_VOLUME - blood));
+        }
+
+        // Without an oxygen damage type blood loss poisons the body instead.
+        if blood < BLOOD_LOSS_THRESHOLD {
+            health_component.apply_raegent_effects(0., 0., BLOOD_LOSS_TOXIN);
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use crate::space::core::health::components::{DamageFlag, DamageModel};
-use crate::space::core::inventory_item::components::CombatSoundSet;
+use crate::space::core::health::components::{DamageFlag, DamageModel, HealthState, LimbWound};
+use crate::space::core::inventory_item::components::{CombatSoundSet, MeleeAttack};
 use bevy_core::Timer;
 use bevy_ecs::entity::Entity;
 use bevy_ecs::prelude::Component;
@@ -16,6 +16,16 @@ pub struct Humanoid {
     pub next_attack_timer: Timer,
     pub default_melee_damage_model: DamageModel,
     pub default_melee_sound_set: CombatSoundSet,
+    pub default_melee_attack: MeleeAttack,
+    // Follows the damage of the Health component, dead humanoids stay dead.
+    pub health_state: HealthState,
+    // Time left on the stun and floor effects, None when not affected.
+    pub stun_timer: Option<Timer>,
+    pub floor_timer: Option<Timer>,
+    // Whether the collider is currently the reduced one of a humanoid on the floor.
+    pub lying_down: bool,
+    // Follows the wounds of the limbs in the Health component.
+    pub wounds: HashMap<String, LimbWound>,
 }
 
 pub enum CharacterAnimationState {
@@ -34,7 +44,10 @@ pub struct LinkedFootstepsWalking {
     pub entity: Entity,
 }
 
-const FIRST_MELEE_TIME: u64 = 433;
+pub const FIRST_MELEE_TIME: u64 = 433;
+
+// Movement speed is multiplied by this for each broken leg.
+const BROKEN_LEG_MOVEMENT_FACTOR: f32 = 0.5;
 
 impl Default for Humanoid {
     fn default() -> Self {
@@ -55,6 +68,40 @@ impl Default for Humanoid {
                 ..Default::default()
             },
             default_melee_sound_set: CombatSoundSet::default(),
+            default_melee_attack: MeleeAttack::default(),
+            health_state: HealthState::Healthy,
+            stun_timer: None,
+            floor_timer: None,
+            lying_down: false,
+            wounds: HashMap::new(),
+        }
+    }
+}
+
+impl Humanoid {
+    pub fn is_stunned(&self) -> bool {
+        self.stun_timer.is_some()
+    }
+    pub fn is_floored(&self) -> bool {
+        self.floor_timer.is_some()
+    }
+    // Whether the humanoid can move, attack and act on its own.
+    pub fn can_act(&self) -> bool {
+        self.health_state.is_conscious() && !self.is_stunned() && !self.is_floored()
+    }
+    pub fn get_limb_wound(&self, limb: &str) -> LimbWound {
+        match self.wounds.get(limb) {
+            Some(wound) => *wound,
+            None => LimbWound::None,
+        }
+    }
+    pub fn get_movement_factor(&self) -> f32 {
+        let mut factor = 1.;
+        for leg in ["left_leg", "right_leg"] {
+            if matches!(self.get_limb_wound(leg), LimbWound::Broken) {
+                factor *= BROKEN_LEG_MOVEMENT_FACTOR;
+            }
         }
+        factor
     }
 }
@@ -48,7 +48,7 @@ pub fn humanoid_update(
     {
         let old_entity_updates = entity_updates_component.updates.clone();
 
-        let lower_body_animation_state: String;
+        let mut lower_body_animation_state: String;
 
         let mut upper_body_animation_state: String;
 
@@ -328,6 +328,19 @@ pub fn humanoid_update(
                 .insert("blend_amount".to_string(), EntityUpdateData::Float(0.));
         }
 
+        // Unconscious and dead humanoids lie on the floor whatever else is going on.
+        if !standard_character_component.health_state.is_conscious() {
+            lower_body_animation_state = "Lying".to_string();
+            upper_body_animation_state = "Lying".to_string();
+            animation_tree1_upper_blend
+                .insert("blend_amount".to_string(), EntityUpdateData::Float(0.));
+        } else if standard_character_component.is_floored() {
+            lower_body_animation_state = "Prone".to_string();
+            upper_body_animation_state = "Prone".to_string();
+            animation_tree1_upper_blend
+                .insert("blend_amount".to_string(), EntityUpdateData::Float(0.));
+        }
+
         let mut animation_tree1_upper_body_updates = HashMap::new();
         let mut animation_tree1_lower_body_updates = HashMap::new();
 
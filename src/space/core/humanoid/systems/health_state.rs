@@ -0,0 +1,140 @@
+use bevy_app::EventWriter;
+use bevy_ecs::{
+    entity::Entity,
+    prelude::Changed,
+    system::{Query, Res},
+};
+use bevy_math::Vec2;
+
+use crate::space::core::{
+    chat::{events::NetChatMessage, functions::new_personal_message},
+    connected_player::{components::ConnectedPlayer, resources::HandleToEntity},
+    health::{
+        components::{Health, HealthContainer, HealthState},
+        events::NetHealthUpdate,
+    },
+    humanoid::components::Humanoid,
+    networking::resources::ReliableServerMessage,
+    pawn::components::ControllerInput,
+};
+
+pub fn humanoid_health_state(
+    mut humanoids: Query<
+        (
+            Entity,
+            &Health,
+            &mut Humanoid,
+            &mut ControllerInput,
+            Option<&ConnectedPlayer>,
+        ),
+        Changed<Health>,
+    >,
+    handle_to_entity: Res<HandleToEntity>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+    mut net_health_update: EventWriter<NetHealthUpdate>,
+) {
+    for (
+        entity,
+        health_component,
+        mut humanoid_component,
+        mut controller_input_component,
+        connected_player_component_option,
+    ) in humanoids.iter_mut()
+    {
+        let old_state = humanoid_component.health_state;
+
+        if old_state == HealthState::Dead {
+            continue;
+        }
+
+        let new_state;
+
+        match &health_component.health_container {
+            HealthContainer::Humanoid(humanoid_health) => {
+                new_state = humanoid_health.get_health_state();
+            }
+            HealthContainer::Entity(_) => {
+                continue;
+            }
+        }
+
+        if new_state == old_state {
+            continue;
+        }
+
+        humanoid_component.health_state = new_state;
+
+        if !new_state.is_conscious() {
+            humanoid_component.combat_mode = false;
+            humanoid_component.is_attacking = false;
+
+            controller_input_component.movement_vector = Vec2::ZERO;
+            controller_input_component.sprinting = false;
+            controller_input_component.is_mouse_action_pressed = false;
+            controller_input_component.auto_move_enabled = false;
+            controller_input_component.combat_targetted_entity = None;
+            controller_input_component.combat_targetted_cell = None;
+        } else if !new_state.can_sprint() {
+            controller_input_component.sprinting = false;
+        }
+
+        let message;
+
+        match new_state {
+            HealthState::Healthy => {
+                message = "You feel better.";
+            }
+            HealthState::Critical => {
+                if old_state > new_state {
+                    message = "You come to, barely.";
+                } else {
+                    message = "You are in critical condition!";
+                }
+            }
+            HealthState::Unconscious => {
+                message = "You lose consciousness.";
+            }
+            HealthState::Dead => {
+                message = "You have died.";
+            }
+        }
+
+        new_personal_message(&mut net_chat_message, &handle_to_entity, entity, message);
+
+        match connected_player_component_option {
+            Some(connected_player_component) => {
+                if !connected_player_component.connected {
+                    continue;
+                }
+                match get_health_state_notice(old_state) {
+                    Some(notice) => {
+                        net_health_update.send(NetHealthUpdate {
+                            handle: connected_player_component.handle,
+                            message: ReliableServerMessage::UIRemoveNotice(notice.to_string()),
+                        });
+                    }
+                    None => {}
+                }
+                match get_health_state_notice(new_state) {
+                    Some(notice) => {
+                        net_health_update.send(NetHealthUpdate {
+                            handle: connected_player_component.handle,
+                            message: ReliableServerMessage::UIAddNotice(notice.to_string()),
+                        });
+                    }
+                    None => {}
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+fn get_health_state_notice(health_state: HealthState) -> Option<&'static str> {
+    match health_state {
+        HealthState::Healthy => None,
+        HealthState::Critical => Some("Critical condition"),
+        HealthState::Unconscious => Some("Unconscious"),
+        HealthState::Dead => Some("Dead"),
+    }
+}
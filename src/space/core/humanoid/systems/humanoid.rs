@@ -1,10 +1,10 @@
-use std::f32::consts::PI;
+use std::{f32::consts::PI, time::Duration};
 
 use bevy_app::EventWriter;
 use bevy_core::Time;
 use bevy_ecs::{
     entity::Entity,
-    system::{Commands, Query, Res},
+    system::{Commands, Query, Res, ResMut},
 };
 use bevy_log::warn;
 use bevy_math::{Quat, Vec2, Vec3};
@@ -18,7 +18,10 @@ use bevy_rapier3d::{
 
 use crate::space::{
     core::{
+        ammunition::components::{AmmoContainer, AmmoUser},
         atmospherics::components::ZeroGravity,
+        chat::{events::NetChatMessage, functions::new_personal_message},
+        combat::components::{Blocking, WeaponAccuracy},
         configuration::resources::TickRate,
         connected_player::resources::HandleToEntity,
         entity::{
@@ -33,6 +36,7 @@ use crate::space::{
         health::events::Attack,
         humanoid::components::{
             CharacterAnimationState, Humanoid, LinkedFootstepsSprinting, LinkedFootstepsWalking,
+            FIRST_MELEE_TIME,
         },
         inventory::components::Inventory,
         inventory_item::components::{CombatType, InventoryItem},
@@ -42,12 +46,18 @@ use crate::space::{
         },
         rigid_body::components::RigidBodyData,
         sensable::components::Sensable,
-        sfx::components::{FootstepsSprinting, FootstepsWalking},
+        sfx::{
+            components::{sfx_auto_destroy, FootstepsSprinting, FootstepsWalking},
+            resources::SfxAutoDestroyTimers,
+        },
         static_body::components::StaticTransform,
     },
-    entities::sfx::actions::{
-        footsteps_sprinting_sfx::FootstepsSprintingSfxBundle,
-        footsteps_walking_sfx::FootstepsWalkingSfxBundle,
+    entities::sfx::{
+        actions::{
+            footsteps_sprinting_sfx::FootstepsSprintingSfxBundle,
+            footsteps_walking_sfx::FootstepsWalkingSfxBundle,
+        },
+        combat::weapon_empty_sfx::WeaponEmptySfxBundle,
     },
 };
 
@@ -56,7 +66,6 @@ const MAX_JOG_SPEED: f32 = 10.;
 const MAX_RUN_SPEED: f32 = 14.;
 const RUN_SPEED: f32 = 3031.44;
 
-const MELEE_FISTS_REACH: f32 = 1.2;
 const COMBAT_ROTATION_SPEED: f32 = 18.;
 const DOWN_FORCE: f32 = -1.0;
 
@@ -95,10 +104,14 @@ pub fn humanoids(
     mut commands: Commands,
     tick_rate: Res<TickRate>,
     mut attack_event_writer: EventWriter<Attack>,
-    tuple0: (EventWriter<NetUnloadEntity>,),
+    tuple0: (EventWriter<NetUnloadEntity>, EventWriter<NetChatMessage>),
     gridmap_main: Res<GridmapMain>,
+    mut ammo_users: Query<(&mut AmmoContainer, &AmmoUser)>,
+    mut sfx_auto_destroy_timers: ResMut<SfxAutoDestroyTimers>,
+    mut weapon_accuracies: Query<&mut WeaponAccuracy>,
+    mut blockers: Query<&mut Blocking>,
 ) {
-    let (mut net_unload_entity,) = tuple0;
+    let (mut net_unload_entity, mut net_chat_message) = tuple0;
 
     for (
         standard_character_entity,
@@ -117,6 +130,30 @@ pub fn humanoids(
         rigidbody_data_component,
     ) in humanoids_query.iter_mut()
     {
+        // Input that still arrives for incapacitated humanoids is ignored.
+        let health_state = standard_character_component.health_state;
+
+        if !standard_character_component.can_act() {
+            if player_input_component.movement_vector != Vec2::ZERO
+                || player_input_component.auto_move_enabled
+                || player_input_component.is_mouse_action_pressed
+            {
+                player_input_component.movement_vector = Vec2::ZERO;
+                player_input_component.auto_move_enabled = false;
+                player_input_component.is_mouse_action_pressed = false;
+            }
+            if standard_character_component.combat_mode {
+                standard_character_component.combat_mode = false;
+            }
+            if player_input_component.blocking {
+                player_input_component.blocking = false;
+            }
+        }
+
+        if !health_state.can_sprint() && player_input_component.sprinting {
+            player_input_component.sprinting = false;
+        }
+
         let movement_options = PawnYAxisRotations::new();
 
         let character_movement_state;
@@ -142,6 +179,10 @@ pub fn humanoids(
             speed_factor = RUN_SPEED;
         }
 
+        // Broken legs slow the humanoid down.
+        let movement_factor = standard_character_component.get_movement_factor();
+        speed_factor *= movement_factor;
+
         let player_input_movement_vector;
 
         if player_input_component.auto_move_enabled
@@ -198,6 +239,27 @@ pub fn humanoids(
             .tick(delta_time);
         let ready_to_attack_this_frame = standard_character_component.next_attack_timer.finished();
 
+        // Sprinting leaves a held weapon unsteady for a while.
+        match character_movement_state {
+            CharacterMovementState::Sprinting => {
+                match inventory_component
+                    .get_slot(&inventory_component.active_slot)
+                    .slot_item
+                {
+                    Some(item_entity) => match weapon_accuracies.get_mut(item_entity) {
+                        Ok(mut weapon_accuracy_component) => {
+                            weapon_accuracy_component.sprinted();
+                        }
+                        Err(_rr) => {}
+                    },
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+
+        let mut blocking_this_frame = false;
+
         // If combat mode, specific new rotation based on mouse direction.
         if standard_character_component.combat_mode && !player_input_component.sprinting {
             let active_slot = inventory_component.get_slot(&inventory_component.active_slot);
@@ -272,7 +334,12 @@ pub fn humanoids(
 
             let mut attacking_this_frame = false;
 
-            if player_input_component.is_mouse_action_pressed {
+            // A raised guard keeps the humanoid from attacking.
+            if player_input_component.blocking {
+                blocking_this_frame = true;
+            }
+
+            if player_input_component.is_mouse_action_pressed && !blocking_this_frame {
                 if ready_to_attack_this_frame {
                     attacking_this_frame = true;
                 }
@@ -292,12 +359,43 @@ pub fn humanoids(
                 }
             }
 
+            // Every shot uses up ammunition, an empty weapon only clicks.
+            if attacking_this_frame && !alt_attack_mode {
+                match active_slot.slot_item {
+                    Some(weapon_entity) => match ammo_users.get_mut(weapon_entity) {
+                        Ok((mut ammo_container_component, ammo_user_component)) => {
+                            if !ammo_container_component.consume(ammo_user_component.shot_cost) {
+                                attacking_this_frame = false;
+
+                                new_personal_message(
+                                    &mut net_chat_message,
+                                    &handle_to_entity,
+                                    standard_character_entity,
+                                    &("*click* The ".to_string()
+                                        + &inventory_item_slot_name
+                                        + " is empty."),
+                                );
+
+                                let sfx_entity = commands
+                                    .spawn()
+                                    .insert_bundle(WeaponEmptySfxBundle::new(rigid_body_transform))
+                                    .id();
+                                sfx_auto_destroy(sfx_entity, &mut sfx_auto_destroy_timers);
+                            }
+                        }
+                        Err(_rr) => {}
+                    },
+                    None => {}
+                }
+            }
+
             if attacking_this_frame {
                 // Get used inventory item and attack mode enum. Then on match execute directPreciseRayCastMeleeAttack
                 let mut combat_type = &CombatType::MeleeDirect;
                 let mut combat_damage_model =
                     &standard_character_component.default_melee_damage_model;
                 let mut combat_sound_set = &standard_character_component.default_melee_sound_set;
+                let mut melee_attack = &standard_character_component.default_melee_attack;
 
                 let offense_words;
                 let trigger_words;
@@ -310,6 +408,7 @@ pub fn humanoids(
                                 combat_damage_model =
                                     &inventory_item_component.combat_melee_damage_model;
                                 combat_sound_set = &inventory_item_component.combat_melee_sound_set;
+                                melee_attack = &inventory_item_component.combat_melee_attack;
                                 offense_words =
                                     inventory_item_component.combat_melee_text_set.clone();
                                 trigger_words =
@@ -340,6 +439,7 @@ pub fn humanoids(
                                         &inventory_item_component.combat_melee_damage_model;
                                     combat_sound_set =
                                         &inventory_item_component.combat_melee_sound_set;
+                                    melee_attack = &inventory_item_component.combat_melee_attack;
                                     offense_words =
                                         inventory_item_component.combat_melee_text_set.clone();
                                     trigger_words =
@@ -355,12 +455,41 @@ pub fn humanoids(
                     }
                 }
 
-                let mut angle = standard_character_component.facing_direction;
+                let mut angle = get_attack_angle(standard_character_component.facing_direction);
+
+                // Shots stray from where they were aimed depending on the weapon and how it is handled.
+                match combat_type {
+                    CombatType::Projectile(_) => match active_slot.slot_item {
+                        Some(weapon_entity) => match weapon_accuracies.get_mut(weapon_entity) {
+                            Ok(mut weapon_accuracy_component) => {
+                                let moving;
+                                match character_movement_state {
+                                    CharacterMovementState::None => {
+                                        moving = false;
+                                    }
+                                    _ => {
+                                        moving = true;
+                                    }
+                                }
+                                angle += weapon_accuracy_component.fire(moving);
+                            }
+                            Err(_rr) => {}
+                        },
+                        None => {}
+                    },
+                    CombatType::MeleeDirect => {}
+                }
+
+                // Melee weapons set how soon the next attack can follow.
+                let next_attack_duration;
 
-                if angle < 0. {
-                    angle = -PI - angle;
-                } else {
-                    angle = PI - angle;
+                match combat_type {
+                    CombatType::MeleeDirect => {
+                        next_attack_duration = Duration::from_secs_f32(melee_attack.cooldown);
+                    }
+                    CombatType::Projectile(_) => {
+                        next_attack_duration = Duration::from_millis(FIRST_MELEE_TIME);
+                    }
                 }
 
                 let sensable_component = sensable_entities
@@ -378,8 +507,9 @@ pub fn humanoids(
                     ),
                     angle: angle,
                     damage_model: combat_damage_model.clone(),
-                    range: MELEE_FISTS_REACH,
+                    range: melee_attack.reach,
                     combat_type: combat_type.clone(),
+                    melee_shape: melee_attack.shape.clone(),
                     targetted_limb: player_input_component.targetted_limb.clone(),
                     attacker_name: standard_character_component.character_name.clone(),
                     combat_sound_set: combat_sound_set.clone(),
@@ -391,6 +521,33 @@ pub fn humanoids(
                     targetted_entity: player_input_component.combat_targetted_entity.clone(),
                     targetted_cell: player_input_component.combat_targetted_cell.clone(),
                 });
+
+                standard_character_component
+                    .next_attack_timer
+                    .set_duration(next_attack_duration);
+            }
+        }
+
+        match blockers.get_mut(standard_character_entity) {
+            Ok(mut blocking_component) => {
+                if blocking_this_frame {
+                    blocking_component.angle =
+                        get_attack_angle(standard_character_component.facing_direction);
+                    blocking_component.parry_timer.tick(delta_time);
+                } else {
+                    commands
+                        .entity(standard_character_entity)
+                        .remove::<Blocking>();
+                }
+            }
+            Err(_rr) => {
+                if blocking_this_frame {
+                    commands
+                        .entity(standard_character_entity)
+                        .insert(Blocking::new(get_attack_angle(
+                            standard_character_component.facing_direction,
+                        )));
+                }
             }
         }
 
@@ -744,10 +901,10 @@ pub fn humanoids(
                     max_speed = 0.;
                 }
                 CharacterMovementState::Jogging => {
-                    max_speed = MAX_JOG_SPEED;
+                    max_speed = MAX_JOG_SPEED * movement_factor;
                 }
                 CharacterMovementState::Sprinting => {
-                    max_speed = MAX_RUN_SPEED;
+                    max_speed = MAX_RUN_SPEED * movement_factor;
                 }
             }
 
@@ -883,3 +1040,12 @@ pub fn humanoids(
         }
     }
 }
+
+// The angle attacks are sent with, from the direction the humanoid faces.
+fn get_attack_angle(facing_direction: f32) -> f32 {
+    if facing_direction < 0. {
+        -PI - facing_direction
+    } else {
+        PI - facing_direction
+    }
+}
@@ -4,11 +4,19 @@ use bevy_rapier3d::physics::PhysicsSystems;
 
 use crate::space::{PostUpdateLabels, UpdateLabels};
 
-use self::humanoid::humanoids;
+use self::{
+    health_state::humanoid_health_state,
+    humanoid::humanoids,
+    status_effects::{inflict_status_effects, status_effects},
+    wounds::humanoid_wounds,
+};
 
 use super::entity_update::humanoid_update;
 
+pub mod health_state;
 pub mod humanoid;
+pub mod status_effects;
+pub mod wounds;
 use bevy_app::CoreStage::PostUpdate;
 
 pub struct HumanoidPlugin;
@@ -21,6 +29,14 @@ impl Plugin for HumanoidPlugin {
                 .before(PhysicsSystems::StepWorld)
                 .after(UpdateLabels::ProcessMovementInput),
         )
+        .add_system(humanoid_health_state.before(UpdateLabels::StandardCharacters))
+        .add_system(inflict_status_effects.before(UpdateLabels::StandardCharacters))
+        .add_system(humanoid_wounds.before(UpdateLabels::StandardCharacters))
+        .add_system(
+            status_effects
+                .after(inflict_status_effects)
+                .before(UpdateLabels::StandardCharacters),
+        )
         .add_system_set_to_stage(
             PostUpdate,
             SystemSet::new()
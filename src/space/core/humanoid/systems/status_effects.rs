@@ -0,0 +1,196 @@
+use bevy_app::{EventReader, EventWriter};
+use bevy_core::{Time, Timer};
+use bevy_ecs::{
+    entity::Entity,
+    system::{Query, Res},
+};
+use bevy_rapier3d::prelude::ColliderShapeComponent;
+
+use crate::space::{
+    core::{
+        chat::{events::NetChatMessage, functions::new_personal_message},
+        connected_player::{components::ConnectedPlayer, resources::HandleToEntity},
+        health::events::{InflictStatusEffects, NetHealthUpdate},
+        humanoid::components::Humanoid,
+        networking::resources::ReliableServerMessage,
+    },
+    entities::human_male_pawn::spawn::get_character_collider_shape,
+};
+
+const STUNNED_NOTICE: &str = "Stunned";
+const FLOORED_NOTICE: &str = "Floored";
+
+pub fn inflict_status_effects(
+    mut inflict_status_effects_events: EventReader<InflictStatusEffects>,
+    mut humanoids: Query<(&mut Humanoid, Option<&ConnectedPlayer>)>,
+    handle_to_entity: Res<HandleToEntity>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+    mut net_health_update: EventWriter<NetHealthUpdate>,
+) {
+    for event in inflict_status_effects_events.iter() {
+        let (mut humanoid_component, connected_player_component_option);
+
+        match humanoids.get_mut(event.entity) {
+            Ok((h, c)) => {
+                humanoid_component = h;
+                connected_player_component_option = c;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        if !humanoid_component.health_state.is_conscious() {
+            continue;
+        }
+
+        if event.stun > 0. {
+            let was_stunned = humanoid_component.is_stunned();
+
+            // A new stun only extends the current one.
+            if extends_timer(&humanoid_component.stun_timer, event.stun) {
+                humanoid_component.stun_timer = Some(Timer::from_seconds(event.stun, false));
+            }
+
+            if !was_stunned {
+                new_personal_message(
+                    &mut net_chat_message,
+                    &handle_to_entity,
+                    event.entity,
+                    "You are stunned!",
+                );
+                send_notice(
+                    connected_player_component_option,
+                    ReliableServerMessage::UIAddNotice(STUNNED_NOTICE.to_string()),
+                    &mut net_health_update,
+                );
+            }
+        }
+
+        if event.floor > 0. {
+            let was_floored = humanoid_component.is_floored();
+
+            if extends_timer(&humanoid_component.floor_timer, event.floor) {
+                humanoid_component.floor_timer = Some(Timer::from_seconds(event.floor, false));
+            }
+
+            if !was_floored {
+                new_personal_message(
+                    &mut net_chat_message,
+                    &handle_to_entity,
+                    event.entity,
+                    "You are knocked to the floor!",
+                );
+                send_notice(
+                    connected_player_component_option,
+                    ReliableServerMessage::UIAddNotice(FLOORED_NOTICE.to_string()),
+                    &mut net_health_update,
+                );
+            }
+        }
+    }
+}
+
+pub fn status_effects(
+    time: Res<Time>,
+    mut humanoids: Query<(
+        Entity,
+        &mut Humanoid,
+        &mut ColliderShapeComponent,
+        Option<&ConnectedPlayer>,
+    )>,
+    handle_to_entity: Res<HandleToEntity>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+    mut net_health_update: EventWriter<NetHealthUpdate>,
+) {
+    for (entity, mut humanoid_component, mut collider_shape_component, connected_player_option) in
+        humanoids.iter_mut()
+    {
+        let mut stun_finished = false;
+        let mut floor_finished = false;
+
+        match &mut humanoid_component.stun_timer {
+            Some(timer) => {
+                timer.tick(time.delta());
+                stun_finished = timer.finished();
+            }
+            None => {}
+        }
+        match &mut humanoid_component.floor_timer {
+            Some(timer) => {
+                timer.tick(time.delta());
+                floor_finished = timer.finished();
+            }
+            None => {}
+        }
+
+        // Effects wear off silently when their victim is no longer conscious.
+        let conscious = humanoid_component.health_state.is_conscious();
+
+        if stun_finished || (!conscious && humanoid_component.is_stunned()) {
+            humanoid_component.stun_timer = None;
+            if conscious {
+                new_personal_message(
+                    &mut net_chat_message,
+                    &handle_to_entity,
+                    entity,
+                    "You can move again.",
+                );
+            }
+            send_notice(
+                connected_player_option,
+                ReliableServerMessage::UIRemoveNotice(STUNNED_NOTICE.to_string()),
+                &mut net_health_update,
+            );
+        }
+
+        if floor_finished || (!conscious && humanoid_component.is_floored()) {
+            humanoid_component.floor_timer = None;
+            if conscious {
+                new_personal_message(
+                    &mut net_chat_message,
+                    &handle_to_entity,
+                    entity,
+                    "You get back up.",
+                );
+            }
+            send_notice(
+                connected_player_option,
+                ReliableServerMessage::UIRemoveNotice(FLOORED_NOTICE.to_string()),
+                &mut net_health_update,
+            );
+        }
+
+        let lying_down = humanoid_component.is_floored() || !conscious;
+
+        if lying_down != humanoid_component.lying_down {
+            humanoid_component.lying_down = lying_down;
+            *collider_shape_component = get_character_collider_shape(lying_down).into();
+        }
+    }
+}
+
+fn extends_timer(timer_option: &Option<Timer>, duration: f32) -> bool {
+    match timer_option {
+        Some(timer) => timer.duration().as_secs_f32() - timer.elapsed_secs() < duration,
+        None => true,
+    }
+}
+
+fn send_notice(
+    connected_player_component_option: Option<&ConnectedPlayer>,
+    message: ReliableServerMessage,
+    net_health_update: &mut EventWriter<NetHealthUpdate>,
+) {
+    match connected_player_component_option {
+        Some(connected_player_component) => {
+            if connected_player_component.connected {
+                net_health_update.send(NetHealthUpdate {
+                    handle: connected_player_component.handle,
+                    message,
+                });
+            }
+        }
+        None => {}
+    }
+}
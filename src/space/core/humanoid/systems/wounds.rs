@@ -0,0 +1,103 @@
+use bevy_app::EventWriter;
+use bevy_ecs::{
+    entity::Entity,
+    prelude::{Changed, Or},
+    system::{Query, Res},
+};
+
+use crate::space::core::{
+    chat::{events::NetChatMessage, functions::new_personal_message},
+    connected_player::resources::HandleToEntity,
+    health::components::{Health, HealthContainer, LimbWound, HUMANOID_LIMBS},
+    humanoid::components::Humanoid,
+    inventory::{components::Inventory, events::InputDropCurrentItem},
+    medical::functions::get_limb_display_name,
+};
+
+// The hand slot that a disabled arm can no longer hold anything in.
+const ARM_HAND_SLOTS: [(&str, &str); 2] = [("left_arm", "left_hand"), ("right_arm", "right_hand")];
+
+pub fn humanoid_wounds(
+    mut humanoids: Query<
+        (Entity, &Health, &mut Humanoid, &Inventory),
+        Or<(Changed<Health>, Changed<Inventory>)>,
+    >,
+    handle_to_entity: Res<HandleToEntity>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+    mut drop_current_item: EventWriter<InputDropCurrentItem>,
+) {
+    for (entity, health_component, mut humanoid_component, inventory_component) in
+        humanoids.iter_mut()
+    {
+        let humanoid_health;
+
+        match &health_component.health_container {
+            HealthContainer::Humanoid(health) => {
+                humanoid_health = health;
+            }
+            HealthContainer::Entity(_) => {
+                continue;
+            }
+        }
+
+        for limb in HUMANOID_LIMBS {
+            let old_wound = humanoid_component.get_limb_wound(limb);
+            let new_wound = humanoid_health.get_limb_wound(limb);
+
+            if old_wound == new_wound {
+                continue;
+            }
+
+            humanoid_component
+                .wounds
+                .insert(limb.to_string(), new_wound);
+
+            if !humanoid_component.health_state.is_conscious() {
+                continue;
+            }
+
+            let message;
+
+            match new_wound {
+                LimbWound::Broken => {
+                    message = " is broken!";
+                }
+                LimbWound::Bleeding => {
+                    if old_wound == LimbWound::Broken {
+                        message = " is no longer broken.";
+                    } else {
+                        message = " is bleeding!";
+                    }
+                }
+                LimbWound::None => {
+                    message = " stops bleeding.";
+                }
+            }
+
+            new_personal_message(
+                &mut net_chat_message,
+                &handle_to_entity,
+                entity,
+                &("Your ".to_string() + &get_limb_display_name(limb) + message),
+            );
+        }
+
+        for (arm, hand_slot_name) in ARM_HAND_SLOTS {
+            if humanoid_component.get_limb_wound(arm) != LimbWound::Broken {
+                continue;
+            }
+
+            for slot in inventory_component.slots.iter() {
+                if slot.slot_name == hand_slot_name && slot.slot_item.is_some() {
+                    drop_current_item.send(InputDropCurrentItem {
+                        pickuper_entity: entity,
+                        input_position_option: None,
+                        storage_entity_option: None,
+                        slot_name_option: Some(hand_slot_name.to_string()),
+                        involuntary: true,
+                    });
+                }
+            }
+        }
+    }
+}
@@ -31,6 +31,7 @@ pub enum SlotType {
     Helmet,
     Jumpsuit,
     Holster,
+    Back,
 }
 
 impl Inventory {
@@ -6,6 +6,12 @@ use crate::space::core::networking::resources::ReliableServerMessage;
 pub struct InputDropCurrentItem {
     pub pickuper_entity: Entity,
     pub input_position_option: Option<Vec3>,
+    // Store the item in this storage instead of dropping it in the world.
+    pub storage_entity_option: Option<Entity>,
+    // Drop from this slot instead of the active one.
+    pub slot_name_option: Option<String>,
+    // Involuntary drops, like from a broken arm, happen even when the pickuper cannot act.
+    pub involuntary: bool,
 }
 
 pub struct InputThrowItem {
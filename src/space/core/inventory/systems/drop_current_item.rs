@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use bevy_app::{EventReader, EventWriter};
 use bevy_ecs::{
     entity::Entity,
-    prelude::QueryState,
+    prelude::{QueryState, Without},
     system::{Commands, Query, QuerySet, Res},
 };
 use bevy_log::warn;
@@ -24,8 +24,9 @@ use crate::space::core::{
         resources::{GridmapData, GridmapMain},
     },
     health::components::Health,
+    humanoid::components::Humanoid,
     inventory::{
-        components::Inventory,
+        components::{Inventory, Slot},
         events::{InputDropCurrentItem, NetDropCurrentItem},
     },
     inventory_item::components::InventoryItem,
@@ -40,6 +41,10 @@ use crate::space::core::{
     physics::components::{WorldMode, WorldModes},
     rigid_body::{components::RigidBodyLinkTransform, functions::enable_rigidbody},
     sensable::components::Sensable,
+    storage::{
+        components::{StorageContainer, Stored},
+        functions::get_holders,
+    },
 };
 
 pub fn drop_current_item<'a>(
@@ -47,6 +52,7 @@ pub fn drop_current_item<'a>(
     mut rigidbody_positions: Query<&mut RigidBodyPositionComponent>,
     mut inventory_entities: Query<(&mut Inventory, &Sensable, &Pawn)>,
     mut inventory_items_query: Query<&mut InventoryItem>,
+    mut storages: Query<(&mut Inventory, &StorageContainer), Without<Pawn>>,
     health_query: Query<&Health>,
     cell_query: Query<&Cell>,
     mut q: QuerySet<(
@@ -70,8 +76,18 @@ pub fn drop_current_item<'a>(
     gridmap_main: Res<GridmapMain>,
     gridmap_data: Res<GridmapData>,
     query_pipeline: Res<QueryPipeline>,
+    humanoids: Query<&Humanoid>,
 ) {
     for event in drop_current_item_events.iter() {
+        match humanoids.get(event.pickuper_entity) {
+            Ok(humanoid_component) => {
+                if !event.involuntary && !humanoid_component.can_act() {
+                    continue;
+                }
+            }
+            Err(_rr) => {}
+        }
+
         let pickuper_components_option = inventory_entities.get_mut(event.pickuper_entity);
         let pickuper_components;
 
@@ -86,9 +102,18 @@ pub fn drop_current_item<'a>(
 
         let mut pickuper_inventory = pickuper_components.0;
 
-        let pickup_slot = &pickuper_inventory.active_slot.clone();
+        let pickup_slot;
+
+        match &event.slot_name_option {
+            Some(slot_name) => {
+                pickup_slot = slot_name.clone();
+            }
+            None => {
+                pickup_slot = pickuper_inventory.active_slot.clone();
+            }
+        }
 
-        let drop_slot = pickuper_inventory.get_slot_mut(pickup_slot);
+        let drop_slot = pickuper_inventory.get_slot_mut(&pickup_slot);
 
         let pickupable_entity;
 
@@ -101,6 +126,115 @@ pub fn drop_current_item<'a>(
             }
         }
 
+        match event.storage_entity_option {
+            Some(storage_entity) => {
+                let pickuper_position: Vec3;
+                let storage_transform;
+
+                match (
+                    rigidbody_positions.get(event.pickuper_entity),
+                    rigidbody_positions.get(storage_entity),
+                ) {
+                    (Ok(pickuper_rigidbody_position), Ok(storage_rigidbody_position)) => {
+                        pickuper_position = pickuper_rigidbody_position.position.translation.into();
+                        storage_transform =
+                            isometry_to_transform(storage_rigidbody_position.position);
+                    }
+                    _ => {
+                        warn!("Couldn't find position of pickuper or storage entity!");
+                        continue;
+                    }
+                }
+
+                if pickuper_position.distance(storage_transform.translation) > REACH_DISTANCE {
+                    continue;
+                }
+
+                // Storages cannot be stored inside themselves or inside anything stored in them.
+                if storage_entity == pickupable_entity
+                    || get_holders(storage_entity, &inventory_items_query)
+                        .contains(&pickupable_entity)
+                {
+                    continue;
+                }
+
+                let mut storage_inventory;
+                let storage_component;
+
+                match storages.get_mut(storage_entity) {
+                    Ok((inventory, storage)) => {
+                        storage_inventory = inventory;
+                        storage_component = storage;
+                    }
+                    Err(_rr) => {
+                        continue;
+                    }
+                }
+
+                if !storage_component.open {
+                    continue;
+                }
+
+                let mut inventory_item_component = inventory_items_query.get_mut(pickupable_entity)
+                .expect("drop_current_item.rs couldnt find InventoryItem component of pickupable_entity from query.");
+
+                if inventory_item_component.size > storage_component.max_item_size {
+                    continue;
+                }
+
+                let mut storage_slot_option = None;
+
+                for slot in storage_inventory.slots.iter_mut() {
+                    if slot.slot_item.is_none() {
+                        storage_slot_option = Some(slot);
+                        break;
+                    }
+                }
+
+                match storage_slot_option {
+                    Some(storage_slot) => {
+                        storage_slot.slot_item = Some(pickupable_entity);
+                    }
+                    None => {
+                        continue;
+                    }
+                }
+
+                drop_slot.slot_item = None;
+                inventory_item_component.in_inventory_of_entity = Some(storage_entity);
+
+                let mut q0 = q.q0();
+
+                let (
+                    mut pickupable_world_mode_component,
+                    _,
+                    _,
+                    _,
+                    mut pickupable_rigidbody_link_transform_component,
+                ) = q0.get_mut(pickupable_entity)
+                .expect("drop_current_item.rs couldnt find pickupable_components of pickupable_entity from query.");
+
+                // Stored items stay disabled and follow the storage around.
+                pickupable_world_mode_component.mode = WorldModes::Worn;
+                pickupable_rigidbody_link_transform_component.follow_entity = storage_entity;
+
+                commands.entity(pickupable_entity).insert(Stored);
+
+                send_drop_item_updates(
+                    drop_slot,
+                    pickupable_entity,
+                    storage_transform,
+                    pickuper_components.1,
+                    event.pickuper_entity,
+                    &handle_to_entity,
+                    &mut net_drop_current_item,
+                );
+
+                continue;
+            }
+            None => {}
+        }
+
         let inventory_item_component_prev = inventory_items_query.get_component_mut::<InventoryItem>(pickupable_entity)
         .expect("drop_current_item.rs couldnt find pickupable_components of pickupable_entity from query.");
 
@@ -238,59 +372,78 @@ pub fn drop_current_item<'a>(
             .entity(pickupable_entity)
             .remove::<RigidBodyLinkTransform>();
 
-        match &drop_slot.slot_attachment {
-            Some(attachment_path) => {
-                // Create detachItem entityUpdate and send it to send_entity_update.rs
-
-                let mut root_entity_update = HashMap::new();
-
-                let mut entity_update = HashMap::new();
-
-                entity_update.insert(
-                    "detachItem".to_string(),
-                    EntityUpdateData::AttachedItem(
-                        pickupable_entity.to_bits(),
-                        new_position.translation,
-                        new_position.rotation,
-                        new_position.scale,
-                    ),
-                );
+        send_drop_item_updates(
+            drop_slot,
+            pickupable_entity,
+            new_position,
+            pickuper_components.1,
+            event.pickuper_entity,
+            &handle_to_entity,
+            &mut net_drop_current_item,
+        );
+    }
+}
 
-                root_entity_update.insert(attachment_path.to_string(), entity_update);
-
-                for entity_id in pickuper_components.1.sensed_by.iter() {
-                    let handle_option = handle_to_entity.inv_map.get(&entity_id);
-
-                    match handle_option {
-                        Some(handle) => {
-                            net_drop_current_item.send(NetDropCurrentItem {
-                                handle: *handle,
-                                message: ReliableServerMessage::EntityUpdate(
-                                    entity_id.to_bits(),
-                                    root_entity_update.clone(),
-                                    false,
-                                    EntityWorldType::Main,
-                                ),
-                            });
-                        }
-                        None => {}
+// Detach the item from the pawn for everyone that senses it and update the UI of the owning client.
+fn send_drop_item_updates(
+    drop_slot: &Slot,
+    pickupable_entity: Entity,
+    new_position: Transform,
+    pickuper_sensable_component: &Sensable,
+    pickuper_entity: Entity,
+    handle_to_entity: &Res<HandleToEntity>,
+    net_drop_current_item: &mut EventWriter<NetDropCurrentItem>,
+) {
+    match &drop_slot.slot_attachment {
+        Some(attachment_path) => {
+            // Create detachItem entityUpdate and send it to send_entity_update.rs
+
+            let mut root_entity_update = HashMap::new();
+
+            let mut entity_update = HashMap::new();
+
+            entity_update.insert(
+                "detachItem".to_string(),
+                EntityUpdateData::AttachedItem(
+                    pickupable_entity.to_bits(),
+                    new_position.translation,
+                    new_position.rotation,
+                    new_position.scale,
+                ),
+            );
+
+            root_entity_update.insert(attachment_path.to_string(), entity_update);
+
+            for entity_id in pickuper_sensable_component.sensed_by.iter() {
+                let handle_option = handle_to_entity.inv_map.get(&entity_id);
+
+                match handle_option {
+                    Some(handle) => {
+                        net_drop_current_item.send(NetDropCurrentItem {
+                            handle: *handle,
+                            message: ReliableServerMessage::EntityUpdate(
+                                entity_id.to_bits(),
+                                root_entity_update.clone(),
+                                false,
+                                EntityWorldType::Main,
+                            ),
+                        });
                     }
+                    None => {}
                 }
             }
-            None => {}
         }
+        None => {}
+    }
 
-        match handle_to_entity.inv_map.get(&event.pickuper_entity) {
-            Some(handle) => {
-                // Send UI/Control update to owning client.
-                net_drop_current_item.send(NetDropCurrentItem {
-                    handle: *handle,
-                    message: ReliableServerMessage::DropItem(drop_slot.slot_name.clone()),
-                });
-            }
-            None => {
-                continue;
-            }
+    match handle_to_entity.inv_map.get(&pickuper_entity) {
+        Some(handle) => {
+            // Send UI/Control update to owning client.
+            net_drop_current_item.send(NetDropCurrentItem {
+                handle: *handle,
+                message: ReliableServerMessage::DropItem(drop_slot.slot_name.clone()),
+            });
         }
+        None => {}
     }
 }
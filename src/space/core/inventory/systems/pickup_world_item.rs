@@ -1,7 +1,7 @@
 use bevy_app::{EventReader, EventWriter};
 use bevy_ecs::{
     entity::Entity,
-    prelude::QueryState,
+    prelude::{QueryState, Without},
     system::{Commands, Query, QuerySet, Res},
 };
 use bevy_log::warn;
@@ -20,6 +20,7 @@ use crate::space::core::{
         resources::{GridmapData, GridmapMain},
     },
     health::components::Health,
+    humanoid::components::Humanoid,
     inventory::{
         components::Inventory,
         events::{InputUseWorldItem, NetPickupWorldItem},
@@ -29,12 +30,18 @@ use crate::space::core::{
     pawn::functions::can_reach_entity::{can_reach_entity, REACH_DISTANCE},
     physics::components::{WorldMode, WorldModes},
     rigid_body::{components::RigidBodyLinkTransform, functions::disable_rigidbody},
+    storage::{
+        components::{StorageContainer, Stored},
+        functions::get_holders,
+    },
+    throwables::components::Thrown,
 };
 
 pub fn pickup_world_item<'a>(
     mut use_world_item_events: EventReader<InputUseWorldItem>,
-    mut inventory_entities: Query<&mut Inventory>,
+    mut inventory_entities: Query<&mut Inventory, Without<StorageContainer>>,
     mut inventory_items_query: Query<&mut InventoryItem>,
+    mut storages: Query<(&mut Inventory, &StorageContainer)>,
     health_query: Query<&Health>,
     mut q: QuerySet<(
         QueryState<(
@@ -59,8 +66,18 @@ pub fn pickup_world_item<'a>(
     gridmap_main: Res<GridmapMain>,
     gridmap_data: Res<GridmapData>,
     cell_query: Query<&Cell>,
+    humanoids: Query<&Humanoid>,
 ) {
     for event in use_world_item_events.iter() {
+        match humanoids.get(event.pickuper_entity) {
+            Ok(humanoid_component) => {
+                if !humanoid_component.can_act() {
+                    continue;
+                }
+            }
+            Err(_rr) => {}
+        }
+
         let pickuper_components_option = inventory_entities.get_mut(event.pickuper_entity);
         let pickuper_components;
 
@@ -86,13 +103,24 @@ pub fn pickup_world_item<'a>(
 
         let pickupable_entity = Entity::from_bits(event.pickupable_entity_bits);
 
-        match inventory_items_query.get_component_mut::<InventoryItem>(pickupable_entity) {
+        let mut storage_entity_option = None;
+
+        match inventory_items_query.get(pickupable_entity) {
             Ok(pickupable_inventory_item_component) => {
-                if !matches!(
-                    pickupable_inventory_item_component.in_inventory_of_entity,
-                    None
-                ) {
-                    continue;
+                match pickupable_inventory_item_component.in_inventory_of_entity {
+                    // Items can only be taken out of storages that are open.
+                    Some(holder_entity) => match storages.get(holder_entity) {
+                        Ok((_, storage_component)) => {
+                            if !storage_component.open {
+                                continue;
+                            }
+                            storage_entity_option = Some(holder_entity);
+                        }
+                        Err(_rr) => {
+                            continue;
+                        }
+                    },
+                    None => {}
                 }
             }
             Err(_rr) => {
@@ -101,7 +129,28 @@ pub fn pickup_world_item<'a>(
             }
         }
 
-        let pickupable_position : Vec3 = rigidbody_positions.get(pickupable_entity)
+        // Stored items are reached through whatever holds them out in the world.
+        let mut reach_entity = pickupable_entity;
+
+        match storage_entity_option {
+            Some(_) => match get_holders(pickupable_entity, &inventory_items_query).last() {
+                Some(outer_holder_entity) => {
+                    reach_entity = *outer_holder_entity;
+                }
+                None => {}
+            },
+            None => {}
+        }
+
+        // Storages carried by someone else are off limits.
+        if reach_entity != event.pickuper_entity
+            && reach_entity != pickupable_entity
+            && storages.get(reach_entity).is_err()
+        {
+            continue;
+        }
+
+        let pickupable_position : Vec3 = rigidbody_positions.get(reach_entity)
         .expect("pickup_world_item.rs pickupable_entity was not found in rigidbody_positions query.")
         .position.translation.into();
 
@@ -114,24 +163,26 @@ pub fn pickup_world_item<'a>(
             .translation
             .into();
 
-        if pickupable_position.distance(pickuper_position) > REACH_DISTANCE {
-            continue;
-        }
+        if reach_entity != event.pickuper_entity {
+            if pickupable_position.distance(pickuper_position) > REACH_DISTANCE {
+                continue;
+            }
 
-        if !can_reach_entity(
-            &query_pipeline,
-            &q.q1(),
-            pickuper_position,
-            pickupable_position,
-            &pickupable_entity,
-            &event.pickuper_entity,
-            &health_query,
-            &cell_query,
-            &gridmap_main,
-            &gridmap_data,
-            false,
-        ) {
-            continue;
+            if !can_reach_entity(
+                &query_pipeline,
+                &q.q1(),
+                pickuper_position,
+                pickupable_position,
+                &reach_entity,
+                &event.pickuper_entity,
+                &health_query,
+                &cell_query,
+                &gridmap_main,
+                &gridmap_data,
+                false,
+            ) {
+                continue;
+            }
         }
 
         let pickupable_entities_components;
@@ -177,6 +228,21 @@ pub fn pickup_world_item<'a>(
 
         pickupable_inventory_item_component.in_inventory_of_entity = Some(event.pickuper_entity);
         pickup_slot.slot_item = Some(pickupable_entity);
+
+        match storage_entity_option {
+            Some(storage_entity) => match storages.get_mut(storage_entity) {
+                Ok((mut storage_inventory, _)) => {
+                    for slot in storage_inventory.slots.iter_mut() {
+                        if slot.slot_item == Some(pickupable_entity) {
+                            slot.slot_item = None;
+                        }
+                    }
+                    commands.entity(pickupable_entity).remove::<Stored>();
+                }
+                Err(_rr) => {}
+            },
+            None => {}
+        }
         pickupable_world_mode.mode = WorldModes::Held;
 
         commands
@@ -186,6 +252,9 @@ pub fn pickup_world_item<'a>(
                 ..Default::default()
             });
 
+        // Catching a throwable in the air stops it from hitting anything.
+        commands.entity(pickupable_entity).remove::<Thrown>();
+
         match handle_to_entity.inv_map.get(&event.pickuper_entity) {
             Some(handle) => {
                 net_pickup_world_item.send(NetPickupWorldItem {
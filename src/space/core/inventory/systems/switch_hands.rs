@@ -3,6 +3,7 @@ use bevy_ecs::system::{Query, Res};
 
 use crate::space::core::{
     connected_player::resources::HandleToEntity,
+    humanoid::components::Humanoid,
     inventory::{
         components::Inventory,
         events::{InputSwitchHands, NetSwitchHands},
@@ -15,8 +16,18 @@ pub fn switch_hands(
     mut inventory_entities: Query<&mut Inventory>,
     mut net_switch_hands: EventWriter<NetSwitchHands>,
     handle_to_entity: Res<HandleToEntity>,
+    humanoids: Query<&Humanoid>,
 ) {
     for event in switch_hands_events.iter() {
+        match humanoids.get(event.entity) {
+            Ok(humanoid_component) => {
+                if !humanoid_component.can_act() {
+                    continue;
+                }
+            }
+            Err(_rr) => {}
+        }
+
         let hand_switcher_components_option = inventory_entities.get_mut(event.entity);
         let hand_switcher_components;
 
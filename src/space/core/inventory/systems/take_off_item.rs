@@ -4,6 +4,7 @@ use bevy_ecs::system::{Query, Res};
 use crate::space::core::{
     connected_player::resources::HandleToEntity,
     entity::components::EntityData,
+    humanoid::components::Humanoid,
     inventory::{
         components::Inventory,
         events::{InputTakeOffItem, NetTakeOffItem},
@@ -19,8 +20,18 @@ pub fn take_off_item(
     mut pickupable_entities: Query<(&InventoryItem, &mut WorldMode, &EntityData)>,
     mut net_takeoff_item: EventWriter<NetTakeOffItem>,
     handle_to_entity: Res<HandleToEntity>,
+    humanoids: Query<&Humanoid>,
 ) {
     for event in take_off_item_events.iter() {
+        match humanoids.get(event.entity) {
+            Ok(humanoid_component) => {
+                if !humanoid_component.can_act() {
+                    continue;
+                }
+            }
+            Err(_rr) => {}
+        }
+
         let carrier_components_option = inventory_entities.get_mut(event.entity);
         let carrier_components;
 
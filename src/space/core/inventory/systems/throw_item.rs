@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use bevy_app::{EventReader, EventWriter};
+use bevy_core::Timer;
 use bevy_ecs::{
     entity::Entity,
     system::{Commands, Query, Res, ResMut},
@@ -9,7 +10,7 @@ use bevy_log::warn;
 use bevy_math::Vec3;
 
 use bevy_rapier3d::prelude::{
-    ColliderFlagsComponent, RigidBodyActivationComponent, RigidBodyForcesComponent,
+    ActiveEvents, ColliderFlagsComponent, RigidBodyActivationComponent, RigidBodyForcesComponent,
     RigidBodyMassPropsComponent, RigidBodyPositionComponent, RigidBodyVelocityComponent,
 };
 use rand::Rng;
@@ -39,6 +40,7 @@ use crate::space::{
         rigid_body::{components::RigidBodyLinkTransform, functions::enable_rigidbody},
         sensable::components::Sensable,
         sfx::{components::sfx_auto_destroy, resources::SfxAutoDestroyTimers},
+        throwables::components::{LitFuse, Throwable, Thrown},
     },
     entities::sfx::actions::{throw1_sfx::Throw1SfxBundle, throw2_sfx::Throw2SfxBundle},
 };
@@ -67,6 +69,7 @@ pub fn throw_item(
         &RigidBodyMassPropsComponent,
     )>,
     mut rigidbody_velocites: Query<&mut RigidBodyVelocityComponent>,
+    throwables: Query<(&Throwable, Option<&LitFuse>)>,
     mut commands: Commands,
     mut net_throw_item: EventWriter<NetThrowItem>,
     gridmap_main: Res<GridmapMain>,
@@ -86,6 +89,10 @@ pub fn throw_item(
             }
         }
 
+        if !pickuper_components.3.can_act() {
+            continue;
+        }
+
         let mut pickuper_inventory = pickuper_components.0;
 
         let pickup_slot = &pickuper_inventory.active_slot.clone();
@@ -132,6 +139,31 @@ pub fn throw_item(
 
         pickupable_rigidbody_link_transform_component.active = false;
 
+        match throwables.get(pickupable_entity) {
+            Ok((throwable_component, lit_fuse_option)) => {
+                // Contact events tell the throwable what it hit.
+                pickupable_rigidbody_collider_flags.active_events = ActiveEvents::CONTACT_EVENTS;
+
+                commands.entity(pickupable_entity).insert(Thrown {
+                    thrower_entity: event.entity,
+                    thrower_name: character_examinable_component.name.get_name().to_string(),
+                });
+
+                match throwable_component.fuse {
+                    Some(fuse) => {
+                        if lit_fuse_option.is_none() {
+                            commands.entity(pickupable_entity).insert(LitFuse {
+                                timer: Timer::from_seconds(fuse, false),
+                                thrower_option: Some(event.entity),
+                            });
+                        }
+                    }
+                    None => {}
+                }
+            }
+            Err(_rr) => {}
+        }
+
         commands
             .entity(pickupable_entity)
             .remove::<RigidBodyLinkTransform>();
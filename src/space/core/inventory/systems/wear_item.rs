@@ -4,6 +4,7 @@ use bevy_ecs::system::{Query, Res};
 use crate::space::core::{
     connected_player::resources::HandleToEntity,
     entity::components::EntityData,
+    humanoid::components::Humanoid,
     inventory::{
         components::Inventory,
         events::{InputWearItem, NetWearItem},
@@ -19,8 +20,18 @@ pub fn wear_item(
     mut wearable_entities: Query<(&InventoryItem, &mut WorldMode, &EntityData)>,
     mut net_wear_item: EventWriter<NetWearItem>,
     handle_to_entity: Res<HandleToEntity>,
+    humanoids: Query<&Humanoid>,
 ) {
     for event in wear_item_events.iter() {
+        match humanoids.get(event.wearer_entity) {
+            Ok(humanoid_component) => {
+                if !humanoid_component.can_act() {
+                    continue;
+                }
+            }
+            Err(_rr) => {}
+        }
+
         let wearer_components_option = inventory_entities.get_mut(event.wearer_entity);
         let wearer_components;
 
@@ -21,7 +21,10 @@ use crate::space::{
             swing4_sfx::Swing4SfxBundle,
         },
         combat::{
-            block1_sfx::Block1SfxBundle, block2_sfx::Block2SfxBundle, block3_sfx::Block3SfxBundle,
+            ballistic_hit1_sfx::BallisticHit1Bundle,
+            ballistic_ricochet1_sfx::BallisticRicochet1Bundle,
+            ballistic_shot1_sfx::BallisticShot1Bundle, block1_sfx::Block1SfxBundle,
+            block2_sfx::Block2SfxBundle, block3_sfx::Block3SfxBundle,
             laser_light_block1_sfx::LaserLightBlock1Bundle,
             laser_light_block2_sfx::LaserLightBlock2Bundle,
             laser_light_block3_sfx::LaserLightBlock3Bundle,
@@ -43,11 +46,13 @@ pub struct InventoryItem {
     pub attachment_transforms: HashMap<String, Transform>,
     pub drop_transform: Transform,
     pub slot_type: SlotType,
+    pub size: ItemSize,
     pub is_attached_when_worn: bool,
     pub combat_standard_animation: CombatStandardAnimation,
     pub combat_attack_animation: CombatAttackAnimation,
     pub combat_type: CombatType,
     pub combat_melee_damage_model: DamageModel,
+    pub combat_melee_attack: MeleeAttack,
     pub combat_projectile_damage_model: Option<DamageModel>,
     pub combat_melee_sound_set: CombatSoundSet,
     pub combat_projectile_sound_set: Option<CombatSoundSet>,
@@ -67,6 +72,9 @@ impl InventoryItem {
             "striked".to_string(),
         ]
     }
+    pub fn get_default_ballistic_words() -> Vec<String> {
+        vec!["shot".to_string(), "hit".to_string()]
+    }
     pub fn get_default_laser_words() -> Vec<String> {
         vec!["shot".to_string(), "hit".to_string(), "beamed".to_string()]
     }
@@ -88,6 +96,16 @@ impl InventoryItem {
     }
 }
 
+// Storage containers only accept items up to a certain size.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+pub enum ItemSize {
+    Tiny,
+    Small,
+    Normal,
+    Bulky,
+    Huge,
+}
+
 pub enum CombatStandardAnimation {
     StandardStance,
     PistolStance,
@@ -98,17 +116,71 @@ pub enum CombatAttackAnimation {
     PistolShot,
 }
 
+// The shape of the area a melee attack reaches into.
+#[derive(Clone, Debug)]
+pub enum MeleeShape {
+    // A narrow line straight ahead.
+    Thrust,
+    // Everything within an arc of this many degrees in front of the attacker.
+    Sweep(f32),
+}
+
+#[derive(Clone, Debug)]
+pub struct MeleeAttack {
+    pub shape: MeleeShape,
+    pub reach: f32,
+    // Seconds until the attacker can attack again.
+    pub cooldown: f32,
+}
+
+impl Default for MeleeAttack {
+    fn default() -> Self {
+        Self {
+            shape: MeleeShape::Thrust,
+            reach: 1.2,
+            cooldown: 0.433,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum CombatType {
     MeleeDirect,
     Projectile(ProjectileType),
 }
 
-#[allow(dead_code)]
 #[derive(Clone, Debug)]
 pub enum ProjectileType {
     Laser((f32, f32, f32, f32), f32, f32, f32),
-    Ballistic,
+    Ballistic(BallisticProjectile),
+}
+
+#[derive(Clone, Debug)]
+pub struct BallisticProjectile {
+    pub range: f32,
+    // In meters per second, hitscan without one.
+    pub speed_option: Option<f32>,
+    // Past this distance damage falls off until it reaches min_damage_factor at full range.
+    pub falloff_start: f32,
+    pub min_damage_factor: f32,
+    // Names of the cells the projectile can pass through.
+    pub penetrable_cells: Vec<String>,
+    // Fraction of the damage that is left after each penetration.
+    pub penetration_damage_factor: f32,
+    pub max_penetrations: u8,
+}
+
+impl BallisticProjectile {
+    pub fn get_falloff_factor(&self, distance: f32) -> f32 {
+        if distance <= self.falloff_start || self.range <= self.falloff_start {
+            return 1.;
+        }
+
+        let progress =
+            ((distance - self.falloff_start) / (self.range - self.falloff_start)).min(1.);
+
+        1. - (1. - self.min_damage_factor) * progress
+    }
 }
 
 #[derive(Clone)]
@@ -142,6 +214,14 @@ impl CombatSoundSet {
         }
     }
 
+    pub fn default_ballistic_projectiles() -> Self {
+        Self {
+            default: vec![CombatSound::BallisticShot1],
+            hit_soft: vec![CombatSound::BallisticHit1],
+            hit_blocked: vec![CombatSound::BallisticRicochet1],
+        }
+    }
+
     pub fn spawn_default_sfx(
         &self,
         commands: &mut Commands,
@@ -205,6 +285,13 @@ impl CombatSoundSet {
                     .id();
                 sfx_auto_destroy(sfx_entity, auto_destroy_timers);
             }
+            CombatSound::BallisticShot1 => {
+                let sfx_entity = commands
+                    .spawn()
+                    .insert_bundle(BallisticShot1Bundle::new(transform))
+                    .id();
+                sfx_auto_destroy(sfx_entity, auto_destroy_timers);
+            }
             _ => (),
         }
     }
@@ -272,6 +359,13 @@ impl CombatSoundSet {
                     .id();
                 sfx_auto_destroy(sfx_entity, auto_destroy_timers);
             }
+            CombatSound::BallisticHit1 => {
+                let sfx_entity = commands
+                    .spawn()
+                    .insert_bundle(BallisticHit1Bundle::new(transform))
+                    .id();
+                sfx_auto_destroy(sfx_entity, auto_destroy_timers);
+            }
             _ => (),
         }
     }
@@ -332,6 +426,13 @@ impl CombatSoundSet {
                     .id();
                 sfx_auto_destroy(sfx_entity, auto_destroy_timers);
             }
+            CombatSound::BallisticRicochet1 => {
+                let sfx_entity = commands
+                    .spawn()
+                    .insert_bundle(BallisticRicochet1Bundle::new(transform))
+                    .id();
+                sfx_auto_destroy(sfx_entity, auto_destroy_timers);
+            }
             _ => (),
         }
     }
@@ -362,6 +463,9 @@ pub enum CombatSound {
     LaserLightHit2,
     LaserLightHit3,
     LaserLightHit4,
+    BallisticShot1,
+    BallisticHit1,
+    BallisticRicochet1,
 }
 
 impl Default for CombatSoundSet {
@@ -0,0 +1,26 @@
+use bevy_core::Timer;
+use bevy_ecs::{entity::Entity, prelude::Component};
+
+// Bandages, ointments and medkits. Heals the limb of the patient that the treater is targetting.
+#[derive(Component)]
+pub struct MedicalItem {
+    pub brute_heal: f32,
+    pub burn_heal: f32,
+    pub toxin_heal: f32,
+    // In seconds.
+    pub treatment_time: f32,
+    pub uses: u8,
+}
+
+// Holding one in the active hand adds per-limb damage to the examine text of humanoids.
+#[derive(Component)]
+pub struct MedicalScanner;
+
+// Attached to the treater for as long as a treatment is in progress.
+#[derive(Component)]
+pub struct Treating {
+    pub patient: Entity,
+    pub medical_item: Entity,
+    pub limb: String,
+    pub timer: Timer,
+}
@@ -0,0 +1,7 @@
+use bevy_ecs::entity::Entity;
+
+pub struct InputTreatPatient {
+    pub treater: Entity,
+    pub medical_item: Entity,
+    pub patient: Entity,
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use bevy_ecs::{entity::Entity, system::Query};
+
+use crate::space::core::{
+    chat::functions::{FURTHER_ITALIC_FONT, HEALTHY_COLOR, UNHEALTHY_COLOR},
+    data_link::components::DataLink,
+    entity::{components::EntityData, resources::EntityDataResource},
+    gridmap::resources::CellData,
+    health::components::{HumanoidHealth, LimbWound, BLOOD_VOLUME, HUMANOID_LIMBS},
+    inventory::components::Inventory,
+    networking::resources::GridMapType,
+    pawn::functions::can_reach_entity::REACH_DISTANCE,
+    tab_actions::components::TabAction,
+};
+
+// Tab actions the medical item offers while it is in the active hand.
+pub fn get_medical_tab_actions(medical_item_entity: Entity) -> Vec<TabAction> {
+    vec![TabAction {
+        id: "medicaltreat".to_string(),
+        text: "Treat".to_string(),
+        tab_list_priority: 61,
+        prerequisite_check: Arc::new(medical_treat_action),
+        belonging_entity: Some(medical_item_entity),
+    }]
+}
+
+pub fn medical_treat_action(
+    self_tab_entity_option: Option<Entity>,
+    entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    distance: f32,
+    inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    match (self_tab_entity_option, entity_id_bits_option) {
+        (Some(self_tab_entity), Some(target_entity_bits)) => {
+            distance < REACH_DISTANCE
+                && self_tab_entity.to_bits() != target_entity_bits
+                && inventory_component.has_item(self_tab_entity)
+        }
+        _ => false,
+    }
+}
+
+pub fn get_limb_display_name(limb: &str) -> String {
+    limb.replace("_", " ")
+}
+
+// The per-limb damage readout of a medical scanner.
+pub fn get_scan_report(humanoid_health: &HumanoidHealth, blood: f32) -> String {
+    let mut report = "[font=".to_owned() + FURTHER_ITALIC_FONT + "]Scan results:";
+
+    for limb in HUMANOID_LIMBS {
+        let (brute, burn, toxin) = humanoid_health.get_limb_damage(limb).unwrap();
+
+        let color;
+
+        if brute + burn + toxin > 0. {
+            color = UNHEALTHY_COLOR;
+        } else {
+            color = HEALTHY_COLOR;
+        }
+
+        report = report
+            + "\n[color="
+            + color
+            + "]"
+            + &get_limb_display_name(limb)
+            + ": "
+            + &format!("{:.0}", brute)
+            + " brute, "
+            + &format!("{:.0}", burn)
+            + " burn, "
+            + &format!("{:.0}", toxin)
+            + " toxin"
+            + get_wound_text(humanoid_health.get_limb_wound(limb))
+            + "[/color]";
+    }
+
+    let blood_color;
+
+    if blood < BLOOD_VOLUME {
+        blood_color = UNHEALTHY_COLOR;
+    } else {
+        blood_color = HEALTHY_COLOR;
+    }
+
+    report = report
+        + "\n[color="
+        + blood_color
+        + "]blood: "
+        + &format!("{:.0}/{:.0}", blood, BLOOD_VOLUME)
+        + "[/color]";
+
+    report + "[/font]"
+}
+
+fn get_wound_text(wound: LimbWound) -> &'static str {
+    match wound {
+        LimbWound::None => "",
+        LimbWound::Bleeding => " (bleeding)",
+        LimbWound::Broken => " (broken)",
+    }
+}
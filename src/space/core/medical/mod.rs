@@ -0,0 +1,21 @@
+use bevy_app::{App, Plugin};
+
+use self::{
+    events::InputTreatPatient,
+    systems::{start_treatment::start_treatment, treatment::treatment},
+};
+
+pub mod components;
+pub mod events;
+pub mod functions;
+pub mod systems;
+
+pub struct MedicalPlugin;
+
+impl Plugin for MedicalPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<InputTreatPatient>()
+            .add_system(start_treatment)
+            .add_system(treatment);
+    }
+}
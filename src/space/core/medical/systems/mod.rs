@@ -0,0 +1,2 @@
+pub mod start_treatment;
+pub mod treatment;
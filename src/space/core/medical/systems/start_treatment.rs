@@ -0,0 +1,169 @@
+use bevy_app::{EventReader, EventWriter};
+use bevy_core::Timer;
+use bevy_ecs::system::{Commands, Query, Res};
+use bevy_math::Vec3;
+use bevy_rapier3d::prelude::RigidBodyPositionComponent;
+
+use crate::space::core::{
+    chat::{events::NetChatMessage, functions::new_personal_message},
+    connected_player::resources::HandleToEntity,
+    examinable::components::Examinable,
+    health::components::{Health, HealthContainer},
+    inventory::components::Inventory,
+    medical::{
+        components::{MedicalItem, Treating},
+        events::InputTreatPatient,
+        functions::get_limb_display_name,
+    },
+    pawn::{components::ControllerInput, functions::can_reach_entity::REACH_DISTANCE},
+};
+
+pub fn start_treatment(
+    mut treat_events: EventReader<InputTreatPatient>,
+    mut commands: Commands,
+    treaters: Query<(&Inventory, &ControllerInput)>,
+    rigid_body_positions: Query<&RigidBodyPositionComponent>,
+    medical_items: Query<&MedicalItem>,
+    healths: Query<&Health>,
+    examinables: Query<&Examinable>,
+    handle_to_entity: Res<HandleToEntity>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+) {
+    for event in treat_events.iter() {
+        let limb;
+
+        match treaters.get(event.treater) {
+            Ok((inventory_component, controller_input_component)) => {
+                if inventory_component.get_active_slot_entity() != Some(event.medical_item) {
+                    continue;
+                }
+                limb = controller_input_component.targetted_limb.clone();
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let medical_item_component;
+
+        match medical_items.get(event.medical_item) {
+            Ok(c) => {
+                medical_item_component = c;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        match (
+            rigid_body_positions.get(event.treater),
+            rigid_body_positions.get(event.patient),
+        ) {
+            (Ok(treater_position), Ok(patient_position)) => {
+                let treater_translation: Vec3 = treater_position.position.translation.into();
+                let patient_translation: Vec3 = patient_position.position.translation.into();
+                if treater_translation.distance(patient_translation) > REACH_DISTANCE {
+                    continue;
+                }
+            }
+            _ => {
+                continue;
+            }
+        }
+
+        match healths.get(event.patient) {
+            Ok(health_component) => match &health_component.health_container {
+                HealthContainer::Humanoid(humanoid_health) => {
+                    if humanoid_health.get_limb_damage(&limb).is_none() {
+                        continue;
+                    }
+                }
+                HealthContainer::Entity(_) => {
+                    new_personal_message(
+                        &mut net_chat_message,
+                        &handle_to_entity,
+                        event.treater,
+                        "That cannot be treated.",
+                    );
+                    continue;
+                }
+            },
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let item_name;
+
+        match examinables.get(event.medical_item) {
+            Ok(examinable_component) => {
+                item_name = examinable_component.name.get_name().to_string();
+            }
+            Err(_rr) => {
+                item_name = "it".to_string();
+            }
+        }
+
+        let limb_name = get_limb_display_name(&limb);
+
+        if event.patient == event.treater {
+            new_personal_message(
+                &mut net_chat_message,
+                &handle_to_entity,
+                event.treater,
+                &("You start treating your ".to_string()
+                    + &limb_name
+                    + " with the "
+                    + &item_name
+                    + "."),
+            );
+        } else {
+            let patient_name;
+            let treater_name;
+
+            match examinables.get(event.patient) {
+                Ok(examinable_component) => {
+                    patient_name = examinable_component.name.get_name().to_string();
+                }
+                Err(_rr) => {
+                    patient_name = "them".to_string();
+                }
+            }
+            match examinables.get(event.treater) {
+                Ok(examinable_component) => {
+                    treater_name = examinable_component.name.get_name().to_string();
+                }
+                Err(_rr) => {
+                    treater_name = "Someone".to_string();
+                }
+            }
+
+            new_personal_message(
+                &mut net_chat_message,
+                &handle_to_entity,
+                event.treater,
+                &("You start treating the ".to_string()
+                    + &limb_name
+                    + " of "
+                    + &patient_name
+                    + " with the "
+                    + &item_name
+                    + "."),
+            );
+            new_personal_message(
+                &mut net_chat_message,
+                &handle_to_entity,
+                event.patient,
+                &(treater_name + " starts treating your " + &limb_name + "."),
+            );
+        }
+
+        // Replaces any treatment that was still in progress.
+        commands.entity(event.treater).insert(Treating {
+            patient: event.patient,
+            medical_item: event.medical_item,
+            limb,
+            timer: Timer::from_seconds(medical_item_component.treatment_time, false),
+        });
+    }
+}
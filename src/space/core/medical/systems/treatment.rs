@@ -0,0 +1,165 @@
+use bevy_app::EventWriter;
+use bevy_core::Time;
+use bevy_ecs::{
+    entity::Entity,
+    system::{Commands, Query, Res},
+};
+use bevy_math::Vec3;
+use bevy_rapier3d::prelude::RigidBodyPositionComponent;
+
+use crate::space::core::{
+    chat::{events::NetChatMessage, functions::new_personal_message},
+    connected_player::resources::HandleToEntity,
+    entity::events::NetUnloadEntity,
+    health::components::{Health, HealthContainer},
+    humanoid::components::Humanoid,
+    inventory::{components::Inventory, events::NetDropCurrentItem},
+    medical::{
+        components::{MedicalItem, Treating},
+        functions::get_limb_display_name,
+    },
+    networking::resources::ReliableServerMessage,
+    pawn::functions::can_reach_entity::REACH_DISTANCE,
+    sensable::components::Sensable,
+};
+
+pub fn treatment(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut treaters: Query<(Entity, &mut Treating, &mut Inventory)>,
+    rigid_body_positions: Query<&RigidBodyPositionComponent>,
+    mut medical_items: Query<(&mut MedicalItem, &mut Sensable)>,
+    mut healths: Query<&mut Health>,
+    handle_to_entity: Res<HandleToEntity>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+    mut net_unload_entity: EventWriter<NetUnloadEntity>,
+    mut net_drop_current_item: EventWriter<NetDropCurrentItem>,
+    humanoids: Query<&Humanoid>,
+) {
+    for (treater_entity, mut treating_component, mut inventory_component) in treaters.iter_mut() {
+        // Switching hands, walking away or being unable to act interrupts the treatment.
+        let mut interrupted =
+            inventory_component.get_active_slot_entity() != Some(treating_component.medical_item);
+
+        match humanoids.get(treater_entity) {
+            Ok(humanoid_component) => {
+                if !humanoid_component.can_act() {
+                    interrupted = true;
+                }
+            }
+            Err(_rr) => {}
+        }
+
+        match (
+            rigid_body_positions.get(treater_entity),
+            rigid_body_positions.get(treating_component.patient),
+        ) {
+            (Ok(treater_position), Ok(patient_position)) => {
+                let treater_translation: Vec3 = treater_position.position.translation.into();
+                let patient_translation: Vec3 = patient_position.position.translation.into();
+                if treater_translation.distance(patient_translation) > REACH_DISTANCE {
+                    interrupted = true;
+                }
+            }
+            _ => {
+                interrupted = true;
+            }
+        }
+
+        if interrupted {
+            new_personal_message(
+                &mut net_chat_message,
+                &handle_to_entity,
+                treater_entity,
+                "You stop the treatment.",
+            );
+            commands.entity(treater_entity).remove::<Treating>();
+            continue;
+        }
+
+        treating_component.timer.tick(time.delta());
+
+        if !treating_component.timer.finished() {
+            continue;
+        }
+
+        commands.entity(treater_entity).remove::<Treating>();
+
+        let mut medical_item_component;
+        let mut medical_item_sensable_component;
+
+        match medical_items.get_mut(treating_component.medical_item) {
+            Ok((m, s)) => {
+                medical_item_component = m;
+                medical_item_sensable_component = s;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        match healths.get_mut(treating_component.patient) {
+            Ok(mut health_component) => match &mut health_component.health_container {
+                HealthContainer::Humanoid(humanoid_health) => {
+                    humanoid_health.heal_limb(
+                        &treating_component.limb,
+                        medical_item_component.brute_heal,
+                        medical_item_component.burn_heal,
+                        medical_item_component.toxin_heal,
+                    );
+                }
+                HealthContainer::Entity(_) => {
+                    continue;
+                }
+            },
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        new_personal_message(
+            &mut net_chat_message,
+            &handle_to_entity,
+            treater_entity,
+            &("You finish treating the ".to_string()
+                + &get_limb_display_name(&treating_component.limb)
+                + "."),
+        );
+
+        medical_item_component.uses = medical_item_component.uses.saturating_sub(1);
+
+        if medical_item_component.uses > 0 {
+            continue;
+        }
+
+        // Used up, take it out of the hand and get rid of it.
+        let active_slot_name = inventory_component.active_slot.clone();
+        inventory_component
+            .get_slot_mut(&active_slot_name)
+            .slot_item = None;
+
+        match handle_to_entity.inv_map.get(&treater_entity) {
+            Some(handle) => {
+                net_drop_current_item.send(NetDropCurrentItem {
+                    handle: *handle,
+                    message: ReliableServerMessage::DropItem(active_slot_name),
+                });
+            }
+            None => {}
+        }
+
+        medical_item_sensable_component.despawn(
+            treating_component.medical_item,
+            &mut net_unload_entity,
+            &handle_to_entity,
+        );
+        commands.entity(treating_component.medical_item).despawn();
+
+        new_personal_message(
+            &mut net_chat_message,
+            &handle_to_entity,
+            treater_entity,
+            "You used it up.",
+        );
+    }
+}
@@ -4,9 +4,11 @@ use bevy_log::info;
 
 use super::StartupLabels;
 
+pub mod ammunition;
 pub mod asana;
 pub mod atmospherics;
 pub mod chat;
+pub mod chemistry;
 pub mod combat;
 pub mod configuration;
 pub mod connected_player;
@@ -14,22 +16,29 @@ pub mod console_commands;
 pub mod data_link;
 pub mod entity;
 pub mod examinable;
+pub mod explosions;
 pub mod gridmap;
 pub mod health;
 pub mod humanoid;
 pub mod inventory;
 pub mod inventory_item;
 pub mod map;
+pub mod medical;
 pub mod networking;
 pub mod pawn;
 pub mod physics;
+pub mod pipe_networks;
+pub mod prototypes;
 pub mod rigid_body;
 pub mod sensable;
 pub mod senser;
 pub mod sfx;
 pub mod static_body;
+pub mod storage;
 pub mod tab_actions;
+pub mod throwables;
 pub mod world_environment;
+pub mod world_save;
 
 pub fn server_is_live() {
     info!("Live.");
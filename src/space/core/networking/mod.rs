@@ -54,14 +54,14 @@ use super::{
     connected_player::{
         components::ConnectedPlayer,
         events::{
-            InputAltItemAttack, InputAttackCell, InputAttackEntity, InputBuildGraphics,
+            InputAltItemAttack, InputAttackCell, InputAttackEntity, InputBlock, InputBuildGraphics,
             InputExamineEntity, InputExamineMap, InputMouseAction, InputMouseDirectionUpdate,
             InputMovementInput, InputSceneReady, InputSelectBodyPart, InputSprinting,
             InputTabDataEntity, InputTabDataMap, InputToggleAutoMove, InputToggleCombatMode,
-            InputUIInput, InputUIInputTransmitText, InputUserName, NetDoneBoarding,
-            NetExamineEntity, NetOnBoarding, NetOnNewPlayerConnection, NetOnSetupUI, NetOnSpawning,
-            NetSendServerTime, NetSendWorldEnvironment, NetTabData, NetUIInputTransmitData,
-            NetUpdatePlayerCount, NetUserName, TextTreeInputSelection,
+            InputUIInput, InputUIInputTransmitText, InputUserName, InputViewTimeStamp,
+            NetDoneBoarding, NetExamineEntity, NetOnBoarding, NetOnNewPlayerConnection,
+            NetOnSetupUI, NetOnSpawning, NetSendServerTime, NetSendWorldEnvironment, NetTabData,
+            NetUIInputTransmitData, NetUpdatePlayerCount, NetUserName, TextTreeInputSelection,
         },
         functions::{
             on_new_player_connection::on_new_player_connection,
@@ -141,6 +141,8 @@ pub fn messages_outgoing(
         EventWriter<TextTreeInputSelection>,
         EventWriter<InputMapRequestDisplayModes>,
         EventWriter<InputMap>,
+        EventWriter<InputViewTimeStamp>,
+        EventWriter<InputBlock>,
     ),
 
     handle_to_entity: Res<HandleToEntity>,
@@ -185,6 +187,8 @@ pub fn messages_outgoing(
         mut text_tree_input_selection,
         mut input_map_request_display_modes,
         mut input_map_view_range,
+        mut input_view_time_stamp,
+        mut input_block,
     ) = tuple2;
 
     for (handle, connection) in net.connections.iter_mut() {
@@ -314,6 +318,9 @@ pub fn messages_outgoing(
                             drop_current_item.send(InputDropCurrentItem {
                                 pickuper_entity: *player_entity,
                                 input_position_option: position_option,
+                                storage_entity_option: None,
+                                slot_name_option: None,
+                                involuntary: false,
                             });
                         }
                         None => {
@@ -603,6 +610,19 @@ pub fn messages_outgoing(
                         }
                     }
                 }
+                ReliableClientMessage::BlockInput(is_blocking) => {
+                    match handle_to_entity.map.get(handle) {
+                        Some(player_entity) => {
+                            input_block.send(InputBlock {
+                                entity: *player_entity,
+                                is_blocking,
+                            });
+                        }
+                        None => {
+                            warn!("Couldn't find player_entity belonging to BlockInput sender handle.");
+                        }
+                    }
+                }
             }
         }
 
@@ -650,6 +670,19 @@ pub fn messages_outgoing(
                         }
                     }
                 }
+                UnreliableClientMessage::ViewTimeStamp(time_stamp) => {
+                    match handle_to_entity.map.get(handle) {
+                        Some(player_entity) => {
+                            input_view_time_stamp.send(InputViewTimeStamp {
+                                entity: *player_entity,
+                                time_stamp,
+                            });
+                        }
+                        None => {
+                            warn!("Couldn't find player_entity belonging to ViewTimeStamp sender handle.");
+                        }
+                    }
+                }
             }
         }
 
@@ -54,6 +54,7 @@ pub enum ReliableClientMessage {
     MapChangeDisplayMode(String),
     MapRequestDisplayModes,
     MapCameraPosition(Vec2),
+    BlockInput(bool),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -74,11 +75,11 @@ pub enum UIInputAction {
     Pressed,
 }
 
-#[allow(dead_code)]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum NetProjectileType {
     Laser((f32, f32, f32, f32), f32, f32, Vec3, Vec3),
-    Ballistic,
+    // Start, end, speed (zero for hitscan) and the position and normal of every impact decal.
+    Ballistic(Vec3, Vec3, f32, Vec<(Vec3, Vec3)>),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -208,6 +209,8 @@ pub enum UnreliableClientMessage {
     MouseDirectionUpdate(f32, u64),
     MapViewRange(f32),
     MapOverlayMouseHoverCell(i16, i16),
+    // The server time stamp of the world state the client is currently showing.
+    ViewTimeStamp(u64),
 }
 
 pub enum NetMessageType {
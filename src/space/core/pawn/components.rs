@@ -52,7 +52,7 @@ pub fn facing_direction_to_direction(direction: &FacingDirection) -> Vec2 {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq)]
 pub enum SpaceJobsEnum {
     Security,
     Control,
@@ -149,6 +149,8 @@ pub struct ControllerInput {
     pub combat_targetted_entity: Option<Entity>,
     pub combat_targetted_cell: Option<Vec3Int>,
     pub alt_attack_mode: bool,
+    // Holding up a guard against melee attacks while in combat mode.
+    pub blocking: bool,
     pub pending_direction: Option<FacingDirection>,
 }
 
@@ -164,6 +166,7 @@ impl Default for ControllerInput {
             combat_targetted_entity: None,
             combat_targetted_cell: None,
             alt_attack_mode: false,
+            blocking: false,
             pending_direction: None,
         }
     }
@@ -78,8 +78,22 @@ fn process_physics_event(
     let collider1_entity = collider1_handle.entity();
     let collider2_entity = collider2_handle.entity();
 
-    let collider1_components = interesting_entities_query.get(collider1_entity).unwrap();
-    let collider2_components = interesting_entities_query.get(collider2_entity).unwrap();
+    // Thrown items report contacts with walls and other colliders that are of no interest here.
+    let collider1_components;
+    let collider2_components;
+
+    match (
+        interesting_entities_query.get(collider1_entity),
+        interesting_entities_query.get(collider2_entity),
+    ) {
+        (Ok(components1), Ok(components2)) => {
+            collider1_components = components1;
+            collider2_components = components2;
+        }
+        _ => {
+            return;
+        }
+    }
 
     if matches!(collider1_components.1.entity_group, EntityGroup::None) == false {
         first_collider_group = collider1_components.1.entity_group;
@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+
+use bevy_log::warn;
+
+use crate::space::core::{
+    atmospherics::systems::rigidbody_forces_atmospherics::AdjacentTileDirection,
+    gridmap::{
+        resources::{GridmapData, GridmapPipes, Vec3Int},
+        GridDirectionRotations,
+    },
+};
+
+use super::resources::{PipeCell, PipeDevice, PipeNetwork, PipeNetworksResource};
+
+pub fn get_adjacent_cell_id(cell_id: &Vec3Int, direction: &AdjacentTileDirection) -> Vec3Int {
+    let mut adjacent_cell_id = cell_id.clone();
+
+    match direction {
+        AdjacentTileDirection::Up => {
+            adjacent_cell_id.z += 1;
+        }
+        AdjacentTileDirection::Down => {
+            adjacent_cell_id.z -= 1;
+        }
+        AdjacentTileDirection::Left => {
+            adjacent_cell_id.x -= 1;
+        }
+        AdjacentTileDirection::Right => {
+            adjacent_cell_id.x += 1;
+        }
+    }
+
+    adjacent_cell_id
+}
+
+// Pumps are oriented like wall cells, they face the direction their orientation rotates them to.
+pub fn orientation_to_direction(orientation: i64) -> Option<AdjacentTileDirection> {
+    for (direction, rotation) in GridDirectionRotations::default_wall_rotations().data {
+        if rotation as i64 == orientation {
+            return Some(direction);
+        }
+    }
+    None
+}
+
+pub fn opposite_direction(direction: &AdjacentTileDirection) -> AdjacentTileDirection {
+    match direction {
+        AdjacentTileDirection::Up => AdjacentTileDirection::Down,
+        AdjacentTileDirection::Down => AdjacentTileDirection::Up,
+        AdjacentTileDirection::Left => AdjacentTileDirection::Right,
+        AdjacentTileDirection::Right => AdjacentTileDirection::Left,
+    }
+}
+
+pub fn mix_temperature(
+    amount_a: f32,
+    temperature_a: f32,
+    amount_b: f32,
+    temperature_b: f32,
+) -> f32 {
+    let total_amount = amount_a + amount_b;
+    if total_amount <= 0. {
+        return temperature_a;
+    }
+    (amount_a * temperature_a + amount_b * temperature_b) / total_amount
+}
+
+// Add and remove pipe cells so they match the pipes gridmap layer, returns whether anything changed.
+// Cells that stay keep their state, like whether a valve is open.
+pub fn sync_pipe_cells(
+    pipe_networks: &mut PipeNetworksResource,
+    gridmap_pipes: &GridmapPipes,
+    gridmap_data: &GridmapData,
+) -> bool {
+    let mut changed = false;
+
+    let removed_cell_ids: Vec<Vec3Int> = pipe_networks
+        .cells
+        .keys()
+        .filter(|cell_id| !gridmap_pipes.data.contains_key(cell_id))
+        .cloned()
+        .collect();
+
+    for cell_id in removed_cell_ids {
+        pipe_networks.cells.remove(&cell_id);
+        changed = true;
+    }
+
+    for (cell_id, cell_data) in gridmap_pipes.data.iter() {
+        if pipe_networks.cells.contains_key(cell_id) {
+            continue;
+        }
+
+        let name = gridmap_data.pipes_id_name_map.get(&cell_data.item).unwrap();
+
+        let device;
+
+        match PipeDevice::from_name(name) {
+            Some(d) => {
+                device = d;
+            }
+            None => {
+                warn!("Unknown pipe device {}", name);
+                continue;
+            }
+        }
+
+        pipe_networks.cells.insert(
+            *cell_id,
+            PipeCell {
+                device,
+                orientation: cell_data.orientation,
+                network: None,
+            },
+        );
+        changed = true;
+    }
+
+    changed
+}
+
+// Flood fill all pipe cells into connected networks, the gas of the previous networks
+// gets spread over the new networks by the volume each cell takes up.
+// Gas is only lost when a cell that leaves its network has no network next to it.
+pub fn rebuild_pipe_networks(pipe_networks: &mut PipeNetworksResource) {
+    let mut cell_gas: HashMap<Vec3Int, (f32, f32)> = HashMap::new();
+
+    for network in pipe_networks.networks.iter() {
+        for cell_id in network.cells.iter() {
+            let cell_volume;
+
+            match pipe_networks.cells.get(cell_id) {
+                Some(pipe_cell) => {
+                    cell_volume = pipe_cell.device.get_volume();
+                }
+                None => {
+                    // The cell got removed, its share of the gas leaks out.
+                    continue;
+                }
+            }
+
+            cell_gas.insert(
+                *cell_id,
+                (
+                    network.amount * (cell_volume / network.volume),
+                    network.temperature,
+                ),
+            );
+        }
+    }
+
+    for pipe_cell in pipe_networks.cells.values_mut() {
+        pipe_cell.network = None;
+    }
+
+    let mut new_networks: Vec<PipeNetwork> = vec![];
+
+    let cell_ids: Vec<Vec3Int> = pipe_networks.cells.keys().cloned().collect();
+
+    for start_cell_id in cell_ids {
+        let start_cell = pipe_networks.cells.get(&start_cell_id).unwrap();
+        if start_cell.network.is_some() || !start_cell.device.joins_network() {
+            continue;
+        }
+
+        let network_i = new_networks.len();
+        let mut network = PipeNetwork::default();
+        let mut total_heat = 0.;
+
+        let mut open_cells = vec![start_cell_id];
+
+        while let Some(cell_id) = open_cells.pop() {
+            let pipe_cell = pipe_networks.cells.get_mut(&cell_id).unwrap();
+            if pipe_cell.network.is_some() {
+                continue;
+            }
+            pipe_cell.network = Some(network_i);
+
+            network.cells.push(cell_id);
+            network.volume += pipe_cell.device.get_volume();
+
+            match cell_gas.get(&cell_id) {
+                Some((amount, temperature)) => {
+                    network.amount += amount;
+                    total_heat += amount * temperature;
+                }
+                None => {}
+            }
+
+            let mut adjacent_cell_ids = vec![];
+
+            for direction in [
+                AdjacentTileDirection::Up,
+                AdjacentTileDirection::Down,
+                AdjacentTileDirection::Left,
+                AdjacentTileDirection::Right,
+            ] {
+                adjacent_cell_ids.push(get_adjacent_cell_id(&cell_id, &direction));
+            }
+
+            let is_connector = matches!(pipe_cell.device, PipeDevice::Connector);
+
+            let mut above = cell_id.clone();
+            above.y += 1;
+            let mut below = cell_id.clone();
+            below.y -= 1;
+
+            for vertical_cell_id in [above, below] {
+                match pipe_networks.cells.get(&vertical_cell_id) {
+                    Some(vertical_cell) => {
+                        // Layers only join through a connector on either end.
+                        if is_connector || matches!(vertical_cell.device, PipeDevice::Connector) {
+                            adjacent_cell_ids.push(vertical_cell_id);
+                        }
+                    }
+                    None => {}
+                }
+            }
+
+            for adjacent_cell_id in adjacent_cell_ids {
+                match pipe_networks.cells.get(&adjacent_cell_id) {
+                    Some(adjacent_cell) => {
+                        if adjacent_cell.network.is_none() && adjacent_cell.device.joins_network() {
+                            open_cells.push(adjacent_cell_id);
+                        }
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        if network.amount > 0. {
+            network.temperature = total_heat / network.amount;
+        }
+
+        new_networks.push(network);
+    }
+
+    // Cells that no longer join a network, like valves that just closed, hand their gas to a neighbouring one.
+    for (cell_id, (amount, temperature)) in cell_gas.iter() {
+        if *amount <= 0. {
+            continue;
+        }
+
+        match pipe_networks.cells.get(cell_id) {
+            Some(pipe_cell) => {
+                if pipe_cell.network.is_some() {
+                    continue;
+                }
+            }
+            None => {}
+        }
+
+        let mut neighbour_network_option = None;
+
+        for direction in [
+            AdjacentTileDirection::Up,
+            AdjacentTileDirection::Down,
+            AdjacentTileDirection::Left,
+            AdjacentTileDirection::Right,
+        ] {
+            match pipe_networks.get_network(&get_adjacent_cell_id(cell_id, &direction)) {
+                Some(network_i) => {
+                    neighbour_network_option = Some(network_i);
+                    break;
+                }
+                None => {}
+            }
+        }
+
+        match neighbour_network_option {
+            Some(network_i) => {
+                let network = &mut new_networks[network_i];
+                network.temperature =
+                    mix_temperature(network.amount, network.temperature, *amount, *temperature);
+                network.amount += amount;
+            }
+            None => {}
+        }
+    }
+
+    pipe_networks.networks = new_networks;
+    pipe_networks.rebuild = false;
+}
@@ -0,0 +1,101 @@
+pub mod functions;
+pub mod resources;
+pub mod systems;
+
+use bevy_app::{App, Plugin};
+use bevy_core::FixedTimestep;
+use bevy_ecs::{
+    schedule::{ParallelSystemDescriptorCoercion, SystemSet},
+    system::{Res, ResMut},
+};
+use bevy_log::info;
+
+use crate::space::{
+    core::{
+        atmospherics::{resources::DEFAULT_INTERNAL_AMOUNT, systems::diffusion::DIFFUSION_STEP},
+        gridmap::resources::{GridmapData, GridmapPipes},
+    },
+    AtmosphericsLabels, StartupLabels,
+};
+
+use self::{
+    functions::{rebuild_pipe_networks, sync_pipe_cells},
+    resources::{PipeDevice, PipeNetworksResource},
+    systems::{
+        pipe_network_devices::pipe_network_devices, rebuild_pipe_networks::pipe_networks_rebuild,
+        sync_pipe_cells::pipe_cells_sync,
+    },
+};
+
+// Tanks start out filled to this many times the default ship pressure.
+const TANK_FILL_FACTOR: f32 = 20.;
+// Liters of gas a single atmospherics cell holds.
+const ATMOSPHERICS_CELL_VOLUME: f32 = 2000.;
+
+pub fn startup_pipe_networks(
+    mut pipe_networks: ResMut<PipeNetworksResource>,
+    gridmap_pipes: Res<GridmapPipes>,
+    gridmap_data: Res<GridmapData>,
+) {
+    sync_pipe_cells(&mut pipe_networks, &gridmap_pipes, &gridmap_data);
+
+    rebuild_pipe_networks(&mut pipe_networks);
+
+    // Fill the fresh networks, tanks hold the supply that vents feed the ship with.
+    let mut fills = vec![];
+
+    for network in pipe_networks.networks.iter() {
+        let mut amount = 0.;
+        for cell_id in network.cells.iter() {
+            let device = pipe_networks.cells.get(cell_id).unwrap().device;
+            let cell_amount =
+                (device.get_volume() / ATMOSPHERICS_CELL_VOLUME) * DEFAULT_INTERNAL_AMOUNT;
+            match device {
+                PipeDevice::Tank => {
+                    amount += cell_amount * TANK_FILL_FACTOR;
+                }
+                _ => {
+                    amount += cell_amount;
+                }
+            }
+        }
+        fills.push(amount);
+    }
+
+    for (network, amount) in pipe_networks.networks.iter_mut().zip(fills) {
+        network.amount = amount;
+    }
+
+    info!(
+        "Loaded {} pipe cells into {} pipe networks.",
+        pipe_networks.cells.len(),
+        pipe_networks.networks.len()
+    );
+}
+
+pub struct PipeNetworksPlugin;
+
+impl Plugin for PipeNetworksPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PipeNetworksResource>()
+            .add_startup_system(
+                startup_pipe_networks
+                    .after(StartupLabels::InitAtmospherics)
+                    .after(StartupLabels::BuildGridmap),
+            )
+            .add_system(pipe_cells_sync.before(AtmosphericsLabels::PipeNetworksRebuild))
+            .add_system_set(
+                SystemSet::new()
+                    .with_run_criteria(FixedTimestep::step(1. / DIFFUSION_STEP))
+                    .with_system(
+                        pipe_networks_rebuild.label(AtmosphericsLabels::PipeNetworksRebuild),
+                    )
+                    .with_system(
+                        pipe_network_devices
+                            .after(AtmosphericsLabels::PipeNetworksRebuild)
+                            .after(AtmosphericsLabels::Diffusion)
+                            .before(AtmosphericsLabels::Effects),
+                    ),
+            );
+    }
+}
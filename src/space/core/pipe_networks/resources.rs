@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::{FromWorld, World};
+
+use crate::space::core::{
+    atmospherics::resources::CELCIUS_KELVIN_OFFSET, gridmap::resources::Vec3Int,
+};
+
+// Liters of gas a single pipe segment holds.
+pub const PIPE_CELL_VOLUME: f32 = 200.;
+// Liters of gas a tank device holds.
+pub const TANK_VOLUME: f32 = 20000.;
+
+pub struct PipeNetworksResource {
+    pub cells: HashMap<Vec3Int, PipeCell>,
+    pub networks: Vec<PipeNetwork>,
+    pub rebuild: bool,
+}
+
+impl FromWorld for PipeNetworksResource {
+    fn from_world(_world: &mut World) -> Self {
+        PipeNetworksResource {
+            cells: HashMap::new(),
+            networks: vec![],
+            rebuild: false,
+        }
+    }
+}
+
+impl PipeNetworksResource {
+    pub fn set_valve(&mut self, cell_id: &Vec3Int, open: bool) -> bool {
+        match self.cells.get_mut(cell_id) {
+            Some(pipe_cell) => match pipe_cell.device {
+                PipeDevice::Valve(_) => {
+                    pipe_cell.device = PipeDevice::Valve(open);
+                    self.rebuild = true;
+                    true
+                }
+                _ => false,
+            },
+            None => false,
+        }
+    }
+
+    pub fn get_network(&self, cell_id: &Vec3Int) -> Option<usize> {
+        match self.cells.get(cell_id) {
+            Some(pipe_cell) => pipe_cell.network,
+            None => None,
+        }
+    }
+}
+
+pub struct PipeCell {
+    pub device: PipeDevice,
+    pub orientation: i64,
+    pub network: Option<usize>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PipeDevice {
+    Pipe,
+    // Moves gas from the network behind it to the network it faces.
+    Pump,
+    // Joins both sides into one network while open.
+    Valve(bool),
+    // Joins the pipe layers directly above and below it.
+    Connector,
+    Tank,
+    // Pushes gas from its network into the room.
+    Vent,
+    // Draws gas from the room into its network.
+    Scrubber,
+}
+
+impl PipeDevice {
+    pub fn from_name(name: &str) -> Option<PipeDevice> {
+        match name {
+            "pipe" => Some(PipeDevice::Pipe),
+            "pump" => Some(PipeDevice::Pump),
+            "valve" => Some(PipeDevice::Valve(true)),
+            "connector" => Some(PipeDevice::Connector),
+            "tank" => Some(PipeDevice::Tank),
+            "vent" => Some(PipeDevice::Vent),
+            "scrubber" => Some(PipeDevice::Scrubber),
+            _ => None,
+        }
+    }
+
+    pub fn joins_network(&self) -> bool {
+        match self {
+            PipeDevice::Pump => false,
+            PipeDevice::Valve(open) => *open,
+            _ => true,
+        }
+    }
+
+    pub fn get_volume(&self) -> f32 {
+        match self {
+            PipeDevice::Tank => TANK_VOLUME,
+            _ => PIPE_CELL_VOLUME,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PipeNetwork {
+    pub cells: Vec<Vec3Int>,
+    //Liters
+    pub volume: f32,
+    //Mol
+    pub amount: f32,
+    //Kelvin
+    pub temperature: f32,
+}
+
+impl Default for PipeNetwork {
+    fn default() -> Self {
+        Self {
+            cells: vec![],
+            volume: 0.,
+            amount: 0.,
+            temperature: 20. + CELCIUS_KELVIN_OFFSET,
+        }
+    }
+}
+
+impl PipeNetwork {
+    pub fn get_pressure(&self) -> f32 {
+        // Return kpa
+        if self.volume <= 0. {
+            return 0.;
+        }
+        (((self.amount * 0.08206 * self.temperature) / self.volume) * 101325.) / 1000.
+    }
+}
@@ -0,0 +1,3 @@
+pub mod pipe_network_devices;
+pub mod rebuild_pipe_networks;
+pub mod sync_pipe_cells;
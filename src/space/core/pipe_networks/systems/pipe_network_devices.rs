@@ -0,0 +1,229 @@
+use bevy_app::EventWriter;
+use bevy_ecs::system::ResMut;
+use rand::Rng;
+
+use crate::space::core::{
+    atmospherics::{
+        events::IgniteAtmospherics,
+        functions::{get_atmos_id, get_atmos_index},
+        resources::{AtmosphericsResource, DEFAULT_OXIDISER_RATIO},
+        systems::rigidbody_forces_atmospherics::AdjacentTileDirection,
+    },
+    gridmap::resources::{Vec2Int, Vec3Int},
+    pipe_networks::{
+        functions::{
+            get_adjacent_cell_id, mix_temperature, opposite_direction, orientation_to_direction,
+        },
+        resources::{PipeDevice, PipeNetworksResource},
+    },
+};
+
+// Mol moved per device per diffusion step.
+const PUMP_RATE: f32 = 4.;
+const VENT_RATE: f32 = 2.;
+const SCRUBBER_RATE: f32 = 2.;
+
+// kPa
+const PUMP_TARGET_PRESSURE: f32 = 4500.;
+const VENT_TARGET_PRESSURE: f32 = 101.325;
+const SCRUBBER_TARGET_PRESSURE: f32 = 101.325;
+const SCRUBBER_MAX_NETWORK_PRESSURE: f32 = 4500.;
+
+// Chance per diffusion step that a running pump sparks and ignites flammable gas around it.
+const PUMP_SPARK_CHANCE: f32 = 0.01;
+
+pub fn pipe_network_devices(
+    mut pipe_networks: ResMut<PipeNetworksResource>,
+    mut atmospherics_resource: ResMut<AtmosphericsResource>,
+    mut ignite_atmospherics: EventWriter<IgniteAtmospherics>,
+) {
+    let mut rng = rand::thread_rng();
+
+    let mut pumps = vec![];
+    let mut vents = vec![];
+
+    for (cell_id, pipe_cell) in pipe_networks.cells.iter() {
+        match pipe_cell.device {
+            PipeDevice::Pump => {
+                pumps.push((*cell_id, pipe_cell.orientation));
+            }
+            PipeDevice::Vent | PipeDevice::Scrubber => match pipe_cell.network {
+                Some(network_i) => {
+                    vents.push((*cell_id, pipe_cell.device, network_i));
+                }
+                None => {}
+            },
+            _ => {}
+        }
+    }
+
+    for (cell_id, orientation) in pumps {
+        let output_direction;
+
+        match orientation_to_direction(orientation) {
+            Some(d) => {
+                output_direction = d;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let input_network_option = pipe_networks.get_network(&get_adjacent_cell_id(
+            &cell_id,
+            &opposite_direction(&output_direction),
+        ));
+        let output_network_option =
+            pipe_networks.get_network(&get_adjacent_cell_id(&cell_id, &output_direction));
+
+        let input_network_i;
+        let output_network_i;
+
+        match (input_network_option, output_network_option) {
+            (Some(i), Some(o)) => {
+                if i == o {
+                    continue;
+                }
+                input_network_i = i;
+                output_network_i = o;
+            }
+            _ => {
+                continue;
+            }
+        }
+
+        if pipe_networks.networks[output_network_i].get_pressure() >= PUMP_TARGET_PRESSURE {
+            continue;
+        }
+
+        let input_network = &mut pipe_networks.networks[input_network_i];
+        let moved_amount = PUMP_RATE.min(input_network.amount);
+        if moved_amount <= 0. {
+            continue;
+        }
+        input_network.amount -= moved_amount;
+        let moved_temperature = input_network.temperature;
+
+        let output_network = &mut pipe_networks.networks[output_network_i];
+        output_network.temperature = mix_temperature(
+            output_network.amount,
+            output_network.temperature,
+            moved_amount,
+            moved_temperature,
+        );
+        output_network.amount += moved_amount;
+
+        if rng.gen::<f32>() < PUMP_SPARK_CHANCE {
+            match get_vent_atmos_index(&cell_id, &atmospherics_resource) {
+                Some(atmos_index) => {
+                    ignite_atmospherics.send(IgniteAtmospherics {
+                        cell_id: get_atmos_id(atmos_index),
+                    });
+                }
+                None => {}
+            }
+        }
+    }
+
+    for (cell_id, device, network_i) in vents {
+        let atmos_index;
+
+        match get_vent_atmos_index(&cell_id, &atmospherics_resource) {
+            Some(i) => {
+                atmos_index = i;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let atmospherics = atmospherics_resource
+            .atmospherics
+            .get_mut(atmos_index)
+            .unwrap();
+        let network = &mut pipe_networks.networks[network_i];
+
+        match device {
+            PipeDevice::Vent => {
+                if atmospherics.get_pressure() >= VENT_TARGET_PRESSURE {
+                    continue;
+                }
+                let moved_amount = VENT_RATE.min(network.amount);
+                if moved_amount <= 0. {
+                    continue;
+                }
+                network.amount -= moved_amount;
+                atmospherics.temperature = mix_temperature(
+                    atmospherics.amount,
+                    atmospherics.temperature,
+                    moved_amount,
+                    network.temperature,
+                );
+                atmospherics.amount += moved_amount;
+                // Pipe networks carry breathable air.
+                atmospherics.oxidiser += moved_amount * DEFAULT_OXIDISER_RATIO;
+            }
+            PipeDevice::Scrubber => {
+                if atmospherics.get_pressure() <= SCRUBBER_TARGET_PRESSURE
+                    || network.get_pressure() >= SCRUBBER_MAX_NETWORK_PRESSURE
+                {
+                    continue;
+                }
+                let moved_amount = SCRUBBER_RATE.min(atmospherics.amount);
+                if moved_amount <= 0. {
+                    continue;
+                }
+                atmospherics.scale_amount(atmospherics.amount - moved_amount);
+                network.temperature = mix_temperature(
+                    network.amount,
+                    network.temperature,
+                    moved_amount,
+                    atmospherics.temperature,
+                );
+                network.amount += moved_amount;
+            }
+            _ => {}
+        }
+    }
+}
+
+// Vents sit below walls as often as not, they exchange with the first open cell next to them.
+fn get_vent_atmos_index(
+    cell_id: &Vec3Int,
+    atmospherics_resource: &AtmosphericsResource,
+) -> Option<usize> {
+    let mut candidates = vec![Vec2Int {
+        x: cell_id.x,
+        y: cell_id.z,
+    }];
+
+    for direction in [
+        AdjacentTileDirection::Up,
+        AdjacentTileDirection::Down,
+        AdjacentTileDirection::Left,
+        AdjacentTileDirection::Right,
+    ] {
+        let adjacent_cell_id = get_adjacent_cell_id(cell_id, &direction);
+        candidates.push(Vec2Int {
+            x: adjacent_cell_id.x,
+            y: adjacent_cell_id.z,
+        });
+    }
+
+    for candidate in candidates {
+        if AtmosphericsResource::is_id_out_of_range(candidate) {
+            continue;
+        }
+        let atmos_index = get_atmos_index(candidate);
+        if !atmospherics_resource
+            .atmospherics
+            .get(atmos_index)
+            .unwrap()
+            .blocked
+        {
+            return Some(atmos_index);
+        }
+    }
+
+    None
+}
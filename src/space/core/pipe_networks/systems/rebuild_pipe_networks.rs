@@ -0,0 +1,13 @@
+use bevy_ecs::system::ResMut;
+
+use crate::space::core::pipe_networks::{
+    functions::rebuild_pipe_networks, resources::PipeNetworksResource,
+};
+
+pub fn pipe_networks_rebuild(mut pipe_networks: ResMut<PipeNetworksResource>) {
+    if !pipe_networks.rebuild {
+        return;
+    }
+
+    rebuild_pipe_networks(&mut pipe_networks);
+}
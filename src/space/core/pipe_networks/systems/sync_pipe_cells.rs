@@ -0,0 +1,20 @@
+use bevy_ecs::system::{Res, ResMut};
+
+use crate::space::core::{
+    gridmap::resources::{GridmapData, GridmapPipes},
+    pipe_networks::{functions::sync_pipe_cells, resources::PipeNetworksResource},
+};
+
+pub fn pipe_cells_sync(
+    gridmap_pipes: Res<GridmapPipes>,
+    gridmap_data: Res<GridmapData>,
+    mut pipe_networks: ResMut<PipeNetworksResource>,
+) {
+    if !gridmap_pipes.is_changed() {
+        return;
+    }
+
+    if sync_pipe_cells(&mut pipe_networks, &gridmap_pipes, &gridmap_data) {
+        pipe_networks.rebuild = true;
+    }
+}
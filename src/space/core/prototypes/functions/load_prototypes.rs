@@ -0,0 +1,403 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use serde_json::{Map, Value};
+
+use crate::space::core::{
+    health::components::HUMANOID_LIMBS, prototypes::resources::EntityPrototype,
+};
+
+// Load every .json file in the directory, each file holds an array of prototypes.
+pub fn load_prototypes(directory: &Path) -> Result<Vec<EntityPrototype>, String> {
+    let mut paths = vec![];
+
+    match fs::read_dir(directory) {
+        Ok(entries) => {
+            for entry in entries {
+                match entry {
+                    Ok(e) => {
+                        let path = e.path();
+                        if path
+                            .extension()
+                            .map_or(false, |extension| extension == "json")
+                        {
+                            paths.push(path);
+                        }
+                    }
+                    Err(rr) => {
+                        return Err(rr.to_string());
+                    }
+                }
+            }
+        }
+        Err(rr) => {
+            return Err(rr.to_string());
+        }
+    }
+
+    // Keep the order in which prototypes get their ids the same between runs.
+    paths.sort();
+
+    let mut raw_prototypes: Vec<(String, Map<String, Value>)> = vec![];
+
+    for path in paths {
+        let raw_json;
+
+        match fs::read_to_string(&path) {
+            Ok(s) => {
+                raw_json = s;
+            }
+            Err(rr) => {
+                return Err(path.to_string_lossy().to_string() + ": " + &rr.to_string());
+            }
+        }
+
+        let values: Vec<Value>;
+
+        match serde_json::from_str(&raw_json) {
+            Ok(v) => {
+                values = v;
+            }
+            Err(rr) => {
+                return Err(path.to_string_lossy().to_string() + ": " + &rr.to_string());
+            }
+        }
+
+        for value in values {
+            match value {
+                Value::Object(object) => match object.get("name") {
+                    Some(Value::String(name)) => {
+                        if raw_prototypes.iter().any(|(n, _)| n == name) {
+                            return Err("Duplicate prototype ".to_string() + name);
+                        }
+                        raw_prototypes.push((name.to_string(), object.clone()));
+                    }
+                    _ => {
+                        return Err(
+                            path.to_string_lossy().to_string() + ": prototype without a name."
+                        );
+                    }
+                },
+                _ => {
+                    return Err(
+                        path.to_string_lossy().to_string() + ": prototype is not an object."
+                    );
+                }
+            }
+        }
+    }
+
+    let raw_by_name: HashMap<String, Map<String, Value>> = raw_prototypes.iter().cloned().collect();
+
+    let mut prototypes = vec![];
+
+    for (name, _) in raw_prototypes.iter() {
+        let resolved = resolve_inheritance(name, &raw_by_name, &mut vec![])?;
+
+        let prototype: EntityPrototype;
+
+        match serde_json::from_value(Value::Object(resolved)) {
+            Ok(p) => {
+                prototype = p;
+            }
+            Err(rr) => {
+                return Err(name.to_string() + ": " + &rr.to_string());
+            }
+        }
+
+        if !prototype.is_abstract {
+            validate_prototype(&prototype)?;
+        }
+
+        prototypes.push(prototype);
+    }
+
+    Ok(prototypes)
+}
+
+// Merge all parents into the prototype, the closest definition of a field wins.
+fn resolve_inheritance(
+    name: &str,
+    raw_by_name: &HashMap<String, Map<String, Value>>,
+    visited: &mut Vec<String>,
+) -> Result<Map<String, Value>, String> {
+    if visited.contains(&name.to_string()) {
+        return Err("Prototype inheritance cycle at ".to_string() + name);
+    }
+    visited.push(name.to_string());
+
+    let raw;
+
+    match raw_by_name.get(name) {
+        Some(r) => {
+            raw = r;
+        }
+        None => {
+            return Err("Unknown parent prototype ".to_string() + name);
+        }
+    }
+
+    let mut resolved;
+
+    match raw.get("parent") {
+        Some(Value::String(parent_name)) => {
+            resolved = resolve_inheritance(parent_name, raw_by_name, visited)?;
+        }
+        _ => {
+            resolved = Map::new();
+        }
+    }
+
+    merge_objects(&mut resolved, raw);
+
+    // Being abstract is not inherited.
+    if !raw.contains_key("abstract") {
+        resolved.remove("abstract");
+    }
+
+    Ok(resolved)
+}
+
+fn merge_objects(base: &mut Map<String, Value>, overrides: &Map<String, Value>) {
+    for (key, value) in overrides.iter() {
+        let merged;
+
+        match (base.get_mut(key), value) {
+            (Some(Value::Object(base_object)), Value::Object(override_object)) => {
+                merge_objects(base_object, override_object);
+                merged = true;
+            }
+            _ => {
+                merged = false;
+            }
+        }
+
+        if !merged {
+            base.insert(key.to_string(), value.clone());
+        }
+    }
+}
+
+// Catch typos in the data files at startup rather than when the entity first spawns.
+fn validate_prototype(prototype: &EntityPrototype) -> Result<(), String> {
+    match &prototype.inventory_item {
+        Some(inventory_item) => {
+            let prefix = prototype.name.to_string() + ": ";
+            inventory_item
+                .get_slot_type()
+                .map_err(|rr| prefix.clone() + &rr)?;
+            inventory_item
+                .get_size()
+                .map_err(|rr| prefix.clone() + &rr)?;
+            inventory_item
+                .get_combat_standard_animation()
+                .map_err(|rr| prefix.clone() + &rr)?;
+            inventory_item
+                .get_combat_attack_animation()
+                .map_err(|rr| prefix.clone() + &rr)?;
+            inventory_item
+                .melee_damage
+                .get_damage_model()
+                .map_err(|rr| prefix.clone() + &rr)?;
+            inventory_item
+                .melee_attack
+                .get_melee_attack()
+                .map_err(|rr| prefix.clone() + &rr)?;
+            match &inventory_item.laser {
+                Some(laser) => {
+                    laser
+                        .damage
+                        .get_damage_model()
+                        .map_err(|rr| prefix.clone() + &rr)?;
+                }
+                None => {}
+            }
+            match &inventory_item.ballistic {
+                Some(ballistic) => {
+                    if inventory_item.laser.is_some() {
+                        return Err(
+                            prefix + "an item cannot have both a laser and a ballistic section"
+                        );
+                    }
+                    ballistic
+                        .get_ballistic_projectile()
+                        .map_err(|rr| prefix.clone() + &rr)?;
+                    ballistic
+                        .damage
+                        .get_damage_model()
+                        .map_err(|rr| prefix.clone() + &rr)?;
+                }
+                None => {}
+            }
+            match &inventory_item.accuracy {
+                Some(accuracy) => {
+                    if inventory_item.laser.is_none() && inventory_item.ballistic.is_none() {
+                        return Err(prefix + "accuracy requires a laser or ballistic section");
+                    }
+                    for value in [
+                        accuracy.base_spread,
+                        accuracy.recoil_per_shot,
+                        accuracy.max_recoil,
+                        accuracy.recovery,
+                        accuracy.moving_penalty,
+                        accuracy.sprinting_penalty,
+                    ] {
+                        if value < 0. {
+                            return Err(prefix + "accuracy values cannot be negative");
+                        }
+                    }
+                }
+                None => {}
+            }
+        }
+        None => {}
+    }
+
+    match &prototype.storage {
+        Some(storage) => {
+            storage
+                .get_max_item_size()
+                .map_err(|rr| prototype.name.to_string() + ": " + &rr)?;
+            if storage.slots == 0 {
+                return Err(prototype.name.to_string() + ": storage needs at least one slot");
+            }
+        }
+        None => {}
+    }
+
+    match &prototype.reagent_container {
+        Some(reagent_container) => {
+            let prefix = prototype.name.to_string() + ": ";
+            reagent_container
+                .get_transfer_method()
+                .map_err(|rr| prefix.clone() + &rr)?;
+            if reagent_container.capacity <= 0. || reagent_container.transfer_amount <= 0. {
+                return Err(
+                    prefix + "reagent_container capacity and transfer_amount have to be above zero"
+                );
+            }
+            if reagent_container.reagents.values().sum::<f32>() > reagent_container.capacity {
+                return Err(prefix + "reagent_container holds more reagents than its capacity");
+            }
+            if prototype.inventory_item.is_none() {
+                return Err(prefix + "reagent_container requires an inventory_item section");
+            }
+        }
+        None => {}
+    }
+
+    match &prototype.medical_item {
+        Some(medical_item) => {
+            let prefix = prototype.name.to_string() + ": ";
+            if medical_item.uses == 0 {
+                return Err(prefix + "medical_item needs at least one use");
+            }
+            if medical_item.treatment_time < 0. {
+                return Err(prefix + "medical_item treatment_time cannot be negative");
+            }
+            if prototype.inventory_item.is_none() {
+                return Err(prefix + "medical_item requires an inventory_item section");
+            }
+        }
+        None => {}
+    }
+
+    if prototype.medical_scanner && prototype.inventory_item.is_none() {
+        return Err(
+            prototype.name.to_string() + ": medical_scanner requires an inventory_item section"
+        );
+    }
+
+    if !prototype.armor.is_empty() {
+        let prefix = prototype.name.to_string() + ": ";
+        if prototype.inventory_item.is_none() {
+            return Err(prefix + "armor requires an inventory_item section");
+        }
+        for (limb, limb_armor) in prototype.armor.iter() {
+            if !HUMANOID_LIMBS.contains(&limb.as_str()) {
+                return Err(prefix + "armor has unknown limb " + limb);
+            }
+            for value in [limb_armor.brute, limb_armor.burn, limb_armor.toxin] {
+                if value < 0. || value > 100. {
+                    return Err(prefix + "armor of " + limb + " must be between 0 and 100");
+                }
+            }
+        }
+    }
+
+    match &prototype.ammo {
+        Some(ammo) => {
+            let prefix = prototype.name.to_string() + ": ";
+            if ammo.ammo_type.is_empty() {
+                return Err(prefix + "ammo needs an ammo_type");
+            }
+            if ammo.capacity == 0 {
+                return Err(prefix + "ammo capacity has to be above zero");
+            }
+            if ammo.get_amount() > ammo.capacity {
+                return Err(prefix + "ammo amount is more than its capacity");
+            }
+            match &prototype.inventory_item {
+                Some(inventory_item) => match ammo.shot_cost {
+                    Some(shot_cost) => {
+                        if shot_cost == 0 {
+                            return Err(prefix + "ammo shot_cost has to be above zero");
+                        }
+                        if inventory_item.laser.is_none() && inventory_item.ballistic.is_none() {
+                            return Err(
+                                prefix + "ammo shot_cost requires a laser or ballistic section"
+                            );
+                        }
+                    }
+                    None => {}
+                },
+                None => {
+                    return Err(prefix + "ammo requires an inventory_item section");
+                }
+            }
+        }
+        None => {}
+    }
+
+    match &prototype.recharger {
+        Some(recharger) => {
+            let prefix = prototype.name.to_string() + ": ";
+            if recharger.ammo_type.is_empty() {
+                return Err(prefix + "recharger needs an ammo_type");
+            }
+            if recharger.charge_per_second == 0 {
+                return Err(prefix + "recharger charge_per_second has to be above zero");
+            }
+            // Both would need the tab actions of the entity to themselves.
+            if prototype.inventory_item.is_some() || prototype.storage.is_some() {
+                return Err(prefix + "recharger cannot be combined with inventory_item or storage");
+            }
+        }
+        None => {}
+    }
+
+    match &prototype.laser_surface {
+        Some(laser_surface) => {
+            let prefix = prototype.name.to_string() + ": ";
+            laser_surface
+                .get_laser_surface()
+                .map_err(|rr| prefix.clone() + &rr)?;
+            if laser_surface.energy_factor < 0. || laser_surface.energy_factor > 1. {
+                return Err(prefix + "laser_surface energy_factor must be between 0 and 1");
+            }
+        }
+        None => {}
+    }
+
+    match &prototype.throwable {
+        Some(throwable) => {
+            let prefix = prototype.name.to_string() + ": ";
+            if prototype.inventory_item.is_none() {
+                return Err(prefix + "throwable requires an inventory_item section");
+            }
+            throwable.get_throwable().map_err(|rr| prefix + &rr)?;
+        }
+        None => {}
+    }
+
+    Ok(())
+}
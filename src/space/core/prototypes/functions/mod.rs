@@ -0,0 +1,2 @@
+pub mod load_prototypes;
+pub mod spawn_prototype;
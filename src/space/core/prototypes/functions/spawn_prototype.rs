@@ -0,0 +1,497 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use bevy_ecs::system::Commands;
+use bevy_math::Vec3;
+use bevy_rapier3d::prelude::{
+    CoefficientCombineRule, ColliderBundle, ColliderFlags, ColliderMaterial, ColliderPosition,
+    ColliderShape, InteractionGroups, RigidBodyActivation, RigidBodyBundle, RigidBodyForces,
+    RigidBodyType,
+};
+
+use crate::space::core::{
+    ammunition::{
+        components::{AmmoContainer, AmmoUser, Recharger},
+        functions::{get_ammo_user_tab_actions, get_recharger_tab_actions},
+    },
+    chemistry::{
+        components::ReagentHolder, functions::reagent_tab_actions::get_reagent_tab_actions,
+    },
+    combat::components::LaserSurfaceKind,
+    entity::{
+        components::{EntityData, EntityUpdates, Showcase},
+        events::NetShowcase,
+        functions::transform_to_isometry::transform_to_isometry,
+        resources::{SpawnContext, SpawnFunction, SpawnedEntity},
+    },
+    examinable::components::{Examinable, RichName},
+    health::components::{Armor, Health, RaegentContainer},
+    inventory::components::Inventory,
+    inventory_item::components::{CombatSoundSet, CombatType, InventoryItem, ProjectileType},
+    medical::{
+        components::{MedicalItem, MedicalScanner},
+        functions::get_medical_tab_actions,
+    },
+    networking::resources::ReliableServerMessage,
+    physics::{
+        components::{WorldMode, WorldModes},
+        functions::{get_bit_masks, ColliderGroup},
+    },
+    prototypes::resources::EntityPrototype,
+    rigid_body::components::{
+        CachedBroadcastTransform, DefaultTransform, RigidBodyData, RigidBodyDisabled,
+        RigidBodyLinkTransform,
+    },
+    sensable::components::Sensable,
+    storage::{
+        components::StorageContainer,
+        functions::{get_storage_slots, get_storage_tab_actions},
+    },
+    tab_actions::components::TabActions,
+};
+
+pub fn get_prototype_spawn_function(prototype: Arc<EntityPrototype>) -> SpawnFunction {
+    Box::new(as_spawn_function(move |commands, spawn_context| {
+        spawn_prototype(&prototype, commands, spawn_context)
+    }))
+}
+
+// Lets the compiler infer the closure arguments from the spawn function signature.
+fn as_spawn_function<F>(spawn_function: F) -> F
+where
+    F: Fn(&mut Commands, SpawnContext) -> SpawnedEntity + Sync + Send,
+{
+    spawn_function
+}
+
+pub fn spawn_prototype(
+    prototype: &EntityPrototype,
+    commands: &mut Commands,
+    spawn_context: SpawnContext,
+) -> SpawnedEntity {
+    let held = spawn_context.holder_entity.is_some();
+
+    let mut this_transform;
+    let default_transform = prototype.default_transform.to_transform();
+
+    if held {
+        this_transform = default_transform;
+    } else {
+        this_transform = spawn_context.transform;
+    }
+
+    if spawn_context.correct_transform {
+        this_transform.rotation = default_transform.rotation;
+    }
+
+    let rigid_body_component;
+    let collider_component;
+
+    let half_extents = prototype.collider.half_extents;
+    let shape = ColliderShape::cuboid(half_extents[0], half_extents[1], half_extents[2]);
+
+    let collider_position: ColliderPosition = Vec3::from(prototype.collider.position).into();
+    let friction = prototype.friction;
+    let friction_combine_rule = CoefficientCombineRule::Multiply;
+
+    if held == false {
+        rigid_body_component = RigidBodyBundle {
+            body_type: RigidBodyType::Dynamic.into(),
+            position: transform_to_isometry(this_transform).into(),
+            ..Default::default()
+        };
+
+        let masks = get_bit_masks(ColliderGroup::Standard);
+
+        collider_component = ColliderBundle {
+            shape: shape.into(),
+            position: collider_position.into(),
+            material: ColliderMaterial {
+                friction,
+                friction_combine_rule,
+                ..Default::default()
+            }
+            .into(),
+            flags: ColliderFlags {
+                collision_groups: InteractionGroups::new(masks.0, masks.1),
+                ..Default::default()
+            }
+            .into(),
+            ..Default::default()
+        };
+    } else {
+        rigid_body_component = RigidBodyBundle {
+            body_type: RigidBodyType::Dynamic.into(),
+            position: transform_to_isometry(this_transform).into(),
+            forces: RigidBodyForces {
+                gravity_scale: 0.,
+                ..Default::default()
+            }
+            .into(),
+            activation: RigidBodyActivation {
+                sleeping: true,
+                ..Default::default()
+            }
+            .into(),
+            ..Default::default()
+        };
+
+        let masks = get_bit_masks(ColliderGroup::NoCollision);
+
+        collider_component = ColliderBundle {
+            shape: shape.into(),
+            position: collider_position.into(),
+            material: ColliderMaterial {
+                friction,
+                friction_combine_rule,
+                ..Default::default()
+            }
+            .into(),
+            flags: ColliderFlags {
+                collision_groups: InteractionGroups::new(masks.0, masks.1),
+                ..Default::default()
+            }
+            .into(),
+            ..Default::default()
+        };
+    }
+
+    let mut examine_map = BTreeMap::new();
+    for (i, text) in prototype.examinable.texts.iter().enumerate() {
+        examine_map.insert(i as u32, text.to_string());
+    }
+
+    let mut builder = commands.spawn_bundle(rigid_body_component);
+
+    let entity_id = builder.id();
+
+    builder.insert_bundle(collider_component).insert_bundle((
+        EntityData {
+            entity_class: prototype.entity_class.to_string(),
+            entity_name: prototype.name.to_string(),
+            ..Default::default()
+        },
+        EntityUpdates::default(),
+        CachedBroadcastTransform::default(),
+        Examinable {
+            assigned_texts: examine_map,
+            name: RichName {
+                name: prototype.examinable.name.to_string(),
+                n: prototype.examinable.n,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        DefaultTransform {
+            transform: default_transform,
+        },
+        RigidBodyData {
+            friction,
+            friction_combine_rule,
+        },
+    ));
+
+    match &prototype.inventory_item {
+        Some(inventory_item_prototype) => {
+            let mut attachment_transforms = HashMap::new();
+            for (attachment, transform) in inventory_item_prototype.attachment_transforms.iter() {
+                attachment_transforms.insert(attachment.to_string(), transform.to_transform());
+            }
+
+            // Prototypes were validated when they got loaded.
+            let combat_type;
+            let combat_projectile_damage_model;
+            let combat_projectile_sound_set;
+            let combat_projectile_text_set;
+            let trigger_projectile_text_set;
+
+            match &inventory_item_prototype.laser {
+                Some(laser) => {
+                    combat_type = CombatType::Projectile(ProjectileType::Laser(
+                        (
+                            laser.color[0],
+                            laser.color[1],
+                            laser.color[2],
+                            laser.color[3],
+                        ),
+                        laser.height,
+                        laser.radius,
+                        laser.range,
+                    ));
+                    combat_projectile_damage_model = Some(laser.damage.get_damage_model().unwrap());
+                    combat_projectile_sound_set = Some(CombatSoundSet::default_laser_projectiles());
+                    combat_projectile_text_set = Some(InventoryItem::get_default_laser_words());
+                    trigger_projectile_text_set =
+                        Some(InventoryItem::get_default_trigger_weapon_words());
+                }
+                None => match &inventory_item_prototype.ballistic {
+                    Some(ballistic) => {
+                        combat_type = CombatType::Projectile(ProjectileType::Ballistic(
+                            ballistic.get_ballistic_projectile().unwrap(),
+                        ));
+                        combat_projectile_damage_model =
+                            Some(ballistic.damage.get_damage_model().unwrap());
+                        combat_projectile_sound_set =
+                            Some(CombatSoundSet::default_ballistic_projectiles());
+                        combat_projectile_text_set =
+                            Some(InventoryItem::get_default_ballistic_words());
+                        trigger_projectile_text_set =
+                            Some(InventoryItem::get_default_trigger_weapon_words());
+                    }
+                    None => {
+                        combat_type = CombatType::MeleeDirect;
+                        combat_projectile_damage_model = None;
+                        combat_projectile_sound_set = None;
+                        combat_projectile_text_set = None;
+                        trigger_projectile_text_set = None;
+                    }
+                },
+            }
+
+            let mut active_slot_tab_actions = vec![];
+
+            match &prototype.reagent_container {
+                Some(reagent_container_prototype) => {
+                    active_slot_tab_actions.extend(get_reagent_tab_actions(
+                        entity_id,
+                        reagent_container_prototype.get_transfer_method().unwrap(),
+                    ));
+                }
+                None => {}
+            }
+
+            match &prototype.medical_item {
+                Some(_) => {
+                    active_slot_tab_actions.extend(get_medical_tab_actions(entity_id));
+                }
+                None => {}
+            }
+
+            match &inventory_item_prototype.accuracy {
+                Some(accuracy_prototype) => {
+                    builder.insert(accuracy_prototype.get_weapon_accuracy());
+                }
+                None => {}
+            }
+
+            match &prototype.ammo {
+                Some(ammo_prototype) => {
+                    if ammo_prototype.shot_cost.is_some() {
+                        active_slot_tab_actions.extend(get_ammo_user_tab_actions(entity_id));
+                    }
+                }
+                None => {}
+            }
+
+            builder.insert(InventoryItem {
+                in_inventory_of_entity: spawn_context.holder_entity,
+                attachment_transforms: attachment_transforms,
+                drop_transform: default_transform,
+                slot_type: inventory_item_prototype.get_slot_type().unwrap(),
+                size: inventory_item_prototype.get_size().unwrap(),
+                is_attached_when_worn: inventory_item_prototype.is_attached_when_worn,
+                combat_attack_animation: inventory_item_prototype
+                    .get_combat_attack_animation()
+                    .unwrap(),
+                combat_type,
+                combat_melee_damage_model: inventory_item_prototype
+                    .melee_damage
+                    .get_damage_model()
+                    .unwrap(),
+                combat_melee_attack: inventory_item_prototype
+                    .melee_attack
+                    .get_melee_attack()
+                    .unwrap(),
+                combat_projectile_damage_model,
+                combat_melee_sound_set: CombatSoundSet::default(),
+                combat_standard_animation: inventory_item_prototype
+                    .get_combat_standard_animation()
+                    .unwrap(),
+                combat_projectile_sound_set,
+                combat_melee_text_set: InventoryItem::get_default_strike_words(),
+                combat_projectile_text_set,
+                trigger_melee_text_set: InventoryItem::get_default_trigger_melee_words(),
+                trigger_projectile_text_set,
+                active_slot_tab_actions,
+                throw_force_factor: inventory_item_prototype.throw_force_factor,
+            });
+        }
+        None => {}
+    }
+
+    match &prototype.storage {
+        Some(storage_prototype) => {
+            builder.insert_bundle((
+                Inventory {
+                    slots: get_storage_slots(storage_prototype.slots),
+                    ..Default::default()
+                },
+                StorageContainer {
+                    open: false,
+                    max_item_size: storage_prototype.get_max_item_size().unwrap(),
+                },
+                TabActions {
+                    tab_actions: get_storage_tab_actions(entity_id, false, vec![]),
+                },
+            ));
+        }
+        None => {}
+    }
+
+    match &prototype.medical_item {
+        Some(medical_item_prototype) => {
+            builder.insert(MedicalItem {
+                brute_heal: medical_item_prototype.brute_heal,
+                burn_heal: medical_item_prototype.burn_heal,
+                toxin_heal: medical_item_prototype.toxin_heal,
+                treatment_time: medical_item_prototype.treatment_time,
+                uses: medical_item_prototype.uses,
+            });
+        }
+        None => {}
+    }
+
+    if prototype.medical_scanner {
+        builder.insert(MedicalScanner);
+    }
+
+    match &prototype.ammo {
+        Some(ammo_prototype) => {
+            builder.insert(AmmoContainer {
+                ammo_type: ammo_prototype.ammo_type.to_string(),
+                capacity: ammo_prototype.capacity,
+                amount: ammo_prototype.get_amount(),
+            });
+            match ammo_prototype.shot_cost {
+                Some(shot_cost) => {
+                    builder.insert(AmmoUser { shot_cost });
+                }
+                None => {}
+            }
+        }
+        None => {}
+    }
+
+    match &prototype.recharger {
+        Some(recharger_prototype) => {
+            builder.insert_bundle((
+                Recharger {
+                    ammo_type: recharger_prototype.ammo_type.to_string(),
+                    charge_per_second: recharger_prototype.charge_per_second,
+                },
+                TabActions {
+                    tab_actions: get_recharger_tab_actions(entity_id),
+                },
+            ));
+        }
+        None => {}
+    }
+
+    // Lasers only run into entities that are combat obstacles, refractive ones still let them through.
+    let mut is_combat_obstacle = false;
+    let mut is_laser_obstacle = true;
+
+    match &prototype.laser_surface {
+        Some(laser_surface_prototype) => {
+            let laser_surface = laser_surface_prototype.get_laser_surface().unwrap();
+            is_combat_obstacle = true;
+            is_laser_obstacle = matches!(laser_surface.kind, LaserSurfaceKind::Reflective);
+            builder.insert(laser_surface);
+        }
+        None => {}
+    }
+
+    match &prototype.throwable {
+        Some(throwable_prototype) => {
+            builder.insert(throwable_prototype.get_throwable().unwrap());
+        }
+        None => {}
+    }
+
+    if !prototype.armor.is_empty() {
+        let mut limbs = HashMap::new();
+        for (limb, limb_armor) in prototype.armor.iter() {
+            limbs.insert(limb.to_string(), limb_armor.get_limb_armor());
+        }
+        builder.insert(Armor { limbs });
+    }
+
+    let mut raegent_container = RaegentContainer {
+        raegents: HashMap::new(),
+    };
+
+    match &prototype.reagent_container {
+        Some(reagent_container_prototype) => {
+            builder.insert(ReagentHolder {
+                capacity: reagent_container_prototype.capacity,
+                transfer_amount: reagent_container_prototype.transfer_amount,
+                transfer_method: reagent_container_prototype.get_transfer_method().unwrap(),
+            });
+            raegent_container.raegents = reagent_container_prototype.reagents.clone();
+        }
+        None => {}
+    }
+
+    match spawn_context.showcase {
+        Some(showcase) => {
+            builder.insert(Showcase {
+                handle: showcase.handle,
+            });
+            let entity_updates = HashMap::new();
+            showcase.net_showcase.send(NetShowcase {
+                handle: showcase.handle,
+                message: ReliableServerMessage::LoadEntity(
+                    prototype.entity_class.to_string(),
+                    prototype.name.to_string(),
+                    entity_updates,
+                    entity_id.to_bits(),
+                    true,
+                    "main".to_string(),
+                    "".to_string(),
+                    false,
+                ),
+            });
+        }
+        None => {
+            builder.insert_bundle((
+                Sensable::default(),
+                Health {
+                    raegent_container,
+                    is_combat_obstacle,
+                    is_laser_obstacle,
+                    ..Default::default()
+                },
+            ));
+        }
+    }
+
+    match held {
+        true => {
+            builder.insert_bundle((
+                RigidBodyDisabled,
+                WorldMode {
+                    mode: WorldModes::Worn,
+                },
+            ));
+        }
+        false => {
+            builder.insert(WorldMode {
+                mode: WorldModes::Physics,
+            });
+        }
+    }
+
+    match spawn_context.holder_entity {
+        Some(holder_entity) => {
+            builder.insert(RigidBodyLinkTransform {
+                follow_entity: holder_entity,
+                ..Default::default()
+            });
+        }
+        None => {}
+    }
+
+    SpawnedEntity::new(entity_id)
+}
@@ -0,0 +1,87 @@
+use std::{path::Path, sync::Arc};
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::{schedule::ParallelSystemDescriptorCoercion, system::ResMut};
+use bevy_log::info;
+
+use crate::space::StartupLabels;
+
+use self::{
+    functions::{load_prototypes::load_prototypes, spawn_prototype::get_prototype_spawn_function},
+    resources::EntityPrototypesResource,
+};
+
+use super::entity::resources::{EntityDataProperties, EntityDataResource};
+
+pub mod functions;
+pub mod resources;
+
+pub fn startup_prototypes(
+    mut entity_data: ResMut<EntityDataResource>,
+    mut entity_prototypes: ResMut<EntityPrototypesResource>,
+) {
+    let prototypes;
+
+    match load_prototypes(&Path::new("data").join("prototypes")) {
+        Ok(p) => {
+            prototypes = p;
+        }
+        Err(rr) => {
+            panic!("Failed to load entity prototypes: {}", rr);
+        }
+    }
+
+    let mut spawnable_amount = 0;
+
+    for prototype in prototypes {
+        let prototype = Arc::new(prototype);
+
+        entity_prototypes
+            .prototypes
+            .insert(prototype.name.clone(), prototype.clone());
+
+        if prototype.is_abstract {
+            continue;
+        }
+
+        if entity_data.name_to_id.contains_key(&prototype.name) {
+            panic!(
+                "Entity prototype {} is already registered as an entity type.",
+                prototype.name
+            );
+        }
+
+        let entity_properties = EntityDataProperties {
+            name: prototype.name.clone(),
+            id: entity_data.get_id_inc(),
+            spawn_function: get_prototype_spawn_function(prototype.clone()),
+            ..Default::default()
+        };
+
+        entity_data
+            .id_to_name
+            .insert(entity_properties.id, entity_properties.name.clone());
+        entity_data
+            .name_to_id
+            .insert(entity_properties.name.clone(), entity_properties.id);
+
+        entity_data.data.push(entity_properties);
+
+        spawnable_amount += 1;
+    }
+
+    info!("Loaded {} entity types from prototypes.", spawnable_amount);
+}
+
+pub struct PrototypesPlugin;
+
+impl Plugin for PrototypesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EntityPrototypesResource>()
+            .add_startup_system(
+                startup_prototypes
+                    .after(StartupLabels::InitEntities)
+                    .before(StartupLabels::BuildGridmap),
+            );
+    }
+}
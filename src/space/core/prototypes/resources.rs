@@ -0,0 +1,756 @@
+use std::{collections::HashMap, sync::Arc};
+
+use bevy_ecs::prelude::{FromWorld, World};
+use bevy_math::{Quat, Vec3};
+use bevy_transform::components::Transform;
+use serde::Deserialize;
+
+use crate::space::core::{
+    chemistry::components::ReagentTransferMethod,
+    combat::components::{LaserSurface, LaserSurfaceKind, WeaponAccuracy},
+    health::components::{DamageFlag, DamageModel, LimbArmor},
+    inventory::components::SlotType,
+    inventory_item::components::{
+        BallisticProjectile, CombatAttackAnimation, CombatStandardAnimation, ItemSize, MeleeAttack,
+        MeleeShape,
+    },
+    throwables::components::{Throwable, ThrowableEffect},
+};
+
+pub struct EntityPrototypesResource {
+    pub prototypes: HashMap<String, Arc<EntityPrototype>>,
+}
+
+impl FromWorld for EntityPrototypesResource {
+    fn from_world(_world: &mut World) -> Self {
+        EntityPrototypesResource {
+            prototypes: HashMap::new(),
+        }
+    }
+}
+
+// A prototype after its parents have been merged into it.
+// Every field is optional in the data files, missing fields fall back to these defaults.
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct EntityPrototype {
+    pub name: String,
+    pub parent: Option<String>,
+    // Abstract prototypes only exist to be inherited from and cannot be spawned.
+    #[serde(rename = "abstract")]
+    pub is_abstract: bool,
+    pub entity_class: String,
+    pub examinable: ExaminablePrototype,
+    pub collider: ColliderPrototype,
+    pub friction: f32,
+    pub default_transform: TransformPrototype,
+    pub inventory_item: Option<InventoryItemPrototype>,
+    pub storage: Option<StoragePrototype>,
+    pub reagent_container: Option<ReagentContainerPrototype>,
+    pub medical_item: Option<MedicalItemPrototype>,
+    pub medical_scanner: bool,
+    // Armor per limb given to whoever wears the item.
+    pub armor: HashMap<String, LimbArmorPrototype>,
+    pub ammo: Option<AmmoPrototype>,
+    pub recharger: Option<RechargerPrototype>,
+    pub laser_surface: Option<LaserSurfacePrototype>,
+    pub throwable: Option<ThrowablePrototype>,
+}
+
+impl Default for EntityPrototype {
+    fn default() -> Self {
+        Self {
+            name: "".to_string(),
+            parent: None,
+            is_abstract: false,
+            entity_class: "entity".to_string(),
+            examinable: ExaminablePrototype::default(),
+            collider: ColliderPrototype::default(),
+            friction: 0.125,
+            default_transform: TransformPrototype::default(),
+            inventory_item: None,
+            storage: None,
+            reagent_container: None,
+            medical_item: None,
+            medical_scanner: false,
+            armor: HashMap::new(),
+            ammo: None,
+            recharger: None,
+            laser_surface: None,
+            throwable: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ExaminablePrototype {
+    pub name: String,
+    pub n: bool,
+    pub texts: Vec<String>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct ColliderPrototype {
+    pub half_extents: [f32; 3],
+    pub position: [f32; 3],
+}
+
+impl Default for ColliderPrototype {
+    fn default() -> Self {
+        Self {
+            half_extents: [0.1, 0.1, 0.1],
+            position: [0., 0., 0.],
+        }
+    }
+}
+
+// Rotations are given either as a quaternion (x, y, z, w) or as an axis and an angle in radians.
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct TransformPrototype {
+    pub translation: [f32; 3],
+    pub rotation: Option<[f32; 4]>,
+    pub axis_angle: Option<[f32; 4]>,
+    pub scale: [f32; 3],
+}
+
+impl Default for TransformPrototype {
+    fn default() -> Self {
+        Self {
+            translation: [0., 0., 0.],
+            rotation: None,
+            axis_angle: None,
+            scale: [1., 1., 1.],
+        }
+    }
+}
+
+impl TransformPrototype {
+    pub fn to_transform(&self) -> Transform {
+        let rotation;
+
+        match self.rotation {
+            Some(r) => {
+                rotation = Quat::from_xyzw(r[0], r[1], r[2], r[3]);
+            }
+            None => match self.axis_angle {
+                Some(a) => {
+                    rotation = Quat::from_axis_angle(Vec3::new(a[0], a[1], a[2]).normalize(), a[3]);
+                }
+                None => {
+                    rotation = Quat::IDENTITY;
+                }
+            },
+        }
+
+        Transform {
+            translation: Vec3::from(self.translation),
+            rotation,
+            scale: Vec3::from(self.scale),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct InventoryItemPrototype {
+    pub slot_type: String,
+    pub size: String,
+    pub is_attached_when_worn: bool,
+    pub throw_force_factor: f32,
+    pub combat_standard_animation: String,
+    pub combat_attack_animation: String,
+    pub melee_damage: DamageModelPrototype,
+    pub melee_attack: MeleeAttackPrototype,
+    pub laser: Option<LaserPrototype>,
+    pub ballistic: Option<BallisticPrototype>,
+    pub accuracy: Option<AccuracyPrototype>,
+    pub attachment_transforms: HashMap<String, TransformPrototype>,
+}
+
+impl Default for InventoryItemPrototype {
+    fn default() -> Self {
+        Self {
+            slot_type: "Generic".to_string(),
+            size: "Normal".to_string(),
+            is_attached_when_worn: true,
+            throw_force_factor: 1.,
+            combat_standard_animation: "StandardStance".to_string(),
+            combat_attack_animation: "OneHandedMeleePunch".to_string(),
+            melee_damage: DamageModelPrototype::default(),
+            melee_attack: MeleeAttackPrototype::default(),
+            laser: None,
+            ballistic: None,
+            accuracy: None,
+            attachment_transforms: HashMap::new(),
+        }
+    }
+}
+
+impl InventoryItemPrototype {
+    pub fn get_slot_type(&self) -> Result<SlotType, String> {
+        match self.slot_type.as_str() {
+            "Generic" => Ok(SlotType::Generic),
+            "Helmet" => Ok(SlotType::Helmet),
+            "Jumpsuit" => Ok(SlotType::Jumpsuit),
+            "Holster" => Ok(SlotType::Holster),
+            "Back" => Ok(SlotType::Back),
+            _ => Err("Unknown slot_type ".to_string() + &self.slot_type),
+        }
+    }
+    pub fn get_size(&self) -> Result<ItemSize, String> {
+        get_item_size(&self.size)
+    }
+    pub fn get_combat_standard_animation(&self) -> Result<CombatStandardAnimation, String> {
+        match self.combat_standard_animation.as_str() {
+            "StandardStance" => Ok(CombatStandardAnimation::StandardStance),
+            "PistolStance" => Ok(CombatStandardAnimation::PistolStance),
+            _ => {
+                Err("Unknown combat_standard_animation ".to_string()
+                    + &self.combat_standard_animation)
+            }
+        }
+    }
+    pub fn get_combat_attack_animation(&self) -> Result<CombatAttackAnimation, String> {
+        match self.combat_attack_animation.as_str() {
+            "OneHandedMeleePunch" => Ok(CombatAttackAnimation::OneHandedMeleePunch),
+            "PistolShot" => Ok(CombatAttackAnimation::PistolShot),
+            _ => {
+                Err("Unknown combat_attack_animation ".to_string() + &self.combat_attack_animation)
+            }
+        }
+    }
+}
+
+// The arc in degrees only matters for the Sweep shape, cooldown is in seconds.
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct MeleeAttackPrototype {
+    pub shape: String,
+    pub arc: f32,
+    pub reach: f32,
+    pub cooldown: f32,
+}
+
+impl Default for MeleeAttackPrototype {
+    fn default() -> Self {
+        Self {
+            shape: "Thrust".to_string(),
+            arc: 90.,
+            reach: 1.2,
+            cooldown: 0.433,
+        }
+    }
+}
+
+impl MeleeAttackPrototype {
+    pub fn get_melee_attack(&self) -> Result<MeleeAttack, String> {
+        let shape;
+
+        match self.shape.as_str() {
+            "Thrust" => {
+                shape = MeleeShape::Thrust;
+            }
+            "Sweep" => {
+                if self.arc <= 0. || self.arc > 360. {
+                    return Err("melee_attack arc must be above 0 and at most 360".to_string());
+                }
+                shape = MeleeShape::Sweep(self.arc);
+            }
+            _ => {
+                return Err("Unknown melee_attack shape ".to_string() + &self.shape);
+            }
+        }
+
+        if self.reach <= 0. {
+            return Err("melee_attack reach has to be above zero".to_string());
+        }
+        if self.cooldown <= 0. {
+            return Err("melee_attack cooldown has to be above zero".to_string());
+        }
+
+        Ok(MeleeAttack {
+            shape,
+            reach: self.reach,
+            cooldown: self.cooldown,
+        })
+    }
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct StoragePrototype {
+    pub slots: u8,
+    pub max_item_size: String,
+}
+
+impl Default for StoragePrototype {
+    fn default() -> Self {
+        Self {
+            slots: 7,
+            max_item_size: "Normal".to_string(),
+        }
+    }
+}
+
+impl StoragePrototype {
+    pub fn get_max_item_size(&self) -> Result<ItemSize, String> {
+        get_item_size(&self.max_item_size)
+    }
+}
+
+// The raegents are amounts in units the container spawns with.
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct ReagentContainerPrototype {
+    pub capacity: f32,
+    pub transfer_amount: f32,
+    pub transfer_method: String,
+    pub reagents: HashMap<String, f32>,
+}
+
+impl Default for ReagentContainerPrototype {
+    fn default() -> Self {
+        Self {
+            capacity: 30.,
+            transfer_amount: 5.,
+            transfer_method: "Ingest".to_string(),
+            reagents: HashMap::new(),
+        }
+    }
+}
+
+impl ReagentContainerPrototype {
+    pub fn get_transfer_method(&self) -> Result<ReagentTransferMethod, String> {
+        match self.transfer_method.as_str() {
+            "Inject" => Ok(ReagentTransferMethod::Inject),
+            "Ingest" => Ok(ReagentTransferMethod::Ingest),
+            _ => Err("Unknown transfer_method ".to_string() + &self.transfer_method),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct MedicalItemPrototype {
+    pub brute_heal: f32,
+    pub burn_heal: f32,
+    pub toxin_heal: f32,
+    // In seconds.
+    pub treatment_time: f32,
+    pub uses: u8,
+}
+
+impl Default for MedicalItemPrototype {
+    fn default() -> Self {
+        Self {
+            brute_heal: 0.,
+            burn_heal: 0.,
+            toxin_heal: 0.,
+            treatment_time: 3.,
+            uses: 1,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct LimbArmorPrototype {
+    pub brute: f32,
+    pub burn: f32,
+    pub toxin: f32,
+}
+
+impl LimbArmorPrototype {
+    pub fn get_limb_armor(&self) -> LimbArmor {
+        LimbArmor {
+            brute: self.brute,
+            burn: self.burn,
+            toxin: self.toxin,
+        }
+    }
+}
+
+// Power cells and magazines. Weapons that take ammunition also set the cost of a shot.
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct AmmoPrototype {
+    pub ammo_type: String,
+    pub capacity: u32,
+    // Spawns full when left out.
+    pub amount: Option<u32>,
+    pub shot_cost: Option<u32>,
+}
+
+impl Default for AmmoPrototype {
+    fn default() -> Self {
+        Self {
+            ammo_type: "energy".to_string(),
+            capacity: 20,
+            amount: None,
+            shot_cost: None,
+        }
+    }
+}
+
+impl AmmoPrototype {
+    pub fn get_amount(&self) -> u32 {
+        self.amount.unwrap_or(self.capacity)
+    }
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct RechargerPrototype {
+    pub ammo_type: String,
+    pub charge_per_second: u32,
+}
+
+impl Default for RechargerPrototype {
+    fn default() -> Self {
+        Self {
+            ammo_type: "energy".to_string(),
+            charge_per_second: 2,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct LaserSurfacePrototype {
+    pub kind: String,
+    pub energy_factor: f32,
+}
+
+impl Default for LaserSurfacePrototype {
+    fn default() -> Self {
+        Self {
+            kind: "Reflective".to_string(),
+            energy_factor: 0.8,
+        }
+    }
+}
+
+impl LaserSurfacePrototype {
+    pub fn get_laser_surface(&self) -> Result<LaserSurface, String> {
+        let kind;
+
+        match self.kind.as_str() {
+            "Reflective" => {
+                kind = LaserSurfaceKind::Reflective;
+            }
+            "Refractive" => {
+                kind = LaserSurfaceKind::Refractive;
+            }
+            _ => {
+                return Err("Unknown laser_surface kind ".to_string() + &self.kind);
+            }
+        }
+
+        Ok(LaserSurface {
+            kind,
+            energy_factor: self.energy_factor,
+        })
+    }
+}
+
+// Fuse, duration and stun are in seconds, radius in meters.
+#[derive(Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ThrowablePrototype {
+    pub impact_damage: Option<DamageModelPrototype>,
+    pub fuse: Option<f32>,
+    pub effect: Option<ThrowableEffectPrototype>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct ThrowableEffectPrototype {
+    pub kind: String,
+    pub power: f32,
+    pub radius: f32,
+    pub duration: f32,
+}
+
+impl Default for ThrowableEffectPrototype {
+    fn default() -> Self {
+        Self {
+            kind: "Explosion".to_string(),
+            power: 50.,
+            radius: 6.,
+            duration: 5.,
+        }
+    }
+}
+
+impl ThrowablePrototype {
+    pub fn get_throwable(&self) -> Result<Throwable, String> {
+        let impact_damage_model;
+
+        match &self.impact_damage {
+            Some(impact_damage) => {
+                impact_damage_model = Some(impact_damage.get_damage_model()?);
+            }
+            None => {
+                impact_damage_model = None;
+            }
+        }
+
+        match self.fuse {
+            Some(fuse) => {
+                if fuse <= 0. {
+                    return Err("throwable fuse has to be above zero".to_string());
+                }
+                if self.effect.is_none() {
+                    return Err("throwable fuse requires an effect".to_string());
+                }
+            }
+            None => {}
+        }
+
+        let effect;
+
+        match &self.effect {
+            Some(effect_prototype) => {
+                effect = Some(effect_prototype.get_throwable_effect()?);
+            }
+            None => {
+                if impact_damage_model.is_none() {
+                    return Err("throwable needs an impact_damage or an effect".to_string());
+                }
+                effect = None;
+            }
+        }
+
+        Ok(Throwable {
+            impact_damage_model,
+            fuse: self.fuse,
+            effect,
+        })
+    }
+}
+
+impl ThrowableEffectPrototype {
+    pub fn get_throwable_effect(&self) -> Result<ThrowableEffect, String> {
+        if self.radius <= 0. {
+            return Err("throwable effect radius has to be above zero".to_string());
+        }
+
+        match self.kind.as_str() {
+            "Explosion" => {
+                if self.power <= 0. {
+                    return Err("throwable explosion power has to be above zero".to_string());
+                }
+                Ok(ThrowableEffect::Explosion {
+                    power: self.power,
+                    falloff: self.radius,
+                })
+            }
+            "Flash" => {
+                if self.duration <= 0. {
+                    return Err("throwable flash duration has to be above zero".to_string());
+                }
+                Ok(ThrowableEffect::Flash {
+                    radius: self.radius,
+                    stun: self.duration,
+                })
+            }
+            "Smoke" => {
+                if self.duration <= 0. {
+                    return Err("throwable smoke duration has to be above zero".to_string());
+                }
+                Ok(ThrowableEffect::Smoke {
+                    radius: self.radius,
+                    duration: self.duration,
+                })
+            }
+            _ => Err("Unknown throwable effect kind ".to_string() + &self.kind),
+        }
+    }
+}
+
+// In degrees, recovery in degrees per second.
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct AccuracyPrototype {
+    pub base_spread: f32,
+    pub recoil_per_shot: f32,
+    pub max_recoil: f32,
+    pub recovery: f32,
+    pub moving_penalty: f32,
+    pub sprinting_penalty: f32,
+}
+
+impl Default for AccuracyPrototype {
+    fn default() -> Self {
+        Self {
+            base_spread: 2.,
+            recoil_per_shot: 2.,
+            max_recoil: 10.,
+            recovery: 8.,
+            moving_penalty: 4.,
+            sprinting_penalty: 12.,
+        }
+    }
+}
+
+impl AccuracyPrototype {
+    pub fn get_weapon_accuracy(&self) -> WeaponAccuracy {
+        WeaponAccuracy {
+            base_spread: self.base_spread,
+            recoil_per_shot: self.recoil_per_shot,
+            max_recoil: self.max_recoil,
+            recovery: self.recovery,
+            moving_penalty: self.moving_penalty,
+            sprinting_penalty: self.sprinting_penalty,
+            recoil: 0.,
+        }
+    }
+}
+
+pub fn get_item_size(size: &str) -> Result<ItemSize, String> {
+    match size {
+        "Tiny" => Ok(ItemSize::Tiny),
+        "Small" => Ok(ItemSize::Small),
+        "Normal" => Ok(ItemSize::Normal),
+        "Bulky" => Ok(ItemSize::Bulky),
+        "Huge" => Ok(ItemSize::Huge),
+        _ => Err("Unknown item size ".to_string() + size),
+    }
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct DamageModelPrototype {
+    pub brute: f32,
+    pub burn: f32,
+    pub toxin: f32,
+    pub flags: Vec<String>,
+    pub stun: f32,
+    pub floor: f32,
+}
+
+impl DamageModelPrototype {
+    pub fn get_damage_model(&self) -> Result<DamageModel, String> {
+        let mut damage_flags = HashMap::new();
+
+        for (i, flag) in self.flags.iter().enumerate() {
+            let damage_flag;
+            match flag.as_str() {
+                "SoftDamage" => {
+                    damage_flag = DamageFlag::SoftDamage;
+                }
+                "WeakLethalLaser" => {
+                    damage_flag = DamageFlag::WeakLethalLaser;
+                }
+                _ => {
+                    return Err("Unknown damage flag ".to_string() + flag);
+                }
+            }
+            damage_flags.insert(i as u32, damage_flag);
+        }
+
+        if self.stun < 0. || self.floor < 0. {
+            return Err("Stun and floor durations cannot be negative".to_string());
+        }
+
+        if self.stun > 0. {
+            damage_flags.insert(damage_flags.len() as u32, DamageFlag::Stun(self.stun));
+        }
+        if self.floor > 0. {
+            damage_flags.insert(damage_flags.len() as u32, DamageFlag::Floor(self.floor));
+        }
+
+        Ok(DamageModel {
+            brute: self.brute,
+            burn: self.burn,
+            toxin: self.toxin,
+            damage_flags,
+        })
+    }
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct LaserPrototype {
+    pub color: [f32; 4],
+    pub height: f32,
+    pub radius: f32,
+    pub range: f32,
+    pub damage: DamageModelPrototype,
+}
+
+impl Default for LaserPrototype {
+    fn default() -> Self {
+        Self {
+            color: [1., 0., 0., 1.],
+            height: 3.,
+            radius: 0.025,
+            range: 50.,
+            damage: DamageModelPrototype::default(),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct BallisticPrototype {
+    pub range: f32,
+    // In meters per second, hitscan when left out.
+    pub speed: Option<f32>,
+    pub falloff_start: f32,
+    pub min_damage_factor: f32,
+    pub penetrable_cells: Vec<String>,
+    pub penetration_damage_factor: f32,
+    pub max_penetrations: u8,
+    pub damage: DamageModelPrototype,
+}
+
+impl Default for BallisticPrototype {
+    fn default() -> Self {
+        Self {
+            range: 40.,
+            speed: None,
+            falloff_start: 15.,
+            min_damage_factor: 0.5,
+            penetrable_cells: vec![],
+            penetration_damage_factor: 0.5,
+            max_penetrations: 1,
+            damage: DamageModelPrototype::default(),
+        }
+    }
+}
+
+impl BallisticPrototype {
+    pub fn get_ballistic_projectile(&self) -> Result<BallisticProjectile, String> {
+        if self.range <= 0. {
+            return Err("ballistic range has to be above zero".to_string());
+        }
+        match self.speed {
+            Some(speed) => {
+                if speed <= 0. {
+                    return Err("ballistic speed has to be above zero".to_string());
+                }
+            }
+            None => {}
+        }
+        if self.min_damage_factor < 0. || self.min_damage_factor > 1. {
+            return Err("ballistic min_damage_factor must be between 0 and 1".to_string());
+        }
+        if self.penetration_damage_factor < 0. || self.penetration_damage_factor > 1. {
+            return Err("ballistic penetration_damage_factor must be between 0 and 1".to_string());
+        }
+
+        Ok(BallisticProjectile {
+            range: self.range,
+            speed_option: self.speed,
+            falloff_start: self.falloff_start,
+            min_damage_factor: self.min_damage_factor,
+            penetrable_cells: self.penetrable_cells.clone(),
+            penetration_damage_factor: self.penetration_damage_factor,
+            max_penetrations: self.max_penetrations,
+        })
+    }
+}
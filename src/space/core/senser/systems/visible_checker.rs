@@ -19,6 +19,7 @@ use crate::space::core::{
     sensable::components::Sensable,
     senser::components::Senser,
     static_body::components::StaticTransform,
+    storage::components::Stored,
 };
 
 pub fn visible_checker(
@@ -30,6 +31,7 @@ pub fn visible_checker(
         &EntityData,
         &EntityUpdates,
         Option<&WorldMode>,
+        Option<&Stored>,
     )>,
     mut query_visible_checker_entities_rigid: Query<(
         Entity,
@@ -65,6 +67,7 @@ pub fn visible_checker(
             entity_data_component,
             entity_updates_component,
             entity_world_mode_option,
+            stored_component_option,
         ) in query_visible_entities.iter_mut()
         {
             let visible_entity_transform;
@@ -111,6 +114,7 @@ pub fn visible_checker(
                 visible_entity_id,
                 is_interpolated,
                 &entity_updates_component,
+                stored_component_option.is_some(),
             );
         }
 
@@ -181,6 +185,7 @@ fn visible_check(
     visible_entity_id: Entity,
     interpolated_transform: bool,
     visible_entity_updates_component: &EntityUpdates,
+    stored: bool,
 ) {
     let distance = visible_checker_translation.distance(visible_entity_transform.translation);
     let is_cached = distance < VIEW_DISTANCE;
@@ -189,6 +194,7 @@ fn visible_check(
     if sensable_component.is_light
         || sensable_component.is_audible
         || sensable_component.always_sensed
+        || stored
     {
         can_cache = false;
     } else {
@@ -217,6 +223,10 @@ fn visible_check(
         is_sensed = true;
     }
 
+    if stored {
+        is_sensed = false;
+    }
+
     let sensed_by_contains = sensable_component
         .sensed_by
         .contains(&visible_checker_entity_id);
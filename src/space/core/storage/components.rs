@@ -0,0 +1,23 @@
+use bevy_ecs::prelude::Component;
+
+use crate::space::core::inventory_item::components::ItemSize;
+
+// Entities with this component keep the items stored in them in their own Inventory component.
+#[derive(Component)]
+pub struct StorageContainer {
+    pub open: bool,
+    pub max_item_size: ItemSize,
+}
+
+impl Default for StorageContainer {
+    fn default() -> Self {
+        Self {
+            open: false,
+            max_item_size: ItemSize::Normal,
+        }
+    }
+}
+
+// Items inside a storage container are not loaded on clients until they are taken out again.
+#[derive(Component)]
+pub struct Stored;
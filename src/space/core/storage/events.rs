@@ -0,0 +1,11 @@
+use bevy_ecs::entity::Entity;
+
+pub struct InputStorageOpen {
+    pub opener: Entity,
+    pub storage: Entity,
+}
+
+pub struct InputStorageClose {
+    pub closer: Entity,
+    pub storage: Entity,
+}
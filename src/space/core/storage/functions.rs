@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use bevy_ecs::{entity::Entity, system::Query};
+
+use crate::space::core::{
+    data_link::components::DataLink,
+    entity::{components::EntityData, resources::EntityDataResource},
+    gridmap::resources::CellData,
+    inventory::components::{Inventory, Slot, SlotType},
+    inventory_item::components::InventoryItem,
+    networking::resources::GridMapType,
+    pawn::functions::can_reach_entity::REACH_DISTANCE,
+    tab_actions::components::TabAction,
+};
+
+pub fn get_storage_slots(amount: u8) -> Vec<Slot> {
+    let mut slots = vec![];
+
+    for i in 0..amount {
+        slots.push(Slot {
+            slot_type: SlotType::Generic,
+            slot_name: "storage".to_string() + &i.to_string(),
+            slot_item: None,
+            slot_attachment: None,
+        });
+    }
+
+    slots
+}
+
+// The entities holding this entity, from the direct holder to the one that is in the world.
+pub fn get_holders(entity: Entity, inventory_items: &Query<&mut InventoryItem>) -> Vec<Entity> {
+    let mut holders = vec![];
+    let mut current_entity = entity;
+
+    loop {
+        match inventory_items.get(current_entity) {
+            Ok(inventory_item_component) => match inventory_item_component.in_inventory_of_entity {
+                Some(holder_entity) => {
+                    if holder_entity == entity || holders.contains(&holder_entity) {
+                        break;
+                    }
+                    holders.push(holder_entity);
+                    current_entity = holder_entity;
+                }
+                None => {
+                    break;
+                }
+            },
+            Err(_rr) => {
+                break;
+            }
+        }
+    }
+
+    holders
+}
+
+// Closed storages only offer to be opened, open storages offer to store the held item and to take out every item inside.
+pub fn get_storage_tab_actions(
+    storage_entity: Entity,
+    open: bool,
+    stored_items: Vec<(Entity, String)>,
+) -> Vec<TabAction> {
+    let mut tab_actions = vec![];
+
+    if !open {
+        tab_actions.push(TabAction {
+            id: "storageopen".to_string(),
+            text: "Open".to_string(),
+            tab_list_priority: 100,
+            prerequisite_check: Arc::new(storage_toggle_action),
+            belonging_entity: Some(storage_entity),
+        });
+        return tab_actions;
+    }
+
+    tab_actions.push(TabAction {
+        id: "storageclose".to_string(),
+        text: "Close".to_string(),
+        tab_list_priority: 100,
+        prerequisite_check: Arc::new(storage_toggle_action),
+        belonging_entity: Some(storage_entity),
+    });
+    tab_actions.push(TabAction {
+        id: "storageinsert".to_string(),
+        text: "Store".to_string(),
+        tab_list_priority: 99,
+        prerequisite_check: Arc::new(storage_insert_action),
+        belonging_entity: Some(storage_entity),
+    });
+
+    for (item_entity, item_name) in stored_items {
+        tab_actions.push(TabAction {
+            id: "storagetake".to_string(),
+            text: "Take ".to_string() + &item_name,
+            tab_list_priority: 98,
+            prerequisite_check: Arc::new(storage_take_action),
+            belonging_entity: Some(item_entity),
+        });
+    }
+
+    tab_actions
+}
+
+pub fn storage_toggle_action(
+    _self_tab_entity: Option<Entity>,
+    entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    distance: f32,
+    _inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    distance < REACH_DISTANCE && entity_id_bits_option.is_some()
+}
+
+pub fn storage_insert_action(
+    _self_tab_entity: Option<Entity>,
+    entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    distance: f32,
+    inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    match (
+        entity_id_bits_option,
+        inventory_component.get_active_slot_entity(),
+    ) {
+        (Some(storage_entity_bits), Some(held_entity)) => {
+            distance < REACH_DISTANCE && held_entity.to_bits() != storage_entity_bits
+        }
+        _ => false,
+    }
+}
+
+pub fn storage_take_action(
+    self_tab_entity: Option<Entity>,
+    _entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    distance: f32,
+    inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    distance < REACH_DISTANCE
+        && self_tab_entity.is_some()
+        && inventory_component.get_active_slot_entity().is_none()
+}
@@ -0,0 +1,22 @@
+use bevy_app::{App, Plugin};
+
+use self::{
+    events::{InputStorageClose, InputStorageOpen},
+    systems::{storage_open::storage_open, storage_tab_actions::storage_tab_actions},
+};
+
+pub mod components;
+pub mod events;
+pub mod functions;
+pub mod systems;
+
+pub struct StoragePlugin;
+
+impl Plugin for StoragePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<InputStorageOpen>()
+            .add_event::<InputStorageClose>()
+            .add_system(storage_open)
+            .add_system(storage_tab_actions);
+    }
+}
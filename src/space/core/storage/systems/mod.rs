@@ -0,0 +1,2 @@
+pub mod storage_open;
+pub mod storage_tab_actions;
@@ -0,0 +1,35 @@
+use bevy_app::EventReader;
+use bevy_ecs::system::Query;
+
+use crate::space::core::storage::{
+    components::StorageContainer,
+    events::{InputStorageClose, InputStorageOpen},
+};
+
+pub fn storage_open(
+    mut open_events: EventReader<InputStorageOpen>,
+    mut close_events: EventReader<InputStorageClose>,
+    mut storages: Query<&mut StorageContainer>,
+) {
+    for event in open_events.iter() {
+        match storages.get_mut(event.storage) {
+            Ok(mut storage_component) => {
+                if !storage_component.open {
+                    storage_component.open = true;
+                }
+            }
+            Err(_rr) => {}
+        }
+    }
+
+    for event in close_events.iter() {
+        match storages.get_mut(event.storage) {
+            Ok(mut storage_component) => {
+                if storage_component.open {
+                    storage_component.open = false;
+                }
+            }
+            Err(_rr) => {}
+        }
+    }
+}
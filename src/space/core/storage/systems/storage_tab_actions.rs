@@ -0,0 +1,48 @@
+use bevy_ecs::{
+    entity::Entity,
+    prelude::{Changed, Or},
+    system::Query,
+};
+
+use crate::space::core::{
+    examinable::components::Examinable,
+    inventory::components::Inventory,
+    storage::{components::StorageContainer, functions::get_storage_tab_actions},
+    tab_actions::components::TabActions,
+};
+
+// Keep the tab actions of storages in line with whether they are open and what is inside.
+pub fn storage_tab_actions(
+    mut changed_storages: Query<
+        (Entity, &StorageContainer, &Inventory, &mut TabActions),
+        Or<(Changed<StorageContainer>, Changed<Inventory>)>,
+    >,
+    examinables: Query<&Examinable>,
+) {
+    for (storage_entity, storage_component, inventory_component, mut tab_actions_component) in
+        changed_storages.iter_mut()
+    {
+        let mut stored_items = vec![];
+
+        for slot in inventory_component.slots.iter() {
+            match slot.slot_item {
+                Some(item_entity) => {
+                    let item_name;
+                    match examinables.get(item_entity) {
+                        Ok(examinable_component) => {
+                            item_name = examinable_component.name.get_name().to_string();
+                        }
+                        Err(_rr) => {
+                            item_name = "item".to_string();
+                        }
+                    }
+                    stored_items.push((item_entity, item_name));
+                }
+                None => {}
+            }
+        }
+
+        tab_actions_component.tab_actions =
+            get_storage_tab_actions(storage_entity, storage_component.open, stored_items);
+    }
+}
@@ -10,6 +10,8 @@ use bevy_rapier3d::prelude::RigidBodyPositionComponent;
 
 use crate::space::{
     core::{
+        ammunition::events::{InputRecharge, InputReloadWeapon},
+        chemistry::events::InputTransferReagents,
         connected_player::{
             components::{ConnectedPlayer, SoftPlayer},
             events::{InputExamineEntity, InputExamineMap},
@@ -21,9 +23,15 @@ use crate::space::{
             functions::gridmap_functions::cell_id_to_world,
             resources::{GridmapMain, Vec3Int},
         },
-        inventory::{components::Inventory, events::InputUseWorldItem},
+        humanoid::components::Humanoid,
+        inventory::{
+            components::Inventory,
+            events::{InputDropCurrentItem, InputUseWorldItem},
+        },
+        medical::events::InputTreatPatient,
         pawn::components::Pawn,
         static_body::components::StaticTransform,
+        storage::events::{InputStorageClose, InputStorageOpen},
         tab_actions::{components::TabActions, events::InputTabAction},
     },
     entities::{
@@ -53,6 +61,17 @@ pub fn tab_action(
     mut counter_window_toggle_open_event: EventWriter<InputCounterWindowToggleOpen>,
     mut counter_window_lock_open_event: EventWriter<CounterWindowLockOpen>,
     mut counter_window_lock_closed_event: EventWriter<CounterWindowLockClosed>,
+    storage_events: (
+        EventWriter<InputStorageOpen>,
+        EventWriter<InputStorageClose>,
+        EventWriter<InputDropCurrentItem>,
+    ),
+    item_use_events: (
+        EventWriter<InputTransferReagents>,
+        EventWriter<InputTreatPatient>,
+        EventWriter<InputReloadWeapon>,
+        EventWriter<InputRecharge>,
+    ),
 
     criteria_query: Query<&ConnectedPlayer, Without<SoftPlayer>>,
 
@@ -67,6 +86,7 @@ pub fn tab_action(
     entity_data_resource: Res<EntityDataResource>,
     entity_datas: Query<&EntityData>,
     handle_to_entity: Res<HandleToEntity>,
+    humanoids: Query<&Humanoid>,
 ) {
     let (
         mut input_tab_action_events,
@@ -79,6 +99,16 @@ pub fn tab_action(
         mut air_lock_lock_closed_event,
     ) = events;
 
+    let (mut storage_open_event, mut storage_close_event, mut drop_current_item_event) =
+        storage_events;
+
+    let (
+        mut transfer_reagents_event,
+        mut treat_patient_event,
+        mut reload_weapon_event,
+        mut recharge_event,
+    ) = item_use_events;
+
     for event in input_tab_action_events.iter() {
         // Safety check.
         match criteria_query.get(event.player_entity) {
@@ -88,6 +118,16 @@ pub fn tab_action(
             }
         }
 
+        // Stunned, floored, unconscious and dead humanoids cannot use tab actions.
+        match humanoids.get(event.player_entity) {
+            Ok(humanoid_component) => {
+                if !humanoid_component.can_act() {
+                    continue;
+                }
+            }
+            Err(_rr) => {}
+        }
+
         let pawn_component;
         let pawn_inventory_component;
         let pawn_rigid_body_position_component;
@@ -359,6 +399,72 @@ pub fn tab_action(
                     locker: event.player_entity,
                 });
             }
+        } else if event.tab_id == "storageopen" {
+            if event.target_entity_option.is_some() {
+                storage_open_event.send(InputStorageOpen {
+                    opener: event.player_entity,
+                    storage: Entity::from_bits(event.target_entity_option.unwrap()),
+                });
+            }
+        } else if event.tab_id == "storageclose" {
+            if event.target_entity_option.is_some() {
+                storage_close_event.send(InputStorageClose {
+                    closer: event.player_entity,
+                    storage: Entity::from_bits(event.target_entity_option.unwrap()),
+                });
+            }
+        } else if event.tab_id == "storageinsert" {
+            if event.target_entity_option.is_some() {
+                drop_current_item_event.send(InputDropCurrentItem {
+                    pickuper_entity: event.player_entity,
+                    input_position_option: None,
+                    storage_entity_option: Some(Entity::from_bits(
+                        event.target_entity_option.unwrap(),
+                    )),
+                    slot_name_option: None,
+                    involuntary: false,
+                });
+            }
+        } else if event.tab_id == "storagetake" {
+            // The belonging entity of a take action is the stored item.
+            if event.belonging_entity.is_some() {
+                pickup_world_item_event.send(InputUseWorldItem {
+                    pickuper_entity: event.player_entity,
+                    pickupable_entity_bits: event.belonging_entity.unwrap(),
+                });
+            }
+        } else if event.tab_id == "reagenttransfer" || event.tab_id == "reagentdraw" {
+            if event.target_entity_option.is_some() && event.belonging_entity.is_some() {
+                transfer_reagents_event.send(InputTransferReagents {
+                    user: event.player_entity,
+                    holder: Entity::from_bits(event.belonging_entity.unwrap()),
+                    target: Entity::from_bits(event.target_entity_option.unwrap()),
+                    draw: event.tab_id == "reagentdraw",
+                });
+            }
+        } else if event.tab_id == "medicaltreat" {
+            if event.target_entity_option.is_some() && event.belonging_entity.is_some() {
+                treat_patient_event.send(InputTreatPatient {
+                    treater: event.player_entity,
+                    medical_item: Entity::from_bits(event.belonging_entity.unwrap()),
+                    patient: Entity::from_bits(event.target_entity_option.unwrap()),
+                });
+            }
+        } else if event.tab_id == "ammoreload" || event.tab_id == "ammoswap" {
+            if event.belonging_entity.is_some() {
+                reload_weapon_event.send(InputReloadWeapon {
+                    user: event.player_entity,
+                    weapon: Entity::from_bits(event.belonging_entity.unwrap()),
+                    swap: event.tab_id == "ammoswap",
+                });
+            }
+        } else if event.tab_id == "recharge" {
+            if event.target_entity_option.is_some() {
+                recharge_event.send(InputRecharge {
+                    user: event.player_entity,
+                    recharger: Entity::from_bits(event.target_entity_option.unwrap()),
+                });
+            }
         }
     }
 }
@@ -0,0 +1,37 @@
+use bevy_core::Timer;
+use bevy_ecs::{entity::Entity, prelude::Component};
+
+use crate::space::core::health::components::DamageModel;
+
+#[derive(Clone)]
+pub enum ThrowableEffect {
+    // Sends an explosion with this power and falloff.
+    Explosion { power: f32, falloff: f32 },
+    // Stuns everyone within the radius who can see it, up to this many seconds.
+    Flash { radius: f32, stun: f32 },
+    // Blocks the field of view of the cells within the radius for this many seconds.
+    Smoke { radius: f32, duration: f32 },
+}
+
+// Items that do something when they are thrown.
+#[derive(Component)]
+pub struct Throwable {
+    // Dealt to whatever the item hits first after being thrown.
+    pub impact_damage_model: Option<DamageModel>,
+    // Seconds between being thrown and the effect going off, effects without a fuse go off on impact.
+    pub fuse: Option<f32>,
+    pub effect: Option<ThrowableEffect>,
+}
+
+// A throwable that is in the air and has not hit anything yet.
+#[derive(Component)]
+pub struct Thrown {
+    pub thrower_entity: Entity,
+    pub thrower_name: String,
+}
+
+#[derive(Component)]
+pub struct LitFuse {
+    pub timer: Timer,
+    pub thrower_option: Option<Entity>,
+}
@@ -0,0 +1,8 @@
+use bevy_ecs::entity::Entity;
+
+// Makes the effect of a throwable go off, after which the throwable is spent.
+pub struct TriggerThrowable {
+    pub entity: Entity,
+    // Whoever threw it, the effect goes off on their account.
+    pub thrower_option: Option<Entity>,
+}
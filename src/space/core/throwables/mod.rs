@@ -0,0 +1,30 @@
+use bevy_app::{App, Plugin};
+use bevy_ecs::schedule::ParallelSystemDescriptorCoercion;
+
+use self::{
+    events::TriggerThrowable,
+    resources::SmokeClouds,
+    systems::{dissipate_smoke, throwable_effects, throwable_fuses, thrown_impacts},
+};
+
+pub mod components;
+pub mod events;
+pub mod resources;
+pub mod systems;
+
+pub struct ThrowablesPlugin;
+
+impl Plugin for ThrowablesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TriggerThrowable>()
+            .init_resource::<SmokeClouds>()
+            .add_system(thrown_impacts)
+            .add_system(throwable_fuses)
+            .add_system(
+                throwable_effects
+                    .after(thrown_impacts)
+                    .after(throwable_fuses),
+            )
+            .add_system(dissipate_smoke);
+    }
+}
@@ -0,0 +1,28 @@
+use bevy_core::Timer;
+use bevy_ecs::prelude::{FromWorld, World};
+
+use crate::space::core::gridmap::resources::Vec2Int;
+
+// Cells that smoke hides from the field of view of sensers, the DoryenMap itself is left alone.
+pub struct SmokeCloud {
+    pub cells: Vec<Vec2Int>,
+    pub timer: Timer,
+}
+
+pub struct SmokeClouds {
+    pub clouds: Vec<SmokeCloud>,
+}
+
+impl FromWorld for SmokeClouds {
+    fn from_world(_world: &mut World) -> Self {
+        SmokeClouds { clouds: vec![] }
+    }
+}
+
+impl SmokeClouds {
+    pub fn is_smoked(&self, cell_id: &Vec2Int) -> bool {
+        self.clouds
+            .iter()
+            .any(|cloud| cloud.cells.contains(cell_id))
+    }
+}
@@ -0,0 +1,700 @@
+use bevy_app::{EventReader, EventWriter};
+use bevy_core::{Time, Timer};
+use bevy_ecs::{
+    entity::Entity,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_math::Vec3;
+use bevy_rapier3d::{
+    physics::IntoEntity,
+    prelude::{ActiveEvents, ColliderFlagsComponent, ContactEvent, RigidBodyPositionComponent},
+};
+use bevy_transform::components::Transform;
+
+use crate::space::{
+    core::{
+        atmospherics::{
+            functions::{get_atmos_index, get_unblocked_reach},
+            resources::AtmosphericsResource,
+        },
+        chat::{events::NetChatMessage, functions::new_personal_message},
+        combat::resources::{CombatLog, CombatLogRecord, CombatLogTarget, CombatRules},
+        connected_player::resources::HandleToEntity,
+        examinable::components::Examinable,
+        explosions::events::Explosion,
+        gridmap::{
+            functions::{
+                get_cell_name::get_cell_name,
+                gridmap_functions::{cell_id_to_world, world_to_cell_id},
+            },
+            resources::{
+                to_doryen_coordinates, DoryenMap, GridmapData, GridmapMain, Vec2Int, Vec3Int,
+            },
+            systems::senser_update_fov::compute_senser_fov,
+        },
+        health::{
+            components::{DamageFlag, DamageModel, DamageType, Health, HitResult},
+            events::InflictStatusEffects,
+        },
+        inventory_item::components::{HitSoundSurface, InventoryItem},
+        pawn::components::Pawn,
+        senser::components::Senser,
+        sfx::{components::sfx_auto_destroy, resources::SfxAutoDestroyTimers},
+    },
+    entities::sfx::combat::explosion1_sfx::Explosion1SfxBundle,
+};
+
+use super::{
+    components::{LitFuse, Throwable, ThrowableEffect, Thrown},
+    events::TriggerThrowable,
+    resources::{SmokeCloud, SmokeClouds},
+};
+
+pub fn thrown_impacts(
+    mut contact_events: EventReader<ContactEvent>,
+    thrown_items: Query<(
+        &Thrown,
+        &Throwable,
+        &InventoryItem,
+        &Examinable,
+        &RigidBodyPositionComponent,
+    )>,
+    mut collider_flags: Query<&mut ColliderFlagsComponent>,
+    mut health_entities: Query<(&mut Health, &Examinable, &RigidBodyPositionComponent)>,
+    rigid_body_positions: Query<&RigidBodyPositionComponent>,
+    pawns: Query<&Pawn>,
+    mut gridmap_main: ResMut<GridmapMain>,
+    gridmap_data: Res<GridmapData>,
+    combat_resources: (Res<CombatRules>, ResMut<CombatLog>, Res<Time>),
+    mut net_message_event: EventWriter<NetChatMessage>,
+    handle_to_entity: Res<HandleToEntity>,
+    sensers: Query<(Entity, &Senser)>,
+    mut inflict_status_effects: EventWriter<InflictStatusEffects>,
+    mut trigger_throwable: EventWriter<TriggerThrowable>,
+    mut commands: Commands,
+    mut sfx_auto_destroy_timers: ResMut<SfxAutoDestroyTimers>,
+) {
+    let (combat_rules, mut combat_log, time) = combat_resources;
+
+    let mut landed_entities = vec![];
+
+    for contact_event in contact_events.iter() {
+        let collider1_entity;
+        let collider2_entity;
+
+        match contact_event {
+            ContactEvent::Started(collider1, collider2) => {
+                collider1_entity = collider1.entity();
+                collider2_entity = collider2.entity();
+            }
+            ContactEvent::Stopped(_, _) => {
+                continue;
+            }
+        }
+
+        let thrown_entity;
+        let hit_entity;
+
+        if thrown_items.get(collider1_entity).is_ok() {
+            thrown_entity = collider1_entity;
+            hit_entity = collider2_entity;
+        } else if thrown_items.get(collider2_entity).is_ok() {
+            thrown_entity = collider2_entity;
+            hit_entity = collider1_entity;
+        } else {
+            continue;
+        }
+
+        // Thrown gets removed at the end of the frame, only the first contact counts.
+        if landed_entities.contains(&thrown_entity) {
+            continue;
+        }
+
+        let (
+            thrown_component,
+            throwable_component,
+            inventory_item_component,
+            item_examinable_component,
+            item_rigid_body_position_component,
+        ) = thrown_items.get(thrown_entity).unwrap();
+
+        if hit_entity == thrown_component.thrower_entity {
+            continue;
+        }
+
+        landed_entities.push(thrown_entity);
+
+        commands.entity(thrown_entity).remove::<Thrown>();
+
+        match collider_flags.get_mut(thrown_entity) {
+            Ok(mut collider_flags_component) => {
+                collider_flags_component.active_events = ActiveEvents::empty();
+            }
+            Err(_rr) => {}
+        }
+
+        if throwable_component.fuse.is_none() && throwable_component.effect.is_some() {
+            trigger_throwable.send(TriggerThrowable {
+                entity: thrown_entity,
+                thrower_option: Some(thrown_component.thrower_entity),
+            });
+        }
+
+        let mut damage_model;
+
+        match &throwable_component.impact_damage_model {
+            Some(impact_damage_model) => {
+                damage_model = impact_damage_model.clone();
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let impact_position: Vec3 = item_rigid_body_position_component
+            .position
+            .translation
+            .into();
+
+        let thrower_position: Vec3;
+
+        match rigid_body_positions.get(thrown_component.thrower_entity) {
+            Ok(rigid_body_position_component) => {
+                thrower_position = rigid_body_position_component.position.translation.into();
+            }
+            Err(_rr) => {
+                thrower_position = impact_position;
+            }
+        }
+
+        let attacker_cell_id = world_to_cell_id(thrower_position);
+        let weapon_name = item_examinable_component.name.get_name().to_string();
+        let weapon_a_name = item_examinable_component.name.get_a_name();
+        let offense_words = InventoryItem::get_default_strike_words();
+        let trigger_words = vec!["thrown".to_string()];
+
+        let hit_result;
+        let hit_sound_surface;
+        let log_target;
+
+        match health_entities.get_mut(hit_entity) {
+            Ok((mut health_component, examinable_component, rigid_body_position_component)) => {
+                let attacked_position: Vec3 =
+                    rigid_body_position_component.position.translation.into();
+
+                hit_sound_surface = health_component.hit_sound_surface.clone();
+                log_target = CombatLogTarget::Entity(
+                    hit_entity,
+                    examinable_component.name.get_name().to_string(),
+                );
+
+                let mut prevented_reason_option = None;
+
+                match (
+                    pawns.get(thrown_component.thrower_entity),
+                    pawns.get(hit_entity),
+                ) {
+                    (Ok(thrower_pawn_component), Ok(hit_pawn_component)) => {
+                        prevented_reason_option = combat_rules.get_prevented_reason(
+                            thrower_pawn_component.job,
+                            thrower_position,
+                            hit_pawn_component.job,
+                            attacked_position,
+                        );
+                    }
+                    _ => {}
+                }
+
+                match prevented_reason_option {
+                    Some(reason) => {
+                        new_personal_message(
+                            &mut net_message_event,
+                            &handle_to_entity,
+                            thrown_component.thrower_entity,
+                            &("Your attack on ".to_string()
+                                + examinable_component.name.get_name()
+                                + " was prevented, "
+                                + &reason
+                                + "."),
+                        );
+                        hit_result = HitResult::Blocked;
+                        damage_model = DamageModel::default();
+                    }
+                    None => {
+                        hit_result = health_component.apply_damage(
+                            "torso",
+                            &damage_model,
+                            &mut net_message_event,
+                            &handle_to_entity,
+                            &attacker_cell_id,
+                            &world_to_cell_id(attacked_position),
+                            &sensers,
+                            &thrown_component.thrower_name,
+                            &examinable_component.name.get_a_name(),
+                            &DamageType::Projectile,
+                            &weapon_name,
+                            &weapon_a_name,
+                            &offense_words,
+                            &trigger_words,
+                        );
+
+                        let (stun, floor) = damage_model.get_status_durations();
+
+                        if matches!(hit_result, HitResult::HitSoft) && (stun > 0. || floor > 0.) {
+                            inflict_status_effects.send(InflictStatusEffects {
+                                entity: hit_entity,
+                                stun,
+                                floor,
+                            });
+                        }
+                    }
+                }
+            }
+            Err(_rr) => {
+                // Walls are the only cells with colliders, look for the one next to the item.
+                let item_cell_id = world_to_cell_id(impact_position);
+                let mut hit_cell_id_option = None;
+
+                for (x, z) in [(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)] {
+                    let cell_id = Vec3Int {
+                        x: item_cell_id.x + x,
+                        y: 0,
+                        z: item_cell_id.z + z,
+                    };
+
+                    match gridmap_main.grid_data.get(&cell_id) {
+                        Some(cell_data) => {
+                            if cell_data.entity == Some(hit_entity) {
+                                hit_cell_id_option = Some(cell_id);
+                                break;
+                            }
+                        }
+                        None => {}
+                    }
+                }
+
+                let hit_cell_id;
+
+                match hit_cell_id_option {
+                    Some(cell_id) => {
+                        hit_cell_id = cell_id;
+                    }
+                    None => {
+                        continue;
+                    }
+                }
+
+                let cell_data = gridmap_main.grid_data.get_mut(&hit_cell_id).unwrap();
+                let cell_name = get_cell_name(cell_data, &gridmap_data);
+
+                hit_sound_surface = cell_data.health.hit_sound_surface.clone();
+                log_target = CombatLogTarget::Cell(hit_cell_id, cell_name.to_string());
+
+                hit_result = cell_data.health.apply_damage(
+                    "torso",
+                    &damage_model,
+                    &mut net_message_event,
+                    &handle_to_entity,
+                    &attacker_cell_id,
+                    &hit_cell_id,
+                    &sensers,
+                    &thrown_component.thrower_name,
+                    &cell_name,
+                    &DamageType::Projectile,
+                    &weapon_name,
+                    &weapon_a_name,
+                    &offense_words,
+                    &trigger_words,
+                );
+            }
+        }
+
+        combat_log.record(CombatLogRecord {
+            time: time.seconds_since_startup(),
+            attacker_entity: thrown_component.thrower_entity,
+            attacker_name: thrown_component.thrower_name.to_string(),
+            weapon_name,
+            target: log_target,
+            limb: "torso".to_string(),
+            damage_model,
+            hit_result,
+            position: impact_position,
+        });
+
+        let sound_transform = Transform::from_translation(impact_position);
+        let sound_set = &inventory_item_component.combat_melee_sound_set;
+
+        match hit_result {
+            HitResult::HitSoft => match hit_sound_surface {
+                HitSoundSurface::Soft => {
+                    sound_set.spawn_hit_sfx(
+                        &mut commands,
+                        sound_transform,
+                        &mut sfx_auto_destroy_timers,
+                    );
+                }
+                HitSoundSurface::Metaloid => {
+                    sound_set.spawn_hit_blocked(
+                        &mut commands,
+                        sound_transform,
+                        &mut sfx_auto_destroy_timers,
+                    );
+                }
+            },
+            HitResult::Blocked => {
+                sound_set.spawn_hit_blocked(
+                    &mut commands,
+                    sound_transform,
+                    &mut sfx_auto_destroy_timers,
+                );
+            }
+            HitResult::Missed => {}
+        }
+    }
+}
+
+pub fn throwable_fuses(
+    time: Res<Time>,
+    mut lit_fuses: Query<(Entity, &mut LitFuse)>,
+    mut trigger_throwable: EventWriter<TriggerThrowable>,
+    mut commands: Commands,
+) {
+    for (entity, mut lit_fuse_component) in lit_fuses.iter_mut() {
+        lit_fuse_component.timer.tick(time.delta());
+
+        if lit_fuse_component.timer.finished() {
+            trigger_throwable.send(TriggerThrowable {
+                entity,
+                thrower_option: lit_fuse_component.thrower_option,
+            });
+            commands.entity(entity).remove::<LitFuse>();
+        }
+    }
+}
+
+pub fn throwable_effects(
+    mut trigger_throwable_events: EventReader<TriggerThrowable>,
+    throwables: Query<(&Throwable, &InventoryItem, &RigidBodyPositionComponent)>,
+    rigid_body_positions: Query<&RigidBodyPositionComponent>,
+    examinables: Query<&Examinable>,
+    pawns: Query<&Pawn>,
+    mut sensers: Query<(Entity, &mut Senser, &RigidBodyPositionComponent)>,
+    combat_resources: (Res<CombatRules>, ResMut<CombatLog>, Res<Time>),
+    mut explosions: EventWriter<Explosion>,
+    mut inflict_status_effects: EventWriter<InflictStatusEffects>,
+    mut net_message_event: EventWriter<NetChatMessage>,
+    handle_to_entity: Res<HandleToEntity>,
+    mut fov_map: ResMut<DoryenMap>,
+    mut smoke_clouds: ResMut<SmokeClouds>,
+    atmospherics_resource: Res<AtmosphericsResource>,
+    mut commands: Commands,
+    mut sfx_auto_destroy_timers: ResMut<SfxAutoDestroyTimers>,
+) {
+    let (combat_rules, mut combat_log, time) = combat_resources;
+
+    for event in trigger_throwable_events.iter() {
+        let throwable_component;
+        let inventory_item_component;
+        let mut position: Vec3;
+
+        match throwables.get(event.entity) {
+            Ok((throwable, inventory_item, rigid_body_position_component)) => {
+                throwable_component = throwable;
+                inventory_item_component = inventory_item;
+                position = rigid_body_position_component.position.translation.into();
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        // Held items go off in the hands of whoever holds them.
+        match inventory_item_component.in_inventory_of_entity {
+            Some(holder_entity) => match rigid_body_positions.get(holder_entity) {
+                Ok(rigid_body_position_component) => {
+                    position = rigid_body_position_component.position.translation.into();
+                }
+                Err(_rr) => {}
+            },
+            None => {}
+        }
+
+        // The effect only goes off once, what remains is an inert item.
+        commands.entity(event.entity).remove::<Throwable>();
+
+        let effect;
+
+        match &throwable_component.effect {
+            Some(throwable_effect) => {
+                effect = throwable_effect;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let weapon_name;
+
+        match examinables.get(event.entity) {
+            Ok(examinable_component) => {
+                weapon_name = examinable_component.name.get_name().to_string();
+            }
+            Err(_rr) => {
+                weapon_name = "".to_string();
+            }
+        }
+
+        // Throwables that nobody threw, or whose thrower is gone, are their own attacker.
+        let mut attacker_entity = event.entity;
+        let mut attacker_name = weapon_name.to_string();
+
+        match event.thrower_option {
+            Some(thrower_entity) => match examinables.get(thrower_entity) {
+                Ok(examinable_component) => {
+                    attacker_entity = thrower_entity;
+                    attacker_name = examinable_component.name.get_name().to_string();
+                }
+                Err(_rr) => {}
+            },
+            None => {}
+        }
+
+        let attacker_position: Vec3;
+
+        match rigid_body_positions.get(attacker_entity) {
+            Ok(rigid_body_position_component) => {
+                attacker_position = rigid_body_position_component.position.translation.into();
+            }
+            Err(_rr) => {
+                attacker_position = position;
+            }
+        }
+
+        match effect {
+            ThrowableEffect::Explosion { power, falloff } => {
+                explosions.send(Explosion {
+                    position,
+                    attacker_entity,
+                    attacker_name,
+                    weapon_name,
+                    power: *power,
+                    falloff: *falloff,
+                });
+            }
+            ThrowableEffect::Flash { radius, stun } => {
+                let flash_cell_id = world_to_cell_id(position);
+                let flash_coords = to_doryen_coordinates(flash_cell_id.x, flash_cell_id.z);
+
+                for (senser_entity, senser_component, rigid_body_position_component) in
+                    sensers.iter()
+                {
+                    let senser_position: Vec3 =
+                        rigid_body_position_component.position.translation.into();
+
+                    let distance = Vec3::new(senser_position.x, 0., senser_position.z)
+                        .distance(Vec3::new(position.x, 0., position.z));
+
+                    if distance > *radius
+                        || !senser_component
+                            .fov
+                            .is_in_fov(flash_coords.0, flash_coords.1)
+                    {
+                        continue;
+                    }
+
+                    let target_name;
+
+                    match examinables.get(senser_entity) {
+                        Ok(examinable_component) => {
+                            target_name = examinable_component.name.get_name().to_string();
+                        }
+                        Err(_rr) => {
+                            target_name = "".to_string();
+                        }
+                    }
+
+                    let mut prevented_reason_option = None;
+
+                    // Throwers can always blind themselves.
+                    if senser_entity != attacker_entity {
+                        match (pawns.get(attacker_entity), pawns.get(senser_entity)) {
+                            (Ok(attacker_pawn_component), Ok(target_pawn_component)) => {
+                                prevented_reason_option = combat_rules.get_prevented_reason(
+                                    attacker_pawn_component.job,
+                                    attacker_position,
+                                    target_pawn_component.job,
+                                    senser_position,
+                                );
+                            }
+                            _ => {}
+                        }
+                    }
+
+                    let stun_duration = stun * (1. - distance / radius);
+
+                    let mut damage_model = DamageModel::default();
+                    let hit_result;
+
+                    match prevented_reason_option {
+                        Some(reason) => {
+                            new_personal_message(
+                                &mut net_message_event,
+                                &handle_to_entity,
+                                attacker_entity,
+                                &("Your attack on ".to_string()
+                                    + &target_name
+                                    + " was prevented, "
+                                    + &reason
+                                    + "."),
+                            );
+                            hit_result = HitResult::Blocked;
+                        }
+                        None => {
+                            inflict_status_effects.send(InflictStatusEffects {
+                                entity: senser_entity,
+                                stun: stun_duration,
+                                floor: 0.,
+                            });
+
+                            new_personal_message(
+                                &mut net_message_event,
+                                &handle_to_entity,
+                                senser_entity,
+                                "[color=#ff003c]You are blinded by a bright flash![/color]",
+                            );
+
+                            damage_model
+                                .damage_flags
+                                .insert(0, DamageFlag::Stun(stun_duration));
+                            hit_result = HitResult::HitSoft;
+                        }
+                    }
+
+                    combat_log.record(CombatLogRecord {
+                        time: time.seconds_since_startup(),
+                        attacker_entity,
+                        attacker_name: attacker_name.to_string(),
+                        weapon_name: weapon_name.to_string(),
+                        target: CombatLogTarget::Entity(senser_entity, target_name),
+                        limb: "head".to_string(),
+                        damage_model,
+                        hit_result,
+                        position: senser_position,
+                    });
+                }
+
+                let sfx_entity = commands
+                    .spawn()
+                    .insert_bundle(Explosion1SfxBundle::new(Transform::from_translation(
+                        position,
+                    )))
+                    .id();
+                sfx_auto_destroy(sfx_entity, &mut sfx_auto_destroy_timers);
+            }
+            ThrowableEffect::Smoke { radius, duration } => {
+                let center_cell_id = world_to_cell_id(position);
+                let cell_radius = (radius / 2.).ceil() as i16;
+
+                let center_cell_id2 = Vec2Int {
+                    x: center_cell_id.x,
+                    y: center_cell_id.z,
+                };
+
+                if AtmosphericsResource::is_id_out_of_range(center_cell_id2) {
+                    continue;
+                }
+
+                // Smoke does not go through walls, windows or closed doors.
+                let reached_cells =
+                    get_unblocked_reach(center_cell_id2, cell_radius, &atmospherics_resource);
+
+                let mut cells = vec![];
+
+                for cell_id in reached_cells {
+                    let cell_position = cell_id_to_world(Vec3Int {
+                        x: cell_id.x,
+                        y: 0,
+                        z: cell_id.y,
+                    });
+                    let distance = Vec3::new(cell_position.x, 0., cell_position.z)
+                        .distance(Vec3::new(position.x, 0., position.z));
+
+                    if distance > *radius
+                        || atmospherics_resource
+                            .atmospherics
+                            .get(get_atmos_index(cell_id))
+                            .unwrap()
+                            .blocked
+                    {
+                        continue;
+                    }
+
+                    cells.push(cell_id);
+                }
+
+                smoke_clouds.clouds.push(SmokeCloud {
+                    cells: cells.clone(),
+                    timer: Timer::from_seconds(*duration, false),
+                });
+
+                update_senser_fovs(&cells, &mut sensers, &mut fov_map, &smoke_clouds);
+            }
+        }
+    }
+}
+
+pub fn dissipate_smoke(
+    time: Res<Time>,
+    mut smoke_clouds: ResMut<SmokeClouds>,
+    mut fov_map: ResMut<DoryenMap>,
+    mut sensers: Query<(Entity, &mut Senser, &RigidBodyPositionComponent)>,
+) {
+    for cloud in smoke_clouds.clouds.iter_mut() {
+        cloud.timer.tick(time.delta());
+    }
+
+    let (dissipated_clouds, remaining_clouds): (Vec<SmokeCloud>, Vec<SmokeCloud>) = smoke_clouds
+        .clouds
+        .drain(..)
+        .partition(|cloud| cloud.timer.finished());
+
+    smoke_clouds.clouds = remaining_clouds;
+
+    let mut cleared_cells = vec![];
+
+    for cloud in dissipated_clouds {
+        for cell_id in cloud.cells {
+            // Overlapping smoke keeps the cell hidden.
+            if smoke_clouds.is_smoked(&cell_id) || cleared_cells.contains(&cell_id) {
+                continue;
+            }
+
+            cleared_cells.push(cell_id);
+        }
+    }
+
+    if !cleared_cells.is_empty() {
+        update_senser_fovs(&cleared_cells, &mut sensers, &mut fov_map, &smoke_clouds);
+    }
+}
+
+// Sensers that could see any of the changed cells need their field of view recomputed.
+fn update_senser_fovs(
+    changed_cells: &Vec<Vec2Int>,
+    sensers: &mut Query<(Entity, &mut Senser, &RigidBodyPositionComponent)>,
+    fov_map: &mut ResMut<DoryenMap>,
+    smoke_clouds: &SmokeClouds,
+) {
+    for (_entity, mut senser_component, _rigid_body_position_component) in sensers.iter_mut() {
+        let sees_change = changed_cells.iter().any(|cell_id| {
+            let coords = to_doryen_coordinates(cell_id.x, cell_id.y);
+            senser_component.fov.is_in_fov(coords.0, coords.1)
+        });
+
+        if !sees_change {
+            continue;
+        }
+
+        compute_senser_fov(&mut senser_component, fov_map, smoke_clouds);
+    }
+}
@@ -0,0 +1,20 @@
+use bevy_ecs::{entity::Entity, prelude::Component};
+
+use crate::space::entities::air_locks::components::LockedStatus;
+
+use super::resources::{SavedHealth, SavedPawn, SavedStatusEffects, SavedThrowable};
+
+// Saved state waiting to be applied once the spawn commands of a loaded entity have been applied.
+#[derive(Component)]
+pub struct RestoreSavedState {
+    pub health: Option<SavedHealth>,
+    pub inventory_slots: Vec<(String, Option<Entity>)>,
+    pub active_slot: Option<String>,
+    pub air_lock: Option<LockedStatus>,
+    pub air_lock_open: bool,
+    pub storage_open: Option<bool>,
+    pub ammo_amount: Option<u32>,
+    pub status_effects: Option<SavedStatusEffects>,
+    pub throwable: Option<SavedThrowable>,
+    pub pawn: Option<SavedPawn>,
+}
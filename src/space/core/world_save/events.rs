@@ -0,0 +1,10 @@
+pub struct SaveWorld {
+    pub name: String,
+    // The RCON user to report back to.
+    pub handle_option: Option<u32>,
+}
+
+pub struct LoadWorld {
+    pub name: String,
+    pub handle_option: Option<u32>,
+}
@@ -0,0 +1,169 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use bevy_app::EventWriter;
+use bevy_log::{info, warn};
+
+use crate::space::core::{
+    atmospherics::{
+        functions::snapshot::{atmospherics_to_csv, get_occupied_bounds},
+        resources::AtmosphericsResource,
+    },
+    configuration::functions::is_valid_file_name,
+    console_commands::events::NetConsoleCommands,
+    networking::resources::ReliableServerMessage,
+    pawn::functions::{CONSOLE_ERROR_COLOR, CONSOLE_SUCCESS_COLOR},
+};
+
+use super::resources::{WorldSave, WORLD_SAVE_VERSION};
+
+const ENTITIES_FILE: &str = "entities.json";
+const ATMOSPHERICS_FILE: &str = "atmospherics.csv";
+
+pub fn get_world_save_directory(name: &str) -> PathBuf {
+    Path::new("data").join("world_saves").join(name)
+}
+
+// Every save is a directory holding the entities as json and the atmospherics as csv.
+pub fn write_world_save(
+    name: &str,
+    world_save: &WorldSave,
+    atmospherics_resource: &AtmosphericsResource,
+) -> Result<PathBuf, String> {
+    if !is_valid_file_name(name) {
+        return Err("Invalid save name.".to_string());
+    }
+
+    let directory = get_world_save_directory(name);
+
+    match fs::create_dir_all(&directory) {
+        Ok(_) => {}
+        Err(rr) => {
+            return Err(rr.to_string());
+        }
+    }
+
+    let entities_json;
+
+    match serde_json::to_string(world_save) {
+        Ok(j) => {
+            entities_json = j;
+        }
+        Err(rr) => {
+            return Err(rr.to_string());
+        }
+    }
+
+    write_file_atomic(&directory.join(ENTITIES_FILE), &entities_json)?;
+
+    match get_occupied_bounds(atmospherics_resource) {
+        Some((min, max)) => {
+            write_file_atomic(
+                &directory.join(ATMOSPHERICS_FILE),
+                &atmospherics_to_csv(atmospherics_resource, min, max),
+            )?;
+        }
+        None => {}
+    }
+
+    Ok(directory)
+}
+
+// Returns the save and the atmospherics csv if the save has one.
+pub fn read_world_save(name: &str) -> Result<(WorldSave, Option<String>), String> {
+    if !is_valid_file_name(name) {
+        return Err("Invalid save name.".to_string());
+    }
+
+    let directory = get_world_save_directory(name);
+
+    let entities_json;
+
+    match fs::read_to_string(directory.join(ENTITIES_FILE)) {
+        Ok(s) => {
+            entities_json = s;
+        }
+        Err(rr) => {
+            return Err(rr.to_string());
+        }
+    }
+
+    let world_save: WorldSave;
+
+    match serde_json::from_str(&entities_json) {
+        Ok(w) => {
+            world_save = w;
+        }
+        Err(rr) => {
+            return Err(rr.to_string());
+        }
+    }
+
+    if world_save.version != WORLD_SAVE_VERSION {
+        return Err("Unsupported save version ".to_string() + &world_save.version.to_string());
+    }
+
+    let atmospherics_csv;
+
+    match fs::read_to_string(directory.join(ATMOSPHERICS_FILE)) {
+        Ok(s) => {
+            atmospherics_csv = Some(s);
+        }
+        Err(_rr) => {
+            atmospherics_csv = None;
+        }
+    }
+
+    Ok((world_save, atmospherics_csv))
+}
+
+// A crash halfway through writing must not destroy the previous save.
+fn write_file_atomic(path: &Path, contents: &str) -> Result<(), String> {
+    let temporary_path = path.with_extension("tmp");
+
+    match fs::write(&temporary_path, contents) {
+        Ok(_) => {}
+        Err(rr) => {
+            return Err(rr.to_string());
+        }
+    }
+
+    match fs::rename(&temporary_path, path) {
+        Ok(_) => Ok(()),
+        Err(rr) => Err(rr.to_string()),
+    }
+}
+
+// Report to the RCON user that asked for it, or to the server log for autosaves and startup loads.
+pub fn report_world_save_result(
+    handle_option: Option<u32>,
+    message: String,
+    success: bool,
+    net_console_commands: &mut EventWriter<NetConsoleCommands>,
+) {
+    match handle_option {
+        Some(handle) => {
+            let color;
+            if success {
+                color = CONSOLE_SUCCESS_COLOR;
+            } else {
+                color = CONSOLE_ERROR_COLOR;
+            }
+            net_console_commands.send(NetConsoleCommands {
+                handle,
+                message: ReliableServerMessage::ConsoleWriteLine(
+                    "[color=".to_string() + color + "]" + &message + "[/color]",
+                ),
+            });
+        }
+        None => {
+            if success {
+                info!("{}", message);
+            } else {
+                warn!("{}", message);
+            }
+        }
+    }
+}
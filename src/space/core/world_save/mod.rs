@@ -0,0 +1,64 @@
+pub mod components;
+pub mod events;
+pub mod functions;
+pub mod resources;
+pub mod systems;
+
+use std::env;
+
+use bevy_app::{App, EventWriter, Plugin};
+use bevy_core::FixedTimestep;
+use bevy_ecs::schedule::{ParallelSystemDescriptorCoercion, SystemSet};
+use bevy_log::warn;
+
+use crate::space::StartupLabels;
+
+use self::{
+    events::{LoadWorld, SaveWorld},
+    systems::{
+        autosave::{autosave, AUTOSAVE_INTERVAL},
+        load_world::load_world,
+        restore_saved_state::restore_saved_state,
+        save_world::save_world,
+    },
+};
+
+// Start the server with --load-world <name> to continue from a save, --load-world autosave recovers from a crash
+// and autosave1, autosave2.. go further back.
+pub fn startup_load_world(mut load_world: EventWriter<LoadWorld>) {
+    let args: Vec<String> = env::args().collect();
+
+    for (i, arg) in args.iter().enumerate() {
+        if arg == "--load-world" {
+            match args.get(i + 1) {
+                Some(name) => {
+                    load_world.send(LoadWorld {
+                        name: name.to_string(),
+                        handle_option: None,
+                    });
+                }
+                None => {
+                    warn!("--load-world requires a save name.");
+                }
+            }
+        }
+    }
+}
+
+pub struct WorldSavePlugin;
+
+impl Plugin for WorldSavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SaveWorld>()
+            .add_event::<LoadWorld>()
+            .add_system(save_world)
+            .add_system(load_world)
+            .add_system(restore_saved_state)
+            .add_system_set(
+                SystemSet::new()
+                    .with_run_criteria(FixedTimestep::step(AUTOSAVE_INTERVAL))
+                    .with_system(autosave),
+            )
+            .add_startup_system(startup_load_world.after(StartupLabels::InitAtmospherics));
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::space::{
+    core::{
+        health::components::{HealthContainer, RaegentContainer},
+        networking::resources::ConsoleCommandVariantValues,
+    },
+    entities::air_locks::components::LockedStatus,
+};
+
+pub const WORLD_SAVE_VERSION: u32 = 2;
+
+#[derive(Serialize, Deserialize)]
+pub struct WorldSave {
+    pub version: u32,
+    pub entities: Vec<SavedEntity>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SavedEntity {
+    // Entity bits at the time of saving, only used to remap references between saved entities.
+    pub id: u64,
+    pub entity_name: String,
+    pub translation: [f32; 3],
+    pub rotation: [f32; 4],
+    pub default_map_spawn: bool,
+    pub grid_cell: Option<[i16; 3]>,
+    pub properties: HashMap<String, ConsoleCommandVariantValues>,
+    // Saved id of the entity carrying this entity in its inventory.
+    pub holder: Option<u64>,
+    pub health: Option<SavedHealth>,
+    pub inventory: Option<SavedInventory>,
+    pub air_lock: Option<LockedStatus>,
+    pub air_lock_open: bool,
+    pub storage_open: Option<bool>,
+    pub ammo_amount: Option<u32>,
+    pub status_effects: Option<SavedStatusEffects>,
+    // Only live throwables get saved, spent ones are loaded without their Throwable component.
+    pub throwable: Option<SavedThrowable>,
+    pub pawn: Option<SavedPawn>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SavedHealth {
+    pub health_container: HealthContainer,
+    pub raegent_container: RaegentContainer,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SavedInventory {
+    // Slot names with the saved ids of the items in them.
+    pub slots: Vec<(String, Option<u64>)>,
+    pub active_slot: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SavedPawn {
+    pub character_name: String,
+    pub user_name: String,
+}
+
+// Seconds left on the stun and floor timers of a humanoid.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SavedStatusEffects {
+    pub stun: Option<f32>,
+    pub floor: Option<f32>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SavedThrowable {
+    // Seconds left on a fuse that was lit before saving.
+    pub fuse: Option<f32>,
+}
@@ -0,0 +1,59 @@
+use std::fs;
+
+use bevy_app::EventWriter;
+use bevy_log::warn;
+
+use crate::space::core::world_save::{events::SaveWorld, functions::get_world_save_directory};
+
+pub const AUTOSAVE_NAME: &str = "autosave";
+// In seconds.
+pub const AUTOSAVE_INTERVAL: f64 = 300.;
+// The newest autosave is "autosave", older ones are kept as "autosave1", "autosave2"..
+pub const AUTOSAVE_SLOTS: usize = 3;
+
+fn get_autosave_name(slot: usize) -> String {
+    if slot == 0 {
+        AUTOSAVE_NAME.to_string()
+    } else {
+        AUTOSAVE_NAME.to_string() + &slot.to_string()
+    }
+}
+
+// Move every autosave one slot back so a bad autosave never replaces the only one.
+fn rotate_autosaves() {
+    for slot in (0..AUTOSAVE_SLOTS - 1).rev() {
+        let directory = get_world_save_directory(&get_autosave_name(slot));
+
+        if !directory.exists() {
+            continue;
+        }
+
+        let older_directory = get_world_save_directory(&get_autosave_name(slot + 1));
+
+        if older_directory.exists() {
+            match fs::remove_dir_all(&older_directory) {
+                Ok(_) => {}
+                Err(rr) => {
+                    warn!("Couldn't remove old autosave: {}", rr);
+                    continue;
+                }
+            }
+        }
+
+        match fs::rename(&directory, &older_directory) {
+            Ok(_) => {}
+            Err(rr) => {
+                warn!("Couldn't rotate autosave: {}", rr);
+            }
+        }
+    }
+}
+
+pub fn autosave(mut save_world: EventWriter<SaveWorld>) {
+    rotate_autosaves();
+
+    save_world.send(SaveWorld {
+        name: AUTOSAVE_NAME.to_string(),
+        handle_option: None,
+    });
+}
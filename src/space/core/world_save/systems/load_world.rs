@@ -0,0 +1,399 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy_app::{EventReader, EventWriter};
+use bevy_ecs::{
+    entity::Entity,
+    prelude::Without,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_math::{Quat, Vec3};
+use bevy_transform::{components::Transform, hierarchy::DespawnRecursiveExt};
+
+use crate::space::core::{
+    atmospherics::{functions::snapshot::atmospherics_from_csv, resources::AtmosphericsResource},
+    connected_player::{components::ConnectedPlayer, resources::HandleToEntity},
+    console_commands::events::NetConsoleCommands,
+    entity::{
+        components::{EntityData, Showcase},
+        events::NetUnloadEntity,
+        functions::spawn_entity::spawn_entity,
+        resources::{EntityDataResource, SpawnContext, SpawnPawn},
+    },
+    gridmap::resources::{EntityGridData, GridmapMain, Vec3Int},
+    inventory_item::components::InventoryItem,
+    pawn::{
+        components::{Pawn, PersistentPlayerData},
+        resources::UsedNames,
+    },
+    sensable::components::Sensable,
+    world_save::{
+        components::RestoreSavedState,
+        events::LoadWorld,
+        functions::{read_world_save, report_world_save_result},
+        resources::SavedEntity,
+    },
+};
+
+// Replaces every persistent entity with the ones in the save.
+// Pawns of connected players and the items they carry are left alone, saved pawns with the same character
+// names are skipped. Every other saved pawn comes back without a player controlling it.
+pub fn load_world(
+    mut load_world_events: EventReader<LoadWorld>,
+    mut persistent_entities: Query<
+        (
+            Entity,
+            &EntityData,
+            Option<&mut Sensable>,
+            Option<&ConnectedPlayer>,
+            Option<&Pawn>,
+            Option<&InventoryItem>,
+        ),
+        Without<Showcase>,
+    >,
+    mut commands: Commands,
+    entity_data: Res<EntityDataResource>,
+    mut gridmap_main: ResMut<GridmapMain>,
+    mut atmospherics_resource: ResMut<AtmosphericsResource>,
+    mut used_names: ResMut<UsedNames>,
+    handle_to_entity: Res<HandleToEntity>,
+    mut net_unload_entity: EventWriter<NetUnloadEntity>,
+    mut net_console_commands: EventWriter<NetConsoleCommands>,
+) {
+    for event in load_world_events.iter() {
+        let world_save;
+        let atmospherics_csv_option;
+
+        match read_world_save(&event.name) {
+            Ok((w, a)) => {
+                world_save = w;
+                atmospherics_csv_option = a;
+            }
+            Err(rr) => {
+                report_world_save_result(
+                    event.handle_option,
+                    "Failed to load the world: ".to_string() + &rr,
+                    false,
+                    &mut net_console_commands,
+                );
+                continue;
+            }
+        }
+
+        // Invalid atmospherics leave the world as it was.
+        match atmospherics_csv_option {
+            Some(atmospherics_csv) => {
+                match atmospherics_from_csv(&atmospherics_csv, &mut atmospherics_resource) {
+                    Ok(_) => {}
+                    Err(rr) => {
+                        report_world_save_result(
+                            event.handle_option,
+                            "Failed to load the atmospherics of the save: ".to_string() + &rr,
+                            false,
+                            &mut net_console_commands,
+                        );
+                        continue;
+                    }
+                }
+            }
+            None => {}
+        }
+
+        let mut kept_entities = HashSet::new();
+        let mut kept_character_names = HashSet::new();
+
+        for (entity, _, _, connected_player_component_option, pawn_component_option, _) in
+            persistent_entities.iter()
+        {
+            match (connected_player_component_option, pawn_component_option) {
+                (Some(connected_player_component), Some(pawn_component)) => {
+                    if connected_player_component.connected {
+                        kept_entities.insert(entity);
+                        kept_character_names.insert(pawn_component.name.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut despawned_entities = HashSet::new();
+
+        for (
+            entity,
+            entity_data_component,
+            sensable_component_option,
+            _,
+            _,
+            inventory_item_option,
+        ) in persistent_entities.iter_mut()
+        {
+            if kept_entities.contains(&entity)
+                || !entity_data
+                    .name_to_id
+                    .contains_key(&entity_data_component.entity_name)
+            {
+                continue;
+            }
+
+            match inventory_item_option {
+                Some(inventory_item_component) => {
+                    match inventory_item_component.in_inventory_of_entity {
+                        Some(holder_entity) => {
+                            if kept_entities.contains(&holder_entity) {
+                                continue;
+                            }
+                        }
+                        None => {}
+                    }
+                }
+                None => {}
+            }
+
+            match sensable_component_option {
+                Some(mut sensable_component) => {
+                    sensable_component.despawn(entity, &mut net_unload_entity, &handle_to_entity);
+                }
+                None => {}
+            }
+
+            commands.entity(entity).despawn_recursive();
+            despawned_entities.insert(entity);
+        }
+
+        gridmap_main
+            .entity_data
+            .retain(|_, entity_grid_data| !despawned_entities.contains(&entity_grid_data.entity));
+        used_names
+            .names
+            .retain(|_, entity| !despawned_entities.contains(entity));
+        used_names
+            .user_names
+            .retain(|_, entity| !despawned_entities.contains(entity));
+
+        // Saved ids mapped to the entities spawned for them.
+        let mut spawned_entities: HashMap<u64, Entity> = HashMap::new();
+        let mut skipped_ids = HashSet::new();
+
+        for saved_entity in world_save.entities.iter() {
+            match &saved_entity.pawn {
+                Some(saved_pawn) => {
+                    if kept_character_names.contains(&saved_pawn.character_name) {
+                        skipped_ids.insert(saved_entity.id);
+                    }
+                }
+                None => {}
+            }
+        }
+
+        // Holders have to exist before the items they carry, keep going until nothing else can be spawned.
+        let mut pending: Vec<&SavedEntity> = world_save
+            .entities
+            .iter()
+            .filter(|saved_entity| !skipped_ids.contains(&saved_entity.id))
+            .collect();
+
+        loop {
+            let mut still_pending = vec![];
+            let mut progress = false;
+
+            for saved_entity in pending {
+                let holder_entity_option;
+
+                match saved_entity.holder {
+                    Some(holder_id) => {
+                        if skipped_ids.contains(&holder_id) {
+                            skipped_ids.insert(saved_entity.id);
+                            progress = true;
+                            continue;
+                        }
+                        match spawned_entities.get(&holder_id) {
+                            Some(holder_entity) => {
+                                holder_entity_option = Some(*holder_entity);
+                            }
+                            None => {
+                                still_pending.push(saved_entity);
+                                continue;
+                            }
+                        }
+                    }
+                    None => {
+                        holder_entity_option = None;
+                    }
+                }
+
+                match spawn_saved_entity(
+                    saved_entity,
+                    holder_entity_option,
+                    &mut commands,
+                    &entity_data,
+                    &mut used_names,
+                ) {
+                    Some(entity) => {
+                        spawned_entities.insert(saved_entity.id, entity);
+                    }
+                    None => {
+                        skipped_ids.insert(saved_entity.id);
+                    }
+                }
+                progress = true;
+            }
+
+            pending = still_pending;
+
+            if pending.is_empty() {
+                break;
+            }
+
+            // The holder is missing from the save, drop the items where they were.
+            if !progress {
+                for saved_entity in pending.iter() {
+                    match spawn_saved_entity(
+                        saved_entity,
+                        None,
+                        &mut commands,
+                        &entity_data,
+                        &mut used_names,
+                    ) {
+                        Some(entity) => {
+                            spawned_entities.insert(saved_entity.id, entity);
+                        }
+                        None => {}
+                    }
+                }
+                break;
+            }
+        }
+
+        for saved_entity in world_save.entities.iter() {
+            let entity;
+
+            match spawned_entities.get(&saved_entity.id) {
+                Some(e) => {
+                    entity = *e;
+                }
+                None => {
+                    continue;
+                }
+            }
+
+            match saved_entity.grid_cell {
+                Some(cell) => {
+                    gridmap_main.entity_data.insert(
+                        Vec3Int {
+                            x: cell[0],
+                            y: cell[1],
+                            z: cell[2],
+                        },
+                        EntityGridData {
+                            entity,
+                            entity_name: saved_entity.entity_name.to_string(),
+                        },
+                    );
+                }
+                None => {}
+            }
+
+            let mut inventory_slots = vec![];
+            let mut active_slot = None;
+
+            match &saved_entity.inventory {
+                Some(saved_inventory) => {
+                    for (slot_name, saved_item_option) in saved_inventory.slots.iter() {
+                        inventory_slots.push((
+                            slot_name.to_string(),
+                            saved_item_option
+                                .and_then(|saved_item| spawned_entities.get(&saved_item).cloned()),
+                        ));
+                    }
+                    active_slot = Some(saved_inventory.active_slot.to_string());
+                }
+                None => {}
+            }
+
+            commands.entity(entity).insert(RestoreSavedState {
+                health: saved_entity.health.clone(),
+                inventory_slots,
+                active_slot,
+                air_lock: saved_entity.air_lock.clone(),
+                air_lock_open: saved_entity.air_lock_open,
+                storage_open: saved_entity.storage_open,
+                ammo_amount: saved_entity.ammo_amount,
+                status_effects: saved_entity.status_effects.clone(),
+                throwable: saved_entity.throwable.clone(),
+                pawn: saved_entity.pawn.clone(),
+            });
+        }
+
+        report_world_save_result(
+            event.handle_option,
+            "Loaded ".to_string()
+                + &spawned_entities.len().to_string()
+                + " entities from save "
+                + &event.name
+                + ".",
+            true,
+            &mut net_console_commands,
+        );
+    }
+}
+
+fn spawn_saved_entity(
+    saved_entity: &SavedEntity,
+    holder_entity_option: Option<Entity>,
+    commands: &mut Commands,
+    entity_data: &EntityDataResource,
+    used_names: &mut UsedNames,
+) -> Option<Entity> {
+    let transform = Transform {
+        translation: Vec3::from(saved_entity.translation),
+        rotation: Quat::from_xyzw(
+            saved_entity.rotation[0],
+            saved_entity.rotation[1],
+            saved_entity.rotation[2],
+            saved_entity.rotation[3],
+        ),
+        scale: Vec3::ONE,
+    };
+
+    let persistent_player_data = PersistentPlayerData {
+        user_name: "unknownWorldSaveAssigned".to_string(),
+        ..Default::default()
+    };
+
+    let mut spawn_context =
+        SpawnContext::new(transform).properties(saved_entity.properties.clone());
+
+    if saved_entity.default_map_spawn {
+        spawn_context = spawn_context.default_map_spawn();
+    }
+
+    match holder_entity_option {
+        Some(holder_entity) => {
+            spawn_context = spawn_context.held_by(holder_entity);
+        }
+        None => {}
+    }
+
+    match saved_entity.pawn {
+        Some(_) => {
+            // The saved name gets restored once the pawn exists.
+            spawn_context = spawn_context.pawn(SpawnPawn {
+                persistent_player_data: &persistent_player_data,
+                connected_player: None,
+                inventory_setup: vec![],
+                dummy: true,
+                used_names: Some(used_names),
+                default_user_name: None,
+                entity_data,
+            });
+        }
+        None => {}
+    }
+
+    spawn_entity(
+        saved_entity.entity_name.to_string(),
+        commands,
+        entity_data,
+        spawn_context,
+    )
+    .map(|spawned| spawned.entity)
+}
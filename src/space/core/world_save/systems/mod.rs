@@ -0,0 +1,4 @@
+pub mod autosave;
+pub mod load_world;
+pub mod restore_saved_state;
+pub mod save_world;
@@ -0,0 +1,220 @@
+use bevy_app::EventWriter;
+use bevy_core::Timer;
+use bevy_ecs::{
+    entity::Entity,
+    system::{Commands, Query, ResMut},
+};
+
+use crate::space::{
+    core::{
+        ammunition::components::AmmoContainer,
+        examinable::components::Examinable,
+        health::components::Health,
+        humanoid::components::Humanoid,
+        inventory::components::Inventory,
+        pawn::{
+            components::{Pawn, PersistentPlayerData},
+            resources::UsedNames,
+        },
+        storage::components::{StorageContainer, Stored},
+        throwables::components::{LitFuse, Throwable},
+        world_save::components::RestoreSavedState,
+    },
+    entities::air_locks::{
+        components::LockedStatus,
+        events::{AirLockLockClosed, AirLockLockOpen, AirLockOpen},
+    },
+};
+
+pub fn restore_saved_state(
+    mut restored_entities: Query<(
+        Entity,
+        &RestoreSavedState,
+        Option<&mut Health>,
+        Option<&mut Inventory>,
+        Option<&mut Pawn>,
+        Option<&mut PersistentPlayerData>,
+        Option<&mut Humanoid>,
+        Option<&mut Examinable>,
+        Option<&mut StorageContainer>,
+        Option<&mut AmmoContainer>,
+    )>,
+    mut commands: Commands,
+    mut used_names: ResMut<UsedNames>,
+    mut air_lock_lock_open: EventWriter<AirLockLockOpen>,
+    mut air_lock_lock_closed: EventWriter<AirLockLockClosed>,
+    mut air_lock_open: EventWriter<AirLockOpen>,
+) {
+    for (
+        entity,
+        restore_saved_state_component,
+        health_component_option,
+        inventory_component_option,
+        pawn_component_option,
+        persistent_player_data_component_option,
+        mut humanoid_component_option,
+        examinable_component_option,
+        storage_container_component_option,
+        ammo_container_component_option,
+    ) in restored_entities.iter_mut()
+    {
+        match (
+            &restore_saved_state_component.health,
+            health_component_option,
+        ) {
+            (Some(saved_health), Some(mut health_component)) => {
+                health_component.health_container = saved_health.health_container.clone();
+                health_component.raegent_container = saved_health.raegent_container.clone();
+            }
+            _ => {}
+        }
+
+        match inventory_component_option {
+            Some(mut inventory_component) => {
+                for (slot_name, item_option) in restore_saved_state_component.inventory_slots.iter()
+                {
+                    for slot in inventory_component.slots.iter_mut() {
+                        if &slot.slot_name == slot_name {
+                            slot.slot_item = *item_option;
+                        }
+                    }
+                }
+                match &restore_saved_state_component.active_slot {
+                    Some(active_slot) => {
+                        inventory_component.active_slot = active_slot.to_string();
+                    }
+                    None => {}
+                }
+            }
+            None => {}
+        }
+
+        match &restore_saved_state_component.air_lock {
+            Some(LockedStatus::Open) => {
+                air_lock_lock_open.send(AirLockLockOpen {
+                    locked: entity,
+                    locker: entity,
+                });
+            }
+            Some(LockedStatus::Closed) => {
+                air_lock_lock_closed.send(AirLockLockClosed {
+                    locked: entity,
+                    locker: entity,
+                });
+            }
+            Some(LockedStatus::None) | None => {}
+        }
+
+        // Air locks locked open already get opened by their lock.
+        if restore_saved_state_component.air_lock_open
+            && !matches!(
+                restore_saved_state_component.air_lock,
+                Some(LockedStatus::Open)
+            )
+        {
+            air_lock_open.send(AirLockOpen { opened: entity });
+        }
+
+        match (
+            restore_saved_state_component.storage_open,
+            storage_container_component_option,
+        ) {
+            (Some(saved_open), Some(mut storage_container_component)) => {
+                storage_container_component.open = saved_open;
+
+                for (_, item_option) in restore_saved_state_component.inventory_slots.iter() {
+                    match item_option {
+                        Some(item_entity) => {
+                            commands.entity(*item_entity).insert(Stored);
+                        }
+                        None => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        match (
+            restore_saved_state_component.ammo_amount,
+            ammo_container_component_option,
+        ) {
+            (Some(saved_amount), Some(mut ammo_container_component)) => {
+                ammo_container_component.amount =
+                    saved_amount.min(ammo_container_component.capacity);
+            }
+            _ => {}
+        }
+
+        // Wounds and the health state of humanoids follow the restored Health component by themselves.
+        match (
+            &restore_saved_state_component.status_effects,
+            &mut humanoid_component_option,
+        ) {
+            (Some(saved_status_effects), Some(humanoid_component)) => {
+                humanoid_component.stun_timer = saved_status_effects
+                    .stun
+                    .map(|seconds| Timer::from_seconds(seconds, false));
+                humanoid_component.floor_timer = saved_status_effects
+                    .floor
+                    .map(|seconds| Timer::from_seconds(seconds, false));
+            }
+            _ => {}
+        }
+
+        match &restore_saved_state_component.throwable {
+            Some(saved_throwable) => match saved_throwable.fuse {
+                Some(seconds) => {
+                    commands.entity(entity).insert(LitFuse {
+                        timer: Timer::from_seconds(seconds, false),
+                        thrower_option: None,
+                    });
+                }
+                None => {}
+            },
+            None => {
+                // Spent throwables are spawned live again by their prototype.
+                commands.entity(entity).remove::<Throwable>();
+            }
+        }
+
+        match &restore_saved_state_component.pawn {
+            Some(saved_pawn) => {
+                // Loaded pawns were spawned as dummies, give them back their names.
+                match pawn_component_option {
+                    Some(mut pawn_component) => {
+                        used_names.names.remove(&pawn_component.name);
+                        pawn_component.name = saved_pawn.character_name.to_string();
+                    }
+                    None => {}
+                }
+                match persistent_player_data_component_option {
+                    Some(mut persistent_player_data_component) => {
+                        persistent_player_data_component.character_name =
+                            saved_pawn.character_name.to_string();
+                        persistent_player_data_component.user_name =
+                            saved_pawn.user_name.to_string();
+                    }
+                    None => {}
+                }
+                match humanoid_component_option {
+                    Some(mut humanoid_component) => {
+                        humanoid_component.character_name = saved_pawn.character_name.to_string();
+                    }
+                    None => {}
+                }
+                match examinable_component_option {
+                    Some(mut examinable_component) => {
+                        examinable_component.name.name = saved_pawn.character_name.to_string();
+                    }
+                    None => {}
+                }
+                used_names
+                    .names
+                    .insert(saved_pawn.character_name.to_string(), entity);
+            }
+            None => {}
+        }
+
+        commands.entity(entity).remove::<RestoreSavedState>();
+    }
+}
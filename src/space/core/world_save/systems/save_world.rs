@@ -0,0 +1,340 @@
+use std::collections::HashMap;
+
+use bevy_app::{EventReader, EventWriter};
+use bevy_core::Timer;
+use bevy_ecs::{
+    entity::Entity,
+    prelude::Without,
+    system::{Query, Res},
+};
+use bevy_rapier3d::prelude::RigidBodyPositionComponent;
+
+use crate::space::{
+    core::{
+        ammunition::components::AmmoContainer,
+        atmospherics::resources::AtmosphericsResource,
+        console_commands::events::NetConsoleCommands,
+        entity::{
+            components::{DefaultMapEntity, EntityData, Showcase},
+            resources::EntityDataResource,
+        },
+        gridmap::resources::GridmapMain,
+        health::components::Health,
+        humanoid::components::Humanoid,
+        inventory::components::Inventory,
+        inventory_item::components::InventoryItem,
+        networking::resources::ConsoleCommandVariantValues,
+        pawn::components::{Pawn, PersistentPlayerData},
+        storage::components::StorageContainer,
+        throwables::components::{LitFuse, Throwable},
+        world_save::{
+            events::SaveWorld,
+            functions::{report_world_save_result, write_world_save},
+            resources::{
+                SavedEntity, SavedHealth, SavedInventory, SavedPawn, SavedStatusEffects,
+                SavedThrowable, WorldSave, WORLD_SAVE_VERSION,
+            },
+        },
+    },
+    entities::{
+        air_locks::components::{AirLock, AirLockStatus},
+        computers::components::Computer,
+        counter_windows::components::CounterWindow,
+    },
+};
+
+pub fn save_world(
+    mut save_world_events: EventReader<SaveWorld>,
+    persistent_entities: Query<
+        (
+            Entity,
+            &EntityData,
+            Option<&RigidBodyPositionComponent>,
+            Option<&Health>,
+            Option<&Inventory>,
+            Option<&InventoryItem>,
+            Option<&AirLock>,
+            Option<&Pawn>,
+            Option<&PersistentPlayerData>,
+            Option<&Computer>,
+            Option<&DefaultMapEntity>,
+            (
+                Option<&StorageContainer>,
+                Option<&AmmoContainer>,
+                Option<&Humanoid>,
+                Option<&Throwable>,
+                Option<&LitFuse>,
+                Option<&CounterWindow>,
+            ),
+        ),
+        Without<Showcase>,
+    >,
+    entity_data: Res<EntityDataResource>,
+    gridmap_main: Res<GridmapMain>,
+    atmospherics_resource: Res<AtmosphericsResource>,
+    mut net_console_commands: EventWriter<NetConsoleCommands>,
+) {
+    for event in save_world_events.iter() {
+        let mut grid_cells = HashMap::new();
+
+        for (cell_id, entity_grid_data) in gridmap_main.entity_data.iter() {
+            grid_cells.insert(entity_grid_data.entity, [cell_id.x, cell_id.y, cell_id.z]);
+        }
+
+        let mut saved_entities = vec![];
+
+        for (
+            entity,
+            entity_data_component,
+            rigid_body_position_component_option,
+            health_component_option,
+            inventory_component_option,
+            inventory_item_component_option,
+            air_lock_component_option,
+            pawn_component_option,
+            persistent_player_data_component_option,
+            computer_component_option,
+            default_map_entity_option,
+            (
+                storage_container_component_option,
+                ammo_container_component_option,
+                humanoid_component_option,
+                throwable_component_option,
+                lit_fuse_component_option,
+                counter_window_component_option,
+            ),
+        ) in persistent_entities.iter()
+        {
+            // Only entities that can be spawned again by name, children get spawned by their parents.
+            if !entity_data
+                .name_to_id
+                .contains_key(&entity_data_component.entity_name)
+            {
+                continue;
+            }
+
+            let mut translation = [0., 0., 0.];
+            let mut rotation = [0., 0., 0., 1.];
+
+            match rigid_body_position_component_option {
+                Some(rigid_body_position_component) => {
+                    let position = rigid_body_position_component.position;
+                    translation = [
+                        position.translation.x,
+                        position.translation.y,
+                        position.translation.z,
+                    ];
+                    rotation = [
+                        position.rotation.i,
+                        position.rotation.j,
+                        position.rotation.k,
+                        position.rotation.w,
+                    ];
+                }
+                None => {}
+            }
+
+            let mut properties = HashMap::new();
+
+            let mut firelock = false;
+
+            match air_lock_component_option {
+                Some(air_lock_component) => {
+                    firelock = air_lock_component.firelock;
+                }
+                None => {}
+            }
+            match counter_window_component_option {
+                Some(counter_window_component) => {
+                    firelock = counter_window_component.firelock;
+                }
+                None => {}
+            }
+
+            if firelock {
+                properties.insert(
+                    "firelock".to_string(),
+                    ConsoleCommandVariantValues::String("true".to_string()),
+                );
+            }
+
+            match computer_component_option {
+                Some(computer_component) => {
+                    properties.insert(
+                        "computerType".to_string(),
+                        ConsoleCommandVariantValues::String(
+                            computer_component.computer_type.to_string(),
+                        ),
+                    );
+                }
+                None => {}
+            }
+
+            let health;
+
+            match health_component_option {
+                Some(health_component) => {
+                    health = Some(SavedHealth {
+                        health_container: health_component.health_container.clone(),
+                        raegent_container: health_component.raegent_container.clone(),
+                    });
+                }
+                None => {
+                    health = None;
+                }
+            }
+
+            let inventory;
+
+            match inventory_component_option {
+                Some(inventory_component) => {
+                    inventory = Some(SavedInventory {
+                        slots: inventory_component
+                            .slots
+                            .iter()
+                            .map(|slot| {
+                                (
+                                    slot.slot_name.to_string(),
+                                    slot.slot_item.map(|item| item.to_bits()),
+                                )
+                            })
+                            .collect(),
+                        active_slot: inventory_component.active_slot.to_string(),
+                    });
+                }
+                None => {
+                    inventory = None;
+                }
+            }
+
+            let holder;
+
+            match inventory_item_component_option {
+                Some(inventory_item_component) => {
+                    holder = inventory_item_component
+                        .in_inventory_of_entity
+                        .map(|holder_entity| holder_entity.to_bits());
+                }
+                None => {
+                    holder = None;
+                }
+            }
+
+            let pawn;
+
+            match (
+                pawn_component_option,
+                persistent_player_data_component_option,
+            ) {
+                (Some(pawn_component), Some(persistent_player_data_component)) => {
+                    pawn = Some(SavedPawn {
+                        character_name: pawn_component.name.to_string(),
+                        user_name: persistent_player_data_component.user_name.to_string(),
+                    });
+                }
+                _ => {
+                    pawn = None;
+                }
+            }
+
+            let air_lock_open;
+
+            match air_lock_component_option {
+                Some(air_lock_component) => {
+                    air_lock_open = matches!(air_lock_component.status, AirLockStatus::Open);
+                }
+                None => {
+                    air_lock_open = false;
+                }
+            }
+
+            let status_effects;
+
+            match humanoid_component_option {
+                Some(humanoid_component) => {
+                    status_effects = Some(SavedStatusEffects {
+                        stun: humanoid_component.stun_timer.as_ref().map(get_seconds_left),
+                        floor: humanoid_component
+                            .floor_timer
+                            .as_ref()
+                            .map(get_seconds_left),
+                    });
+                }
+                None => {
+                    status_effects = None;
+                }
+            }
+
+            let throwable;
+
+            match throwable_component_option {
+                Some(_) => {
+                    throwable = Some(SavedThrowable {
+                        fuse: lit_fuse_component_option
+                            .map(|lit_fuse_component| get_seconds_left(&lit_fuse_component.timer)),
+                    });
+                }
+                None => {
+                    throwable = None;
+                }
+            }
+
+            saved_entities.push(SavedEntity {
+                id: entity.to_bits(),
+                entity_name: entity_data_component.entity_name.to_string(),
+                translation,
+                rotation,
+                default_map_spawn: default_map_entity_option.is_some(),
+                grid_cell: grid_cells.get(&entity).cloned(),
+                properties,
+                holder,
+                health,
+                inventory,
+                air_lock: air_lock_component_option
+                    .map(|air_lock_component| air_lock_component.locked_status.clone()),
+                air_lock_open,
+                storage_open: storage_container_component_option
+                    .map(|storage_container_component| storage_container_component.open),
+                ammo_amount: ammo_container_component_option
+                    .map(|ammo_container_component| ammo_container_component.amount),
+                status_effects,
+                throwable,
+                pawn,
+            });
+        }
+
+        let saved_amount = saved_entities.len();
+
+        let world_save = WorldSave {
+            version: WORLD_SAVE_VERSION,
+            entities: saved_entities,
+        };
+
+        match write_world_save(&event.name, &world_save, &atmospherics_resource) {
+            Ok(directory) => {
+                report_world_save_result(
+                    event.handle_option,
+                    "Saved ".to_string()
+                        + &saved_amount.to_string()
+                        + " entities to "
+                        + &directory.to_string_lossy()
+                        + ".",
+                    true,
+                    &mut net_console_commands,
+                );
+            }
+            Err(rr) => {
+                report_world_save_result(
+                    event.handle_option,
+                    "Failed to save the world: ".to_string() + &rr,
+                    false,
+                    &mut net_console_commands,
+                );
+            }
+        }
+    }
+}
+
+fn get_seconds_left(timer: &Timer) -> f32 {
+    timer.duration().as_secs_f32() - timer.elapsed_secs()
+}
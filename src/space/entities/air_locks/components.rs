@@ -1,5 +1,6 @@
 use bevy_core::Timer;
 use bevy_ecs::prelude::Component;
+use serde::{Deserialize, Serialize};
 
 use crate::space::core::pawn::components::SpaceAccessEnum;
 
@@ -9,6 +10,11 @@ pub struct AirLock {
     pub access_lights: AccessLightsStatus,
     pub access_permissions: Vec<SpaceAccessEnum>,
     pub locked_status: LockedStatus,
+    // Firelocks shut by themselves and refuse to open when there is a pressure difference across them.
+    // Map entities opt in with the firelock property.
+    pub firelock: bool,
+    // Forced open by someone with access, stays open until it closes by itself.
+    pub firelock_forced: bool,
 }
 
 pub enum AirLockStatus {
@@ -29,10 +35,13 @@ impl Default for AirLock {
             access_lights: AccessLightsStatus::Neutral,
             access_permissions: vec![SpaceAccessEnum::Common],
             locked_status: LockedStatus::None,
+            firelock: false,
+            firelock_forced: false,
         }
     }
 }
 
+#[derive(Serialize, Deserialize, Clone)]
 pub enum LockedStatus {
     Open,
     Closed,
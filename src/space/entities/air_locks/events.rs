@@ -26,3 +26,8 @@ pub struct AirLockLockClosed {
     pub locked: Entity,
     pub locker: Entity,
 }
+
+// Opens an air lock without anyone opening it, the open timer still closes it again.
+pub struct AirLockOpen {
+    pub opened: Entity,
+}
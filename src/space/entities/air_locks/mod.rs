@@ -6,7 +6,9 @@ use crate::space::PostUpdateLabels;
 
 use self::{
     entity_update::air_lock_update,
-    events::{AirLockCollision, AirLockLockClosed, AirLockLockOpen, InputAirLockToggleOpen},
+    events::{
+        AirLockCollision, AirLockLockClosed, AirLockLockOpen, AirLockOpen, InputAirLockToggleOpen,
+    },
     systems::{air_lock_added, air_lock_default_map_added, air_lock_events, air_lock_tick_timers},
 };
 
@@ -28,6 +30,7 @@ impl Plugin for AirLocksPlugin {
             .add_system(air_lock_tick_timers)
             .add_system(air_lock_default_map_added)
             .add_event::<AirLockLockClosed>()
+            .add_event::<AirLockOpen>()
             .add_system(air_lock_events)
             .add_system_set_to_stage(
                 PostUpdate,
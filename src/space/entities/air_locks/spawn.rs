@@ -3,14 +3,13 @@ use std::{
     sync::Arc,
 };
 
-use bevy_ecs::{entity::Entity, system::Commands};
+use bevy_ecs::system::Commands;
 use bevy_log::warn;
 use bevy_math::Vec3;
 use bevy_rapier3d::prelude::{
     ActiveEvents, ColliderBundle, ColliderFlags, ColliderShape, InteractionGroups, RigidBodyBundle,
     RigidBodyType,
 };
-use bevy_transform::components::Transform;
 
 use crate::space::{
     core::{
@@ -18,7 +17,7 @@ use crate::space::{
         entity::{
             components::{DefaultMapEntity, EntityData, EntityGroup, EntityUpdates},
             functions::transform_to_isometry::transform_to_isometry,
-            resources::{SpawnHeldData, SpawnPawnData},
+            resources::{SpawnContext, SpawnedEntity},
         },
         examinable::components::{Examinable, RichName},
         health::components::{Health, HealthFlag},
@@ -37,15 +36,9 @@ use super::functions::{lock_closed_action, lock_open_action, toggle_open_action}
 pub struct AirlockBundle;
 
 impl AirlockBundle {
-    pub fn spawn(
-        entity_transform: Transform,
-        commands: &mut Commands,
-        _correct_transform: bool,
-        _pawn_data_option: Option<SpawnPawnData>,
-        _held_data_option: Option<SpawnHeldData>,
-        default_map_spawn: bool,
-        properties: HashMap<String, ConsoleCommandVariantValues>,
-    ) -> Entity {
+    pub fn spawn(commands: &mut Commands, spawn_context: SpawnContext) -> SpawnedEntity {
+        let entity_transform = spawn_context.transform;
+
         let static_transform_component = StaticTransform {
             transform: entity_transform,
         };
@@ -72,7 +65,7 @@ impl AirlockBundle {
 
         let mut entity_name = "";
 
-        match properties.get("entity_name").unwrap() {
+        match spawn_context.properties.get("entity_name").unwrap() {
             ConsoleCommandVariantValues::String(name) => {
                 entity_name = name;
             }
@@ -81,6 +74,17 @@ impl AirlockBundle {
             }
         }
 
+        let firelock;
+
+        match spawn_context.properties.get("firelock") {
+            Some(ConsoleCommandVariantValues::String(value)) => {
+                firelock = value == "true";
+            }
+            _ => {
+                firelock = false;
+            }
+        }
+
         let mut examine_map = BTreeMap::new();
         examine_map.insert(
             0,
@@ -109,6 +113,7 @@ impl AirlockBundle {
             Sensable::default(),
             AirLock {
                 access_permissions: vec![SpaceAccessEnum::Security],
+                firelock,
                 ..Default::default()
             },
             EntityData {
@@ -158,10 +163,10 @@ impl AirlockBundle {
             },
         ));
 
-        if default_map_spawn {
+        if spawn_context.default_map_spawn {
             builder.insert(DefaultMapEntity);
         }
 
-        entity_id
+        SpawnedEntity::new(entity_id)
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use bevy_app::EventReader;
+use bevy_app::{EventReader, EventWriter};
 use bevy_core::{Time, Timer};
 use bevy_ecs::{
     entity::Entity,
@@ -11,8 +11,15 @@ use bevy_rapier3d::prelude::RigidBodyPositionComponent;
 
 use crate::space::{
     core::{
-        atmospherics::{functions::get_atmos_index, resources::AtmosphericsResource},
-        chat::functions::{FURTHER_ITALIC_FONT, HEALTHY_COLOR},
+        atmospherics::{
+            functions::{get_adjacent_pressure_difference, get_atmos_index},
+            resources::AtmosphericsResource,
+        },
+        chat::{
+            events::NetChatMessage,
+            functions::{new_personal_message, FURTHER_ITALIC_FONT, HEALTHY_COLOR},
+        },
+        connected_player::resources::HandleToEntity,
         entity::components::{DefaultMapEntity, EntityData, EntityGroup},
         examinable::components::{Examinable, RichName},
         gridmap::{
@@ -38,12 +45,19 @@ use crate::space::{
 
 use super::{
     components::LockedStatus,
-    events::{AirLockCollision, AirLockLockClosed, AirLockLockOpen, InputAirLockToggleOpen},
+    events::{
+        AirLockCollision, AirLockLockClosed, AirLockLockOpen, AirLockOpen, InputAirLockToggleOpen,
+    },
 };
 
+// kpa
+pub const FIRELOCK_PRESSURE_DIFFERENCE: f32 = 20.;
+
 pub struct AirLockOpenRequest {
     pub opener_option: Option<Entity>,
     pub opened: Entity,
+    // Openers with access may force a firelock open on purpose.
+    pub force_firelock: bool,
 }
 
 pub struct AirLockCloseRequest {
@@ -69,6 +83,9 @@ pub fn air_lock_events(
     mut atmospherics_resource: ResMut<AtmosphericsResource>,
     mut air_lock_lock_open_event: EventReader<AirLockLockOpen>,
     mut air_lock_lock_close_event: EventReader<AirLockLockClosed>,
+    mut air_lock_open_event: EventReader<AirLockOpen>,
+    mut net_new_chat_message_event: EventWriter<NetChatMessage>,
+    handle_to_entity: Res<HandleToEntity>,
 ) {
     let mut close_requests = vec![];
     let mut open_requests = vec![];
@@ -83,6 +100,7 @@ pub fn air_lock_events(
                         open_requests.push(AirLockOpenRequest {
                             opener_option: None,
                             opened: event.locked,
+                            force_firelock: false,
                         });
                     }
                 }
@@ -169,6 +187,32 @@ pub fn air_lock_events(
             }
             None => {}
         }
+
+        match air_lock_component.status {
+            AirLockStatus::Open => {
+                if air_lock_component.firelock
+                    && !air_lock_component.firelock_forced
+                    && get_air_lock_pressure_difference(
+                        &rigid_body_position_component,
+                        &atmospherics_resource,
+                    ) > FIRELOCK_PRESSURE_DIFFERENCE
+                {
+                    close_requests.push(AirLockCloseRequest {
+                        interacter_option: None,
+                        interacted: air_lock_entity,
+                    });
+                }
+            }
+            AirLockStatus::Closed => {}
+        }
+    }
+
+    for event in air_lock_open_event.iter() {
+        open_requests.push(AirLockOpenRequest {
+            opener_option: None,
+            opened: event.opened,
+            force_firelock: false,
+        });
     }
 
     for event in toggle_open_action.iter() {
@@ -192,6 +236,7 @@ pub fn air_lock_events(
                     open_requests.push(AirLockOpenRequest {
                         opener_option: Some(event.opener),
                         opened: Entity::from_bits(event.opened),
+                        force_firelock: true,
                     });
                 }
             },
@@ -218,6 +263,7 @@ pub fn air_lock_events(
         open_requests.push(AirLockOpenRequest {
             opener_option: Some(pawn_entity),
             opened: air_lock_entity,
+            force_firelock: false,
         });
     }
 
@@ -280,6 +326,39 @@ pub fn air_lock_events(
             }
         }
 
+        let mut firelock_forced = false;
+
+        if pawn_has_permission == true
+            && air_lock_component.firelock
+            && get_air_lock_pressure_difference(
+                &air_lock_rigid_body_position_component,
+                &atmospherics_resource,
+            ) > FIRELOCK_PRESSURE_DIFFERENCE
+        {
+            match request.opener_option {
+                Some(opener) => {
+                    if request.force_firelock {
+                        firelock_forced = true;
+                        new_personal_message(
+                            &mut net_new_chat_message_event,
+                            &handle_to_entity,
+                            opener,
+                            "You force the firelock open against a pressure difference!",
+                        );
+                    } else {
+                        pawn_has_permission = false;
+                        new_personal_message(
+                            &mut net_new_chat_message_event,
+                            &handle_to_entity,
+                            opener,
+                            "The firelock holds back a pressure difference. Toggle it open to force it.",
+                        );
+                    }
+                }
+                None => {}
+            }
+        }
+
         if pawn_has_permission == true {
             let cell_id = world_to_cell_id(
                 air_lock_rigid_body_position_component
@@ -302,6 +381,7 @@ pub fn air_lock_events(
 
             atmospherics.blocked = false;
             air_lock_component.status = AirLockStatus::Open;
+            air_lock_component.firelock_forced = firelock_forced;
             air_lock_component.access_lights = AccessLightsStatus::Granted;
 
             let mut air_lock_rigid_body_position = air_lock_rigid_body_position_component.position;
@@ -349,6 +429,13 @@ pub fn air_lock_events(
                 _timer_closed_component_option,
                 air_lock_entity,
             )) => {
+                match air_lock_component.status {
+                    AirLockStatus::Open => {}
+                    AirLockStatus::Closed => {
+                        continue;
+                    }
+                }
+
                 match air_lock_component.locked_status {
                     LockedStatus::Open => {
                         continue;
@@ -411,6 +498,7 @@ pub fn air_lock_events(
 
                 atmospherics.blocked = true;
                 air_lock_component.status = AirLockStatus::Closed;
+                air_lock_component.firelock_forced = false;
 
                 commands
                     .entity(air_lock_entity)
@@ -422,6 +510,21 @@ pub fn air_lock_events(
     }
 }
 
+pub fn get_air_lock_pressure_difference(
+    rigid_body_position_component: &RigidBodyPositionComponent,
+    atmospherics_resource: &AtmosphericsResource,
+) -> f32 {
+    let cell_id = world_to_cell_id(rigid_body_position_component.position.translation.into());
+    let cell_id2 = Vec2Int {
+        x: cell_id.x,
+        y: cell_id.z,
+    };
+    if AtmosphericsResource::is_id_out_of_range(cell_id2) {
+        return 0.;
+    }
+    get_adjacent_pressure_difference(cell_id2, atmospherics_resource)
+}
+
 pub fn air_lock_tick_timers(
     time: Res<Time>,
     mut query_timer: Query<&mut Timer>,
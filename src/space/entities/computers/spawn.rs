@@ -1,6 +1,5 @@
 use std::collections::{BTreeMap, HashMap};
 
-use bevy_app::EventWriter;
 use bevy_ecs::{entity::Entity, system::Commands};
 use bevy_log::warn;
 use bevy_math::{Mat4, Quat, Vec3};
@@ -17,7 +16,7 @@ use crate::space::{
             components::{EntityData, EntityUpdates, Showcase},
             events::NetShowcase,
             functions::transform_to_isometry::transform_to_isometry,
-            resources::{SpawnHeldData, SpawnPawnData},
+            resources::{SpawnContext, SpawnedEntity},
         },
         examinable::components::{Examinable, RichName},
         health::components::{DamageFlag, Health},
@@ -38,48 +37,17 @@ pub const STANDARD_BODY_FRICTION: f32 = 0.125;
 pub struct ComputerBundle;
 
 impl ComputerBundle {
-    pub fn spawn(
-        passed_transform: Transform,
-        commands: &mut Commands,
-        correct_transform: bool,
-        _pawn_data_option: Option<SpawnPawnData>,
-        _held_data_option: Option<SpawnHeldData>,
-        _default_map_spawn: bool,
-        properties: HashMap<String, ConsoleCommandVariantValues>,
-    ) -> Entity {
-        spawn_entity(
-            commands,
-            Some(passed_transform),
-            false,
-            None,
-            false,
-            None,
-            &mut None,
-            correct_transform,
-            properties,
-        )
+    pub fn spawn(commands: &mut Commands, spawn_context: SpawnContext) -> SpawnedEntity {
+        SpawnedEntity::new(spawn_entity(commands, spawn_context))
     }
 }
 
-fn spawn_entity(
-    commands: &mut Commands,
+fn spawn_entity(commands: &mut Commands, spawn_context: SpawnContext) -> Entity {
+    let held = spawn_context.holder_entity.is_some();
 
-    passed_transform_option: Option<Transform>,
-
-    held: bool,
-    _holder_entity_option: Option<Entity>,
-
-    showcase_instance: bool,
-    showcase_handle_option: Option<u32>,
-
-    net_showcase: &mut Option<&mut EventWriter<NetShowcase>>,
-
-    correct_transform: bool,
-    properties: HashMap<String, ConsoleCommandVariantValues>,
-) -> Entity {
     let computer_type;
 
-    match properties.get("computerType").unwrap() {
+    match spawn_context.properties.get("computerType").unwrap() {
         ConsoleCommandVariantValues::String(s) => {
             computer_type = s.to_string();
         }
@@ -96,16 +64,13 @@ fn spawn_entity(
         Vec3::new(0., 0.355, 0.),
     ));
 
-    match passed_transform_option {
-        Some(transform) => {
-            this_transform = transform;
-        }
-        None => {
-            this_transform = default_transform;
-        }
+    if held {
+        this_transform = default_transform;
+    } else {
+        this_transform = spawn_context.transform;
     }
 
-    if correct_transform {
+    if spawn_context.correct_transform {
         this_transform.rotation = default_transform.rotation;
     }
 
@@ -220,25 +185,29 @@ fn spawn_entity(
         },
     ));
 
-    if showcase_instance {
-        let handle = showcase_handle_option.unwrap();
-        builder.insert(Showcase { handle: handle });
-        let entity_updates = HashMap::new();
-        net_showcase.as_deref_mut().unwrap().send(NetShowcase {
-            handle: handle,
-            message: ReliableServerMessage::LoadEntity(
-                "entity".to_string(),
-                "bridgeComputer".to_string(),
-                entity_updates,
-                entity_id.to_bits(),
-                true,
-                "main".to_string(),
-                "".to_string(),
-                false,
-            ),
-        });
-    } else {
-        builder.insert_bundle((Sensable::default(), Health::default()));
+    match spawn_context.showcase {
+        Some(showcase) => {
+            builder.insert(Showcase {
+                handle: showcase.handle,
+            });
+            let entity_updates = HashMap::new();
+            showcase.net_showcase.send(NetShowcase {
+                handle: showcase.handle,
+                message: ReliableServerMessage::LoadEntity(
+                    "entity".to_string(),
+                    "bridgeComputer".to_string(),
+                    entity_updates,
+                    entity_id.to_bits(),
+                    true,
+                    "main".to_string(),
+                    "".to_string(),
+                    false,
+                ),
+            });
+        }
+        None => {
+            builder.insert_bundle((Sensable::default(), Health::default()));
+        }
     }
 
     entity_id
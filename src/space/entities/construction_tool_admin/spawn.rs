@@ -3,12 +3,10 @@ use std::{
     sync::Arc,
 };
 
-use bevy_app::EventWriter;
 use bevy_ecs::{
     entity::Entity,
     system::{Commands, Query},
 };
-use bevy_log::warn;
 use bevy_math::{Mat4, Quat, Vec3};
 use bevy_rapier3d::prelude::{
     CoefficientCombineRule, ColliderBundle, ColliderFlags, ColliderMaterial, ColliderPosition,
@@ -24,7 +22,7 @@ use crate::space::{
             components::{EntityData, EntityUpdates, Showcase},
             events::NetShowcase,
             functions::transform_to_isometry::transform_to_isometry,
-            resources::{EntityDataResource, SpawnHeldData, SpawnPawnData},
+            resources::{EntityDataResource, SpawnContext, SpawnedEntity},
         },
         examinable::components::{Examinable, RichName},
         gridmap::resources::CellData,
@@ -32,9 +30,9 @@ use crate::space::{
         inventory::components::{Inventory, SlotType},
         inventory_item::components::{
             CombatAttackAnimation, CombatSoundSet, CombatStandardAnimation, CombatType,
-            InventoryItem,
+            InventoryItem, ItemSize, MeleeAttack,
         },
-        networking::resources::{ConsoleCommandVariantValues, GridMapType, ReliableServerMessage},
+        networking::resources::{GridMapType, ReliableServerMessage},
         pawn::functions::can_reach_entity::REACH_DISTANCE,
         physics::{
             components::{WorldMode, WorldModes},
@@ -48,80 +46,32 @@ use crate::space::{
         tab_actions::components::TabAction,
     },
     entities::{
+        computers::spawn::STANDARD_BODY_FRICTION,
         construction_tool_admin::components::ConstructionTool,
-        helmet_security::spawn::STANDARD_BODY_FRICTION,
     },
 };
 
 pub struct ConstructionToolBundle;
 
 impl ConstructionToolBundle {
-    pub fn spawn(
-        passed_transform: Transform,
-        commands: &mut Commands,
-        correct_transform: bool,
-        _pawn_data_option: Option<SpawnPawnData>,
-        held_data_option: Option<SpawnHeldData>,
-        _default_map_spawn: bool,
-        _properties: HashMap<String, ConsoleCommandVariantValues>,
-    ) -> Entity {
-        match held_data_option {
-            Some(held_data) => {
-                let (holder_entity, showcase_instance, showcase_handle_option, net_showcase) =
-                    held_data.data;
-                spawn_entity(
-                    commands,
-                    None,
-                    true,
-                    Some(holder_entity),
-                    showcase_instance,
-                    showcase_handle_option,
-                    net_showcase,
-                    false,
-                )
-            }
-            None => spawn_entity(
-                commands,
-                Some(passed_transform),
-                false,
-                None,
-                false,
-                None,
-                &mut None,
-                correct_transform,
-            ),
-        }
+    pub fn spawn(commands: &mut Commands, spawn_context: SpawnContext) -> SpawnedEntity {
+        SpawnedEntity::new(spawn_entity(commands, spawn_context))
     }
 }
 
-fn spawn_entity(
-    commands: &mut Commands,
-
-    passed_transform_option: Option<Transform>,
-
-    held: bool,
-    holder_entity_option: Option<Entity>,
+fn spawn_entity(commands: &mut Commands, spawn_context: SpawnContext) -> Entity {
+    let held = spawn_context.holder_entity.is_some();
 
-    showcase_instance: bool,
-    showcase_handle_option: Option<u32>,
-
-    net_showcase: &mut Option<&mut EventWriter<NetShowcase>>,
-
-    correct_transform: bool,
-) -> Entity {
     let mut this_transform;
     let default_transform = Transform::identity();
 
-    match passed_transform_option {
-        Some(transform) => {
-            this_transform = transform;
-        }
-        None => {
-            this_transform = default_transform;
-        }
+    if held {
+        this_transform = default_transform;
+    } else {
+        this_transform = spawn_context.transform;
     }
 
-    if correct_transform {
+    if spawn_context.correct_transform {
         this_transform.rotation = default_transform.rotation;
     }
 
@@ -265,10 +215,11 @@ fn spawn_entity(
         },
         ConstructionTool::default(),
         InventoryItem {
-            in_inventory_of_entity: holder_entity_option,
+            in_inventory_of_entity: spawn_context.holder_entity,
             attachment_transforms: attachment_transforms,
             drop_transform: default_transform,
             slot_type: SlotType::Holster,
+            size: ItemSize::Normal,
             is_attached_when_worn: true,
             combat_attack_animation: CombatAttackAnimation::OneHandedMeleePunch,
             combat_type: CombatType::MeleeDirect,
@@ -277,6 +228,7 @@ fn spawn_entity(
                 damage_flags: melee_damage_flags,
                 ..Default::default()
             },
+            combat_melee_attack: MeleeAttack::default(),
             combat_projectile_damage_model: None,
             combat_melee_sound_set: CombatSoundSet::default(),
             combat_standard_animation: CombatStandardAnimation::StandardStance,
@@ -319,25 +271,29 @@ fn spawn_entity(
         },
     ));
 
-    if showcase_instance {
-        let handle = showcase_handle_option.unwrap();
-        builder.insert(Showcase { handle: handle });
-        let entity_updates = HashMap::new();
-        net_showcase.as_deref_mut().unwrap().send(NetShowcase {
-            handle: handle,
-            message: ReliableServerMessage::LoadEntity(
-                "entity".to_string(),
-                entity_type.to_string(),
-                entity_updates,
-                entity_id.to_bits(),
-                true,
-                "main".to_string(),
-                "".to_string(),
-                false,
-            ),
-        });
-    } else {
-        builder.insert_bundle((Sensable::default(), Health::default()));
+    match spawn_context.showcase {
+        Some(showcase) => {
+            builder.insert(Showcase {
+                handle: showcase.handle,
+            });
+            let entity_updates = HashMap::new();
+            showcase.net_showcase.send(NetShowcase {
+                handle: showcase.handle,
+                message: ReliableServerMessage::LoadEntity(
+                    "entity".to_string(),
+                    entity_type.to_string(),
+                    entity_updates,
+                    entity_id.to_bits(),
+                    true,
+                    "main".to_string(),
+                    "".to_string(),
+                    false,
+                ),
+            });
+        }
+        None => {
+            builder.insert_bundle((Sensable::default(), Health::default()));
+        }
     }
 
     match held {
@@ -356,18 +312,14 @@ fn spawn_entity(
         }
     }
 
-    match holder_entity_option {
+    match spawn_context.holder_entity {
         Some(holder_entity) => {
             builder.insert(RigidBodyLinkTransform {
                 follow_entity: holder_entity,
                 ..Default::default()
             });
         }
-        None => {
-            if held == true {
-                warn!("Spawned entity in held mode but holder_entity_option is none.");
-            }
-        }
+        None => {}
     }
     entity_id
 }
@@ -10,7 +10,6 @@ use bevy_log::{info, warn};
 use bevy_math::Quat;
 use bevy_rapier3d::prelude::RigidBodyPositionComponent;
 use bevy_transform::components::Transform;
-use doryen_fov::FovAlgorithm;
 use rand::Rng;
 
 use crate::space::{
@@ -23,8 +22,9 @@ use crate::space::{
         chat::functions::FURTHER_ITALIC_FONT,
         connected_player::{components::ConnectedPlayer, resources::HandleToEntity},
         entity::{
-            components::EntityData, functions::isometry_to_transform::isometry_to_transform,
-            resources::EntityDataResource,
+            components::EntityData,
+            functions::isometry_to_transform::isometry_to_transform,
+            resources::{EntityDataResource, SpawnContext},
         },
         gridmap::{
             events::RemoveCell,
@@ -36,7 +36,7 @@ use crate::space::{
                 to_doryen_coordinates, CellData, CellUpdate, DoryenMap, EntityGridData,
                 GridmapData, GridmapDetails1, GridmapMain, StructureHealth, Vec2Int, Vec3Int,
             },
-            systems::senser_update_fov::FOV_DISTANCE,
+            systems::senser_update_fov::compute_senser_fov,
         },
         inventory_item::components::InventoryItem,
         networking::resources::{GridMapType, ReliableServerMessage, TextTreeBit},
@@ -45,6 +45,7 @@ use crate::space::{
         sensable::components::Sensable,
         senser::components::Senser,
         sfx::{components::sfx_auto_destroy, resources::SfxAutoDestroyTimers},
+        throwables::resources::SmokeClouds,
     },
     entities::{
         construction_tool_admin::{
@@ -94,7 +95,7 @@ pub fn construction_tool(
     pawns: Query<&Pawn>,
     mut commands: Commands,
     mut sfx_auto_destroy_timers: ResMut<SfxAutoDestroyTimers>,
-    mut fov_map: ResMut<DoryenMap>,
+    fov_resources: (ResMut<DoryenMap>, Res<SmokeClouds>),
     mut sensers: Query<(&mut Senser, &ConnectedPlayer)>,
     mut atmospherics_resource: ResMut<AtmosphericsResource>,
     rigid_bodies: Query<(&RigidBodyPositionComponent, &EntityData), Without<RigidBodyDisabled>>,
@@ -106,6 +107,7 @@ pub fn construction_tool(
         mut input_construction_options_selection_event,
         mut remove_cell_events,
     ) = event_readers;
+    let (mut fov_map, smoke_clouds) = fov_resources;
 
     // Retreive all construction and complex constructions as a text list and make generic client GUI text list call.
     for event in input_construction_options_event.iter() {
@@ -863,6 +865,8 @@ pub fn construction_tool(
                         .unwrap();
                     atmospherics.blocked = properties.atmospherics_blocker;
                     atmospherics.forces_push_up = properties.atmospherics_pushes_up;
+                    atmospherics.thermal_conductivity = properties.thermal_conductivity;
+                    atmospherics.heat_capacity = properties.heat_capacity;
                 } else {
                     // Remove vacuum flag from atmos.
                     atmospherics.effects.remove(&EffectType::Floorless);
@@ -976,14 +980,10 @@ pub fn construction_tool(
                     .mul_quat(spawn_rotation);
 
                 let new_entity = (built_entity_data.spawn_function)(
-                    spawn_transform,
                     &mut commands,
-                    true,
-                    None,
-                    None,
-                    false,
-                    HashMap::new(),
-                );
+                    SpawnContext::new(spawn_transform).correct_transform(),
+                )
+                .entity;
 
                 gridmap_main.entity_data.insert(
                     target_cell_id,
@@ -1036,16 +1036,7 @@ pub fn construction_tool(
         // Send netcode message to all clients who see this tile that it has been updated.
         for (mut senser_component, _connected_player_component) in sensers.iter_mut() {
             if senser_component.fov.is_in_fov(coords.0, coords.1) {
-                senser_component.fov.clear_fov();
-                let coords =
-                    to_doryen_coordinates(senser_component.cell_id.x, senser_component.cell_id.y);
-                senser_component.fov.compute_fov(
-                    &mut fov_map.map,
-                    coords.0,
-                    coords.1,
-                    FOV_DISTANCE,
-                    true,
-                );
+                compute_senser_fov(&mut senser_component, &mut fov_map, &smoke_clouds);
             }
         }
 
@@ -63,6 +63,9 @@ pub struct CounterWindow {
     pub access_lights: CounterWindowAccessLightsStatus,
     pub access_permissions: Vec<SpaceAccessEnum>,
     pub locked_status: LockedStatus,
+    // Shuts by itself and refuses to open when there is a pressure difference across it, like firelock air locks.
+    pub firelock: bool,
+    pub firelock_forced: bool,
 }
 
 pub enum CounterWindowStatus {
@@ -83,6 +86,8 @@ impl Default for CounterWindow {
             access_lights: CounterWindowAccessLightsStatus::Neutral,
             access_permissions: vec![SpaceAccessEnum::Common],
             locked_status: LockedStatus::None,
+            firelock: false,
+            firelock_forced: false,
         }
     }
 }
@@ -1,23 +1,21 @@
-use std::{
-    collections::{BTreeMap, HashMap},
-    sync::Arc,
-};
+use std::{collections::BTreeMap, sync::Arc};
 
-use bevy_ecs::{entity::Entity, system::Commands};
+use bevy_ecs::system::Commands;
 use bevy_math::Vec3;
 use bevy_rapier3d::prelude::{
     ActiveEvents, CoefficientCombineRule, ColliderBundle, ColliderFlags, ColliderMaterial,
     ColliderShape, ColliderType, InteractionGroups, RigidBodyBundle, RigidBodyType,
 };
-use bevy_transform::{components::Transform, hierarchy::BuildChildren};
+use bevy_transform::hierarchy::BuildChildren;
 
 use crate::space::{
     core::{
         chat::functions::{FURTHER_ITALIC_FONT, HEALTHY_COLOR},
+        combat::components::{LaserSurface, LaserSurfaceKind},
         entity::{
             components::{DefaultMapEntity, EntityData, EntityGroup, EntityUpdates},
             functions::transform_to_isometry::transform_to_isometry,
-            resources::{SpawnHeldData, SpawnPawnData},
+            resources::{SpawnContext, SpawnedEntity},
         },
         examinable::components::{Examinable, RichName},
         health::components::Health,
@@ -36,15 +34,9 @@ use super::functions::{lock_closed_action, lock_open_action, toggle_open_action}
 pub struct CounterWindowBundle;
 
 impl CounterWindowBundle {
-    pub fn spawn(
-        entity_transform: Transform,
-        commands: &mut Commands,
-        _correct_transform: bool,
-        _pawn_data_option: Option<SpawnPawnData>,
-        _held_data_option: Option<SpawnHeldData>,
-        default_map_spawn: bool,
-        _properties: HashMap<String, ConsoleCommandVariantValues>,
-    ) -> Entity {
+    pub fn spawn(commands: &mut Commands, spawn_context: SpawnContext) -> SpawnedEntity {
+        let entity_transform = spawn_context.transform;
+
         let static_transform_component = StaticTransform {
             transform: entity_transform,
         };
@@ -95,6 +87,17 @@ impl CounterWindowBundle {
             ..Default::default()
         };
 
+        let firelock;
+
+        match spawn_context.properties.get("firelock") {
+            Some(ConsoleCommandVariantValues::String(value)) => {
+                firelock = value == "true";
+            }
+            _ => {
+                firelock = false;
+            }
+        }
+
         let mut examine_map = BTreeMap::new();
         examine_map.insert(
             0,
@@ -120,6 +123,7 @@ impl CounterWindowBundle {
                 Sensable::default(),
                 CounterWindow {
                     access_permissions: vec![SpaceAccessEnum::Security],
+                    firelock,
                     ..Default::default()
                 },
                 EntityData {
@@ -143,6 +147,11 @@ impl CounterWindowBundle {
                     is_reach_obstacle: true,
                     ..Default::default()
                 },
+                // Lasers pass through the glass like they do through reinforced glass walls.
+                LaserSurface {
+                    kind: LaserSurfaceKind::Refractive,
+                    energy_factor: 0.8,
+                },
                 TabActions {
                     tab_actions: vec![
                         TabAction {
@@ -171,7 +180,7 @@ impl CounterWindowBundle {
             ))
             .id();
 
-        if default_map_spawn {
+        if spawn_context.default_map_spawn {
             parent_builder.insert(DefaultMapEntity);
         }
 
@@ -191,6 +200,9 @@ impl CounterWindowBundle {
 
         commands.entity(parent).push_children(&[child]);
 
-        parent
+        SpawnedEntity {
+            entity: parent,
+            children: vec![child],
+        }
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use bevy_app::EventReader;
+use bevy_app::{EventReader, EventWriter};
 use bevy_core::{Time, Timer};
 use bevy_ecs::{
     entity::Entity,
@@ -14,7 +14,11 @@ use bevy_transform::components::Children;
 use crate::space::{
     core::{
         atmospherics::{functions::get_atmos_index, resources::AtmosphericsResource},
-        chat::functions::{FURTHER_ITALIC_FONT, HEALTHY_COLOR},
+        chat::{
+            events::NetChatMessage,
+            functions::{new_personal_message, FURTHER_ITALIC_FONT, HEALTHY_COLOR},
+        },
+        connected_player::resources::HandleToEntity,
         entity::components::{DefaultMapEntity, EntityData, EntityGroup},
         examinable::components::{Examinable, RichName},
         gridmap::{
@@ -27,7 +31,12 @@ use crate::space::{
         static_body::components::StaticTransform,
     },
     entities::{
-        air_locks::{components::LockedStatus, systems::AirLockCloseRequest},
+        air_locks::{
+            components::LockedStatus,
+            systems::{
+                get_air_lock_pressure_difference, AirLockCloseRequest, FIRELOCK_PRESSURE_DIFFERENCE,
+            },
+        },
         sfx::counter_window::{
             counter_window_closed_sfx::CounterWindowClosedSfxBundle,
             counter_window_denied_sfx::CounterWindowDeniedSfxBundle,
@@ -50,6 +59,7 @@ use super::{
 pub struct CounterWindowOpenRequest {
     pub opener_option: Option<Entity>,
     pub opened: Entity,
+    pub force_firelock: bool,
 }
 
 pub fn counter_window_events(
@@ -72,6 +82,8 @@ pub fn counter_window_events(
     mut atmospherics_resource: ResMut<AtmosphericsResource>,
     mut counter_window_lock_open_events: EventReader<CounterWindowLockOpen>,
     mut counter_window_lock_close_events: EventReader<CounterWindowLockClosed>,
+    mut net_new_chat_message_event: EventWriter<NetChatMessage>,
+    handle_to_entity: Res<HandleToEntity>,
 ) {
     let mut close_requests = vec![];
     let mut open_requests = vec![];
@@ -86,6 +98,7 @@ pub fn counter_window_events(
                         open_requests.push(CounterWindowOpenRequest {
                             opener_option: None,
                             opened: event.locked,
+                            force_firelock: false,
                         });
                     }
                 }
@@ -177,6 +190,24 @@ pub fn counter_window_events(
             }
             None => {}
         }
+
+        match counter_window_component.status {
+            CounterWindowStatus::Open => {
+                if counter_window_component.firelock
+                    && !counter_window_component.firelock_forced
+                    && get_air_lock_pressure_difference(
+                        &rigid_body_position_component,
+                        &atmospherics_resource,
+                    ) > FIRELOCK_PRESSURE_DIFFERENCE
+                {
+                    close_requests.push(AirLockCloseRequest {
+                        interacter_option: None,
+                        interacted: counter_window_entity,
+                    });
+                }
+            }
+            CounterWindowStatus::Closed => {}
+        }
     }
 
     for collision_event in counter_window_sensor_collisions.iter() {
@@ -213,6 +244,7 @@ pub fn counter_window_events(
         open_requests.push(CounterWindowOpenRequest {
             opener_option: Some(pawn_entity),
             opened: counter_window_entity,
+            force_firelock: false,
         });
     }
 
@@ -243,6 +275,7 @@ pub fn counter_window_events(
                         open_requests.push(CounterWindowOpenRequest {
                             opener_option: Some(event.opener),
                             opened: opened_entity,
+                            force_firelock: true,
                         });
                     }
                 }
@@ -339,6 +372,39 @@ pub fn counter_window_events(
             }
         }
 
+        let mut firelock_forced = false;
+
+        if pawn_has_permission == true
+            && counter_window_component.firelock
+            && get_air_lock_pressure_difference(
+                &counter_window_rigid_body_position_component,
+                &atmospherics_resource,
+            ) > FIRELOCK_PRESSURE_DIFFERENCE
+        {
+            match request.opener_option {
+                Some(opener) => {
+                    if request.force_firelock {
+                        firelock_forced = true;
+                        new_personal_message(
+                            &mut net_new_chat_message_event,
+                            &handle_to_entity,
+                            opener,
+                            "You force the counter window open against a pressure difference!",
+                        );
+                    } else {
+                        pawn_has_permission = false;
+                        new_personal_message(
+                            &mut net_new_chat_message_event,
+                            &handle_to_entity,
+                            opener,
+                            "The counter window holds back a pressure difference. Toggle it open to force it.",
+                        );
+                    }
+                }
+                None => {}
+            }
+        }
+
         match counter_window_closed_timer_option {
             Some(mut counter_window_closed_timer) => {
                 counter_window_closed_timer.timer.pause();
@@ -381,6 +447,7 @@ pub fn counter_window_events(
             atmospherics.forces_push_up = true;
 
             counter_window_component.status = CounterWindowStatus::Open;
+            counter_window_component.firelock_forced = firelock_forced;
             counter_window_component.access_lights = CounterWindowAccessLightsStatus::Granted;
 
             let mut counter_window_rigid_body_position =
@@ -469,6 +536,7 @@ pub fn counter_window_events(
                 }
 
                 counter_window_component.status = CounterWindowStatus::Closed;
+                counter_window_component.firelock_forced = false;
 
                 let cell_id =
                     world_to_cell_id(rigid_body_position_component.position.translation.into());
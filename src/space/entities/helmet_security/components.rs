@@ -1,4 +0,0 @@
-use bevy_ecs::prelude::Component;
-
-#[derive(Component)]
-pub struct Helmet;
@@ -5,8 +5,11 @@ use crate::space::core::{
         ASTRIX, FURTHER_ITALIC_FONT, FURTHER_NORMAL_FONT, HEALTHY_COLOR, UNHEALTHY_COLOR,
     },
     examinable::components::Examinable,
-    health::components::Health,
+    health::components::{
+        Health, HealthContainer, HealthState, LimbWound, HUMANOID_LIMBS, MAX_ARMOR,
+    },
     inventory::components::Inventory,
+    medical::functions::get_limb_display_name,
 };
 
 pub fn generate_human_examine_text(
@@ -14,6 +17,7 @@ pub fn generate_human_examine_text(
     inventory_component_option: Option<&Inventory>,
     examinables: &Query<&Examinable>,
     health_component: &Health,
+    health_state: HealthState,
 ) -> String {
     let mut examine_text = "[font=".to_owned()
         + FURTHER_NORMAL_FONT
@@ -24,6 +28,37 @@ pub fn generate_human_examine_text(
         + ", a Security Officer.\n"
         + "He is human.\n";
 
+    let health_state_text;
+
+    match health_state {
+        HealthState::Healthy => {
+            health_state_text = None;
+        }
+        HealthState::Critical => {
+            health_state_text = Some("He is barely standing.");
+        }
+        HealthState::Unconscious => {
+            health_state_text = Some("He is unconscious.");
+        }
+        HealthState::Dead => {
+            health_state_text = Some("He is dead.");
+        }
+    }
+
+    match health_state_text {
+        Some(text) => {
+            examine_text = examine_text
+                + "[font="
+                + FURTHER_ITALIC_FONT
+                + "][color="
+                + UNHEALTHY_COLOR
+                + "]"
+                + text
+                + "[/color][/font]\n";
+        }
+        None => {}
+    }
+
     match &health_component.health_container {
         crate::space::core::health::components::HealthContainer::Humanoid(humanoid_container) => {
             let head_damage = humanoid_container.head_brute
@@ -339,6 +374,37 @@ pub fn generate_human_examine_text(
         _ => (),
     }
 
+    match &health_component.health_container {
+        HealthContainer::Humanoid(humanoid_container) => {
+            for limb in HUMANOID_LIMBS {
+                let wound_text;
+
+                match humanoid_container.get_limb_wound(limb) {
+                    LimbWound::None => {
+                        continue;
+                    }
+                    LimbWound::Bleeding => {
+                        wound_text = " is bleeding.";
+                    }
+                    LimbWound::Broken => {
+                        wound_text = " is broken and bleeding.";
+                    }
+                }
+
+                examine_text = examine_text
+                    + "[font="
+                    + FURTHER_ITALIC_FONT
+                    + "][color="
+                    + UNHEALTHY_COLOR
+                    + "]His "
+                    + &get_limb_display_name(limb)
+                    + wound_text
+                    + "[/color][/font]\n";
+            }
+        }
+        _ => (),
+    }
+
     match inventory_component_option {
         Some(inventory_component) => {
             examine_text = examine_text + "\n";
@@ -386,6 +452,25 @@ pub fn generate_human_examine_text(
         None => {}
     }
 
+    for limb in HUMANOID_LIMBS {
+        let limb_armor = health_component.get_limb_armor(limb);
+
+        if limb_armor.is_empty() {
+            continue;
+        }
+
+        examine_text = examine_text
+            + "His "
+            + &get_limb_display_name(limb)
+            + " is armored against "
+            + &format!(
+                "{:.0}% brute, {:.0}% burn and {:.0}% toxin damage.\n",
+                limb_armor.brute.min(MAX_ARMOR),
+                limb_armor.burn.min(MAX_ARMOR),
+                limb_armor.toxin.min(MAX_ARMOR)
+            );
+    }
+
     examine_text = examine_text + ASTRIX + "[/font]";
 
     examine_text
@@ -22,14 +22,16 @@ use crate::space::core::{
         functions::{
             spawn_entity::spawn_held_entity, transform_to_isometry::transform_to_isometry,
         },
-        resources::{SpawnHeldData, SpawnPawnData},
+        resources::{SpawnContext, SpawnPawn, SpawnShowcase, SpawnedEntity},
     },
     examinable::components::{Examinable, RichName},
-    health::components::{Health, HealthContainer, HumanoidHealth},
+    health::components::{
+        Health, HealthContainer, HumanoidHealth, RaegentContainer, BLOOD, BLOOD_VOLUME,
+    },
     humanoid::components::Humanoid,
     inventory::components::{Inventory, Slot, SlotType},
     map::components::Map,
-    networking::resources::{ConsoleCommandVariantValues, ReliableServerMessage},
+    networking::resources::ReliableServerMessage,
     pawn::components::{
         ControllerInput, Pawn, PersistentPlayerData, SpaceAccess, SpaceAccessEnum, SpaceJobsEnum,
     },
@@ -47,33 +49,49 @@ pub struct HumanMalePawnBundle;
 
 pub const CHARACTER_FLOOR_FRICTION: f32 = 7.2;
 
+const CHARACTER_RADIUS: f32 = 0.5;
+const CHARACTER_HEIGHT: f32 = 1.8;
+const CHARACTER_LYING_HEIGHT: f32 = 1.1;
+
+// Humanoids on the floor get a lower collider so others can get past them.
+pub fn get_character_collider_shape(lying_down: bool) -> ColliderShape {
+    let r = CHARACTER_RADIUS;
+
+    let height;
+
+    if lying_down {
+        height = CHARACTER_LYING_HEIGHT;
+    } else {
+        height = CHARACTER_HEIGHT;
+    }
+
+    ColliderShape::capsule(
+        Vec3::new(0.0, 0.0 + r, 0.0).into(),
+        Vec3::new(0.0, height - r, 0.0).into(),
+        r,
+    )
+}
+
 impl HumanMalePawnBundle {
-    pub fn spawn(
-        passed_transform: Transform,
-        commands: &mut Commands,
-        correct_transform: bool,
-        pawn_data_option: Option<SpawnPawnData>,
-        _held_data_option: Option<SpawnHeldData>,
-        _default_map_spawn: bool,
-        _properties: HashMap<String, ConsoleCommandVariantValues>,
-    ) -> Entity {
-        let (
-            persistent_player_data_component,
-            connected_player_component,
-            passed_inventory_setup,
-            showcase_instance,
-            dummy_instance,
+    pub fn spawn(commands: &mut Commands, spawn_context: SpawnContext) -> SpawnedEntity {
+        let SpawnPawn {
+            persistent_player_data: persistent_player_data_component,
+            connected_player: connected_player_component,
+            inventory_setup: passed_inventory_setup,
+            dummy: dummy_instance,
             used_names,
-            mut net_showcase,
-            default_user_name_option,
+            default_user_name: default_user_name_option,
             entity_data,
-        ) = pawn_data_option.unwrap().data;
+        } = spawn_context.pawn.unwrap();
+
+        let mut showcase_option = spawn_context.showcase;
+        let showcase_instance = showcase_option.is_some();
 
         let default_transform = Transform::identity();
 
-        let mut this_transform = passed_transform;
+        let mut this_transform = spawn_context.transform;
 
-        if correct_transform {
+        if spawn_context.correct_transform {
             this_transform.rotation = default_transform.rotation;
         }
 
@@ -85,9 +103,7 @@ impl HumanMalePawnBundle {
             character_name = persistent_player_data_component.character_name.clone();
         }
 
-        let r = 0.5;
-
-        this_transform.translation.y = 0.9 - r;
+        this_transform.translation.y = 0.9 - CHARACTER_RADIUS;
 
         let friction = CHARACTER_FLOOR_FRICTION;
         let friction_combine_rule = CoefficientCombineRule::Min;
@@ -103,12 +119,7 @@ impl HumanMalePawnBundle {
         let masks = get_bit_masks(ColliderGroup::Standard);
 
         let collider_component = ColliderBundle {
-            shape: ColliderShape::capsule(
-                Vec3::new(0.0, 0.0 + r, 0.0).into(),
-                Vec3::new(0.0, 1.8 - r, 0.0).into(),
-                r,
-            )
-            .into(),
+            shape: get_character_collider_shape(false).into(),
             position: Vec3::ZERO.into(),
             collider_type: ColliderType::Solid.into(),
             material: ColliderMaterial {
@@ -125,6 +136,9 @@ impl HumanMalePawnBundle {
             ..Default::default()
         };
 
+        let mut raegents = HashMap::new();
+        raegents.insert(BLOOD.to_string(), BLOOD_VOLUME);
+
         let mut entity_builder;
 
         if !showcase_instance {
@@ -170,6 +184,7 @@ impl HumanMalePawnBundle {
             DefaultTransform::default(),
             Health {
                 health_container: HealthContainer::Humanoid(HumanoidHealth::default()),
+                raegent_container: RaegentContainer { raegents },
                 is_combat_obstacle: true,
                 ..Default::default()
             },
@@ -184,33 +199,31 @@ impl HumanMalePawnBundle {
         let mut slot_entities: HashMap<String, Entity> = HashMap::new();
 
         for (slot_name, item_name) in passed_inventory_setup.iter() {
-            let entity_option;
-
-            if showcase_instance {
-                entity_option = spawn_held_entity(
-                    item_name.to_string(),
-                    commands,
-                    human_male_entity,
-                    showcase_instance,
-                    Some(connected_player_component.unwrap().handle),
-                    &mut net_showcase,
-                    entity_data,
-                );
-            } else {
-                entity_option = spawn_held_entity(
-                    item_name.to_string(),
-                    commands,
-                    human_male_entity,
-                    showcase_instance,
-                    None,
-                    &mut net_showcase,
-                    entity_data,
-                );
+            let item_showcase_option;
+
+            match &mut showcase_option {
+                Some(showcase) => {
+                    item_showcase_option = Some(SpawnShowcase {
+                        handle: showcase.handle,
+                        net_showcase: &mut *showcase.net_showcase,
+                    });
+                }
+                None => {
+                    item_showcase_option = None;
+                }
             }
 
-            match entity_option {
-                Some(entity) => {
-                    slot_entities.insert(slot_name.to_string(), entity);
+            let spawned_option = spawn_held_entity(
+                item_name.to_string(),
+                commands,
+                human_male_entity,
+                item_showcase_option,
+                entity_data,
+            );
+
+            match spawned_option {
+                Some(spawned) => {
+                    slot_entities.insert(slot_name.to_string(), spawned.entity);
                 }
                 None => {}
             }
@@ -261,6 +274,15 @@ impl HumanMalePawnBundle {
                 holster_hand_item = None;
             }
         }
+        let back_item;
+        match slot_entities.get(&"back".to_string()) {
+            Some(entity) => {
+                back_item = Some(*entity);
+            }
+            None => {
+                back_item = None;
+            }
+        }
 
         let inventory_component = Inventory {
             slots: vec![
@@ -302,6 +324,14 @@ impl HumanMalePawnBundle {
                         "Smoothing/pawn/humanMale/rig/holster/Position3D".to_string(),
                     ),
                 },
+                Slot {
+                    slot_type: SlotType::Back,
+                    slot_name: "back".to_string(),
+                    slot_item: back_item,
+                    slot_attachment: Some(
+                        "Smoothing/pawn/humanMale/rig/back/Position3D".to_string(),
+                    ),
+                },
             ],
             active_slot: "left_hand".to_string(),
             ..Default::default()
@@ -320,81 +350,91 @@ impl HumanMalePawnBundle {
 
         entity_commands.insert_bundle((inventory_component, examinable_component));
 
-        if showcase_instance {
-            entity_commands.insert(Showcase {
-                handle: connected_player_component.unwrap().handle,
-            });
-            let entity_updates = HashMap::new();
-            net_showcase.unwrap().send(NetShowcase {
-                handle: connected_player_component.unwrap().handle,
-                message: ReliableServerMessage::LoadEntity(
-                    "entity".to_string(),
-                    "humanMale".to_string(),
-                    entity_updates,
-                    human_male_entity.to_bits(),
-                    true,
-                    "main".to_string(),
-                    ENTITY_SPAWN_PARENT.to_string(),
-                    false,
-                ),
-            });
-        } else {
-            let mut pawn_component = Pawn {
-                name: character_name.clone(),
-                job: SpaceJobsEnum::Security,
-                ..Default::default()
-            };
-
-            // Add default "examine" tab action.
-            pawn_component.tab_actions_add("examine", None, get_tab_action("examine").unwrap());
-            pawn_component.tab_actions_add("pickup", None, get_tab_action("pickup").unwrap());
-
-            entity_commands.insert_bundle((
-                Senser::default(),
-                Sensable::default(),
-                Radio {
-                    listen_access: vec![RadioChannel::Common, RadioChannel::Security],
-                    speak_access: vec![RadioChannel::Common, RadioChannel::Security],
-                },
-                SpaceAccess {
-                    access: vec![SpaceAccessEnum::Security],
-                },
-                pawn_component,
-                ControllerInput::default(),
-            ));
+        match showcase_option {
+            Some(showcase) => {
+                entity_commands.insert(Showcase {
+                    handle: showcase.handle,
+                });
+                let entity_updates = HashMap::new();
+                showcase.net_showcase.send(NetShowcase {
+                    handle: showcase.handle,
+                    message: ReliableServerMessage::LoadEntity(
+                        "entity".to_string(),
+                        "humanMale".to_string(),
+                        entity_updates,
+                        human_male_entity.to_bits(),
+                        true,
+                        "main".to_string(),
+                        ENTITY_SPAWN_PARENT.to_string(),
+                        false,
+                    ),
+                });
+            }
+            None => {
+                let mut pawn_component = Pawn {
+                    name: character_name.clone(),
+                    job: SpaceJobsEnum::Security,
+                    ..Default::default()
+                };
+
+                // Add default "examine" tab action.
+                pawn_component.tab_actions_add("examine", None, get_tab_action("examine").unwrap());
+                pawn_component.tab_actions_add("pickup", None, get_tab_action("pickup").unwrap());
 
-            if !dummy_instance {
                 entity_commands.insert_bundle((
-                    ConnectedPlayer {
-                        handle: connected_player_component.unwrap().handle,
-                        authid: connected_player_component.unwrap().authid,
-                        ..Default::default()
+                    Senser::default(),
+                    Sensable::default(),
+                    Radio {
+                        listen_access: vec![RadioChannel::Common, RadioChannel::Security],
+                        speak_access: vec![RadioChannel::Common, RadioChannel::Security],
                     },
-                    DataLink {
-                        links: vec![DataLinkType::FullAtmospherics, DataLinkType::RemoteLock],
-                    },
-                    Map {
-                        available_display_modes: vec![
-                            ("Standard".to_string(), "standard".to_string()),
-                            (
-                                "Atmospherics Liveable".to_string(),
-                                "atmospherics_liveable".to_string(),
-                            ),
-                            (
-                                "Atmospherics Temperature".to_string(),
-                                "atmospherics_temperature".to_string(),
-                            ),
-                            (
-                                "Atmospherics Pressure".to_string(),
-                                "atmospherics_pressure".to_string(),
-                            ),
-                        ],
-                        ..Default::default()
+                    SpaceAccess {
+                        access: vec![SpaceAccessEnum::Security],
                     },
+                    pawn_component,
+                    ControllerInput::default(),
                 ));
+
+                if !dummy_instance {
+                    entity_commands.insert_bundle((
+                        ConnectedPlayer {
+                            handle: connected_player_component.unwrap().handle,
+                            authid: connected_player_component.unwrap().authid,
+                            ..Default::default()
+                        },
+                        DataLink {
+                            links: vec![DataLinkType::FullAtmospherics, DataLinkType::RemoteLock],
+                        },
+                        Map {
+                            available_display_modes: vec![
+                                ("Standard".to_string(), "standard".to_string()),
+                                (
+                                    "Atmospherics Liveable".to_string(),
+                                    "atmospherics_liveable".to_string(),
+                                ),
+                                (
+                                    "Atmospherics Temperature".to_string(),
+                                    "atmospherics_temperature".to_string(),
+                                ),
+                                (
+                                    "Atmospherics Pressure".to_string(),
+                                    "atmospherics_pressure".to_string(),
+                                ),
+                                (
+                                    "Atmospherics Fire".to_string(),
+                                    "atmospherics_fire".to_string(),
+                                ),
+                            ],
+                            ..Default::default()
+                        },
+                    ));
+                }
             }
         }
 
-        human_male_entity
+        SpawnedEntity {
+            entity: human_male_entity,
+            children: slot_entities.values().cloned().collect(),
+        }
     }
 }
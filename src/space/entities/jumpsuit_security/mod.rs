@@ -1,2 +0,0 @@
-pub mod components;
-pub mod spawn;
@@ -3,10 +3,7 @@ pub mod computers;
 pub mod construction_tool_admin;
 pub mod counter_windows;
 pub mod gi_probe;
-pub mod helmet_security;
 pub mod human_male_pawn;
-pub mod jumpsuit_security;
 pub mod omni_light;
-pub mod pistol_l1;
 pub mod reflection_probe;
 pub mod sfx;
@@ -0,0 +1,41 @@
+use bevy_transform::components::Transform;
+
+use crate::space::core::{
+    entity::components::{EntityData, EntityUpdates},
+    sensable::components::Sensable,
+    sfx::components::{get_random_pitch_scale, Sfx},
+    static_body::components::StaticTransform,
+};
+
+pub struct Explosion1SfxBundle;
+
+pub const EXPLOSION1_PLAY_BACK_DURATION: f32 = 3. + 1.;
+
+impl Explosion1SfxBundle {
+    pub fn new(
+        passed_transform: Transform,
+    ) -> (StaticTransform, EntityData, Sensable, Sfx, EntityUpdates) {
+        (
+            StaticTransform {
+                transform: passed_transform,
+            },
+            EntityData {
+                entity_class: "SFX".to_string(),
+                ..Default::default()
+            },
+            Sensable {
+                is_audible: true,
+                ..Default::default()
+            },
+            Sfx {
+                unit_db: 40.,
+                unit_size: 5.,
+                stream_id: "/content/audio/combat/explosion1.sample".to_string(),
+                play_back_duration: EXPLOSION1_PLAY_BACK_DURATION,
+                pitch_scale: get_random_pitch_scale(1.0),
+                ..Default::default()
+            },
+            EntityUpdates::default(),
+        )
+    }
+}
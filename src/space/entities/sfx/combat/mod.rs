@@ -1,6 +1,10 @@
+pub mod ballistic_hit1_sfx;
+pub mod ballistic_ricochet1_sfx;
+pub mod ballistic_shot1_sfx;
 pub mod block1_sfx;
 pub mod block2_sfx;
 pub mod block3_sfx;
+pub mod explosion1_sfx;
 pub mod laser_light_block1_sfx;
 pub mod laser_light_block2_sfx;
 pub mod laser_light_block3_sfx;
@@ -17,3 +21,4 @@ pub mod punch1_sfx;
 pub mod punch2_sfx;
 pub mod punch3_sfx;
 pub mod punch4_sfx;
+pub mod weapon_empty_sfx;
@@ -0,0 +1,41 @@
+use bevy_transform::components::Transform;
+
+use crate::space::core::{
+    entity::components::{EntityData, EntityUpdates},
+    sensable::components::Sensable,
+    sfx::components::{get_random_pitch_scale, Sfx},
+    static_body::components::StaticTransform,
+};
+
+pub struct WeaponEmptySfxBundle;
+
+pub const WEAPON_EMPTY_PLAY_BACK_DURATION: f32 = 0.3 + 1.;
+
+impl WeaponEmptySfxBundle {
+    pub fn new(
+        passed_transform: Transform,
+    ) -> (StaticTransform, EntityData, Sensable, Sfx, EntityUpdates) {
+        (
+            StaticTransform {
+                transform: passed_transform,
+            },
+            EntityData {
+                entity_class: "SFX".to_string(),
+                ..Default::default()
+            },
+            Sensable {
+                is_audible: true,
+                ..Default::default()
+            },
+            Sfx {
+                unit_db: 10.,
+                unit_size: 1.,
+                stream_id: "/content/audio/combat/weapon_empty.sample".to_string(),
+                play_back_duration: WEAPON_EMPTY_PLAY_BACK_DURATION,
+                pitch_scale: get_random_pitch_scale(1.0),
+                ..Default::default()
+            },
+            EntityUpdates::default(),
+        )
+    }
+}
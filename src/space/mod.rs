@@ -11,15 +11,18 @@ use bevy_transform::TransformPlugin;
 
 use self::{
     core::{
-        asana::AsanaPlugin, atmospherics::AtmosphericsPlugin, chat::ChatPlugin,
-        combat::CombatPlugin, configuration::ConfigurationPlugin,
-        connected_player::ConnectedPlayerPlugin, console_commands::ConsoleCommandsPlugin,
-        entity::EntityPlugin, gridmap::GridmapPlugin, health::HealthPlugin,
+        ammunition::AmmunitionPlugin, asana::AsanaPlugin, atmospherics::AtmosphericsPlugin,
+        chat::ChatPlugin, chemistry::ChemistryPlugin, combat::CombatPlugin,
+        configuration::ConfigurationPlugin, connected_player::ConnectedPlayerPlugin,
+        console_commands::ConsoleCommandsPlugin, entity::EntityPlugin,
+        explosions::ExplosionsPlugin, gridmap::GridmapPlugin, health::HealthPlugin,
         humanoid::systems::HumanoidPlugin, inventory::InventoryPlugin,
-        inventory_item::InventoryItemPlugin, map::MapPlugin, networking::NetworkingPlugin,
-        pawn::PawnPlugin, physics::systems::PhysicsPlugin, rigid_body::systems::RigidBodyPlugin,
-        senser::SenserPlugin, sfx::SfxPlugin, tab_actions::TabActionsPlugin,
-        world_environment::WorldEnvironmentPlugin, CorePlugin,
+        inventory_item::InventoryItemPlugin, map::MapPlugin, medical::MedicalPlugin,
+        networking::NetworkingPlugin, pawn::PawnPlugin, physics::systems::PhysicsPlugin,
+        pipe_networks::PipeNetworksPlugin, prototypes::PrototypesPlugin,
+        rigid_body::systems::RigidBodyPlugin, senser::SenserPlugin, sfx::SfxPlugin,
+        storage::StoragePlugin, tab_actions::TabActionsPlugin, throwables::ThrowablesPlugin,
+        world_environment::WorldEnvironmentPlugin, world_save::WorldSavePlugin, CorePlugin,
     },
     entities::{
         air_locks::AirLocksPlugin, computers::ComputersPlugin,
@@ -34,6 +37,7 @@ pub struct SpacePlugin;
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
 pub enum StartupLabels {
     Launch,
+    InitEntities,
     InitDefaultGridmapData,
     BuildGridmap,
     InitAtmospherics,
@@ -64,6 +68,7 @@ pub enum UpdateLabels {
 pub enum AtmosphericsLabels {
     Diffusion,
     Effects,
+    PipeNetworksRebuild,
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
@@ -96,7 +101,9 @@ impl Plugin for SpacePlugin {
             .add_plugin(SfxPlugin)
             .add_plugin(HealthPlugin)
             .add_plugin(EntityPlugin)
+            .add_plugin(PrototypesPlugin)
             .add_plugin(AtmosphericsPlugin)
+            .add_plugin(PipeNetworksPlugin)
             .add_plugin(MapPlugin)
             .add_plugin(ChatPlugin)
             .add_plugin(AirLocksPlugin)
@@ -112,9 +119,16 @@ impl Plugin for SpacePlugin {
             .add_plugin(RigidBodyPlugin)
             .add_plugin(ComputersPlugin)
             .add_plugin(CombatPlugin)
+            .add_plugin(ThrowablesPlugin)
             .add_plugin(OmniLightPlugin)
             .add_plugin(ReflectionProbePlugin)
             .add_plugin(InventoryItemPlugin)
-            .add_plugin(SenserPlugin);
+            .add_plugin(SenserPlugin)
+            .add_plugin(WorldSavePlugin)
+            .add_plugin(StoragePlugin)
+            .add_plugin(ChemistryPlugin)
+            .add_plugin(MedicalPlugin)
+            .add_plugin(AmmunitionPlugin)
+            .add_plugin(ExplosionsPlugin);
     }
 }